@@ -4,7 +4,7 @@ use inquire::{CustomType, Select, Text};
 
 #[derive(Debug, Clone)]
 pub struct AccessTypeContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub signer_account_id: near_primitives::types::AccountId,
     pub permission: near_primitives::account::AccessKeyPermission,
 }
@@ -19,7 +19,7 @@ pub struct FullAccessType {
 
 #[derive(Debug, Clone)]
 pub struct FullAccessTypeContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     permission: near_primitives::account::AccessKeyPermission,
 }
@@ -30,7 +30,7 @@ impl FullAccessTypeContext {
         _scope: &<FullAccessType as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.owner_account_id.into(),
             permission: near_primitives::account::AccessKeyPermission::FullAccess,
         })
@@ -40,7 +40,7 @@ impl FullAccessTypeContext {
 impl From<FullAccessTypeContext> for AccessTypeContext {
     fn from(item: FullAccessTypeContext) -> Self {
         Self {
-            config: item.config,
+            global_context: item.global_context,
             signer_account_id: item.signer_account_id,
             permission: item.permission,
         }
@@ -54,7 +54,7 @@ impl From<FullAccessTypeContext> for AccessTypeContext {
 pub struct FunctionCallType {
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
-    allowance: Option<crate::common::NearBalance>,
+    allowance: Option<crate::types::allowance::Allowance>,
     #[interactive_clap(long)]
     ///Enter a receiver to use by this access key to pay for function call gas and transaction fees.
     receiver_account_id: crate::types::account_id::AccountId,
@@ -67,9 +67,9 @@ pub struct FunctionCallType {
 
 #[derive(Debug, Clone)]
 pub struct FunctionCallTypeContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
-    allowance: Option<crate::common::NearBalance>,
+    allowance: Option<crate::types::allowance::Allowance>,
     receiver_account_id: crate::types::account_id::AccountId,
     method_names: crate::types::vec_string::VecString,
 }
@@ -80,7 +80,7 @@ impl FunctionCallTypeContext {
         scope: &<FunctionCallType as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.owner_account_id.into(),
             allowance: scope.allowance.clone(),
             receiver_account_id: scope.receiver_account_id.clone(),
@@ -92,11 +92,13 @@ impl FunctionCallTypeContext {
 impl From<FunctionCallTypeContext> for AccessTypeContext {
     fn from(item: FunctionCallTypeContext) -> Self {
         Self {
-            config: item.config,
+            global_context: item.global_context,
             signer_account_id: item.signer_account_id,
             permission: near_primitives::account::AccessKeyPermission::FunctionCall(
                 near_primitives::account::FunctionCallPermission {
-                    allowance: item.allowance.map(|allowance| allowance.to_yoctonear()),
+                    allowance: item
+                        .allowance
+                        .and_then(|allowance| allowance.optional_yoctonear()),
                     receiver_id: item.receiver_account_id.to_string(),
                     method_names: item.method_names.into(),
                 },
@@ -128,7 +130,12 @@ impl interactive_clap::FromCli for FunctionCallType {
         }
         let allowance = clap_variant.allowance.clone();
         if clap_variant.receiver_account_id.is_none() {
-            clap_variant.receiver_account_id = match Self::input_receiver_account_id(&context) {
+            clap_variant.receiver_account_id = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--receiver-account-id")
+                .and_then(|_| Self::input_receiver_account_id(&context))
+            {
                 Ok(Some(first_receiver_account_id)) => Some(first_receiver_account_id),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -139,7 +146,12 @@ impl interactive_clap::FromCli for FunctionCallType {
             .clone()
             .expect("Unexpected error");
         if clap_variant.method_names.is_none() {
-            clap_variant.method_names = match Self::input_method_names(&context) {
+            clap_variant.method_names = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--method-names")
+                .and_then(|_| Self::input_method_names(&context))
+            {
                 Ok(Some(first_method_names)) => Some(first_method_names),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -216,8 +228,13 @@ impl FunctionCallType {
     }
 
     pub fn input_allowance(
-        _context: &super::AddKeyCommandContext,
-    ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
+        context: &super::AddKeyCommandContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::allowance::Allowance>> {
+        // A missing allowance would silently grant an unlimited one, so it is never defaulted
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--allowance")?;
         eprintln!();
         #[derive(strum_macros::Display)]
         enum ConfirmOptions {
@@ -235,9 +252,11 @@ impl FunctionCallType {
             let allowance_near_balance: crate::common::NearBalance =
                     CustomType::new("Enter an allowance which is a balance limit to use by this access key to pay for function call gas and transaction fees. (example: 10NEAR or 0.5near or 10000yoctonear)")
                     .prompt()?;
-            Ok(Some(allowance_near_balance))
+            Ok(Some(crate::types::allowance::Allowance::Limited(
+                allowance_near_balance,
+            )))
         } else {
-            Ok(None)
+            Ok(Some(crate::types::allowance::Allowance::Unlimited))
         }
     }
 }
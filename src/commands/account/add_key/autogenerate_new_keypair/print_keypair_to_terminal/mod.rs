@@ -9,7 +9,7 @@ pub struct PrintKeypairToTerminal {
 
 #[derive(Debug, Clone)]
 pub struct PrintKeypairToTerminalContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     permission: near_primitives::account::AccessKeyPermission,
     key_pair_properties: crate::common::KeyPairProperties,
@@ -22,7 +22,7 @@ impl PrintKeypairToTerminalContext {
         _scope: &<PrintKeypairToTerminal as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             permission: previous_context.permission,
             key_pair_properties: previous_context.key_pair_properties,
@@ -50,7 +50,7 @@ impl From<PrintKeypairToTerminalContext> for crate::commands::ActionContext {
                 })
             });
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
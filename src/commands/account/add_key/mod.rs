@@ -9,15 +9,27 @@ mod use_public_key;
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = AddKeyCommandContext)]
 pub struct AddKeyCommand {
+    #[interactive_clap(skip_default_input_arg)]
     /// Which account should You add an access key to?
     owner_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
     permission: AccessKeyPermission,
 }
 
+impl AddKeyCommand {
+    fn input_owner_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(
+            &context.0,
+            "Which account should You add an access key to?",
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AddKeyCommandContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     owner_account_id: crate::types::account_id::AccountId,
 }
 
@@ -27,7 +39,7 @@ impl AddKeyCommandContext {
         scope: &<AddKeyCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             owner_account_id: scope.owner_account_id.clone(),
         })
     }
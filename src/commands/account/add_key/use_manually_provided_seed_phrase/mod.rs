@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -6,6 +7,14 @@ use std::str::FromStr;
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed-phrase for this sub-account
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
@@ -13,7 +22,7 @@ pub struct AddAccessWithSeedPhraseAction {
 
 #[derive(Debug, Clone)]
 pub struct AddAccessWithSeedPhraseActionContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     permission: near_primitives::account::AccessKeyPermission,
     public_key: near_crypto::PublicKey,
@@ -28,9 +37,11 @@ impl AddAccessWithSeedPhraseActionContext {
         let public_key = crate::common::get_public_key_from_seed_phrase(
             seed_phrase_hd_path_default,
             &scope.master_seed_phrase,
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             permission: previous_context.permission,
             public_key,
@@ -57,7 +68,7 @@ impl From<AddAccessWithSeedPhraseActionContext> for crate::commands::ActionConte
                 })
             });
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -71,3 +82,11 @@ impl From<AddAccessWithSeedPhraseActionContext> for crate::commands::ActionConte
         }
     }
 }
+
+impl SeedPhraseOptions for AddAccessWithSeedPhraseAction {
+    type InputContext = super::access_key_type::AccessTypeContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
@@ -11,7 +11,7 @@ pub struct AddAccessKeyAction {
 
 #[derive(Debug, Clone)]
 pub struct AddAccessKeyActionContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     permission: near_primitives::account::AccessKeyPermission,
     public_key: crate::types::public_key::PublicKey,
@@ -23,7 +23,7 @@ impl AddAccessKeyActionContext {
         scope: &<AddAccessKeyAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             permission: previous_context.permission,
             public_key: scope.public_key.clone(),
@@ -50,7 +50,7 @@ impl From<AddAccessKeyActionContext> for crate::commands::ActionContext {
                 })
             });
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -1,3 +1,6 @@
+use std::io::Write;
+
+use color_eyre::eyre::Context;
 use inquire::Text;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
@@ -5,6 +8,7 @@ mod use_auto_generation;
 #[cfg(feature = "ledger")]
 mod use_ledger;
 mod use_seed_phrase;
+mod use_vanity;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
@@ -34,6 +38,11 @@ pub enum Mode {
     ))]
     /// Use seed phrase to create an implicit account
     UseSeedPhrase(self::use_seed_phrase::SaveWithSeedPhrase),
+    #[strum_discriminants(strum(
+        message = "use-vanity           - Search for an implicit account with the given prefix and suffix"
+    ))]
+    /// Search for an implicit account with the given prefix and suffix
+    UseVanity(self::use_vanity::SaveWithVanity),
 }
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -84,7 +93,11 @@ impl SaveToFolder {
             .with_initial_value(
                 format!(
                     "{}/implicit",
-                    context.config.credentials_home_dir.to_string_lossy()
+                    context
+                        .global_context
+                        .0
+                        .credentials_home_dir
+                        .to_string_lossy()
                 )
                 .as_str(),
             )
@@ -99,6 +112,23 @@ pub type OnAfterGettingFolderPathCallback =
 
 #[derive(Clone)]
 pub struct SaveImplicitAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     on_after_getting_folder_path_callback: OnAfterGettingFolderPathCallback,
 }
+
+/// Saves the key pair of the implicit account to `<folder_path>/<implicit_account_id>.json`.
+fn save_implicit_account_file(
+    folder_path: &std::path::Path,
+    implicit_account_id: &str,
+    buf: &str,
+) -> crate::CliResult {
+    std::fs::create_dir_all(folder_path)
+        .wrap_err_with(|| format!("Failed to create folder: {:?}", folder_path))?;
+    let file_path = folder_path.join(format!("{}.json", implicit_account_id));
+    std::fs::File::create(&file_path)
+        .wrap_err_with(|| format!("Failed to create file: {:?}", file_path))?
+        .write_all(buf.as_bytes())
+        .wrap_err_with(|| format!("Failed to write to file: {:?}", file_path))?;
+    eprintln!("\nThe file {:?} was saved successfully", &file_path);
+    Ok(())
+}
@@ -1,7 +1,3 @@
-use std::io::Write;
-
-use color_eyre::eyre::Context;
-
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SaveWithUseAutoGenerationContext)]
@@ -31,24 +27,15 @@ impl SaveWithUseAutoGenerationContext {
                         "private_key": key_pair_properties.secret_keypair_str,
                     })
                     .to_string();
-                    let mut file_path = std::path::PathBuf::new();
-                    let mut file_name = std::path::PathBuf::new();
-                    file_name.push(format!("{}.json", key_pair_properties.implicit_account_id));
-                    file_path.push(folder_path);
-
-                    std::fs::create_dir_all(&file_path)?;
-                    file_path.push(file_name);
-                    std::fs::File::create(&file_path)
-                        .wrap_err_with(|| format!("Failed to create file: {:?}", file_path))?
-                        .write(buf.as_bytes())
-                        .wrap_err_with(|| format!("Failed to write to file: {:?}", folder_path))?;
-                    eprintln!("\nThe file {:?} was saved successfully", &file_path);
-
-                    Ok(())
+                    super::save_implicit_account_file(
+                        folder_path,
+                        &key_pair_properties.implicit_account_id,
+                        &buf,
+                    )
                 }
             });
         Ok(Self(super::SaveImplicitAccountContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_folder_path_callback,
         }))
     }
@@ -1,7 +1,3 @@
-use std::io::Write;
-
-use color_eyre::eyre::Context;
-
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SaveWithLedgerContext)]
@@ -19,10 +15,11 @@ impl SaveWithLedgerContext {
         previous_context: crate::GlobalContext,
         _scope: &<SaveWithLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        let global_options = previous_context.1.clone();
         let on_after_getting_folder_path_callback: super::OnAfterGettingFolderPathCallback =
             std::sync::Arc::new({
                 move |folder_path| {
-                    let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path()?.unwrap();
+                    let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path(&global_options)?.unwrap();
                     eprintln!(
                         "Please allow getting the PublicKey on Ledger device (HD Path: {})",
                         seed_phrase_hd_path
@@ -44,24 +41,15 @@ impl SaveWithLedgerContext {
                         "public_key": public_key_str,
                     })
                     .to_string();
-                    let file_name: std::path::PathBuf =
-                        format!("{}.json", implicit_account_id).into();
-                    let mut file_path = std::path::PathBuf::new();
-                    file_path.push(folder_path);
-
-                    std::fs::create_dir_all(&file_path)?;
-                    file_path.push(file_name);
-                    std::fs::File::create(&file_path)
-                        .wrap_err_with(|| format!("Failed to create file: {:?}", file_path))?
-                        .write(buf.as_bytes())
-                        .wrap_err_with(|| format!("Failed to write to file: {:?}", file_path))?;
-                    eprintln!("\nThe file {:?} was saved successfully", &file_path);
-
-                    Ok(())
+                    super::save_implicit_account_file(
+                        folder_path,
+                        implicit_account_id.as_str(),
+                        &buf,
+                    )
                 }
             });
         Ok(Self(super::SaveImplicitAccountContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_folder_path_callback,
         }))
     }
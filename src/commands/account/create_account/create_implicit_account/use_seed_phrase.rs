@@ -1,7 +1,6 @@
-use std::io::Write;
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
-use color_eyre::eyre::Context;
 use inquire::Text;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -12,6 +11,14 @@ pub struct SaveWithSeedPhrase {
     master_seed_phrase: String,
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     #[interactive_clap(named_arg)]
     /// Specify a folder to save the implicit account file
@@ -30,12 +37,12 @@ impl SaveWithSeedPhraseContext {
             crate::common::get_key_pair_properties_from_seed_phrase(
                 scope.seed_phrase_hd_path.clone(),
                 scope.master_seed_phrase.clone(),
+                scope.seed_phrase_language,
+                scope.seed_phrase_passphrase.as_deref(),
             )?;
         let on_after_getting_folder_path_callback: super::OnAfterGettingFolderPathCallback =
             std::sync::Arc::new({
                 move |folder_path| {
-                    let mut file_path = std::path::PathBuf::new();
-                    let mut file_name = std::path::PathBuf::new();
                     let buf = serde_json::json!({
                         "master_seed_phrase": key_pair_properties.master_seed_phrase,
                         "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path,
@@ -44,22 +51,15 @@ impl SaveWithSeedPhraseContext {
                         "private_key": key_pair_properties.secret_keypair_str,
                     })
                     .to_string();
-                    file_name.push(format!("{}.json", key_pair_properties.implicit_account_id));
-                    file_path.push(folder_path);
-
-                    std::fs::create_dir_all(&file_path)?;
-                    file_path.push(file_name);
-                    std::fs::File::create(&file_path)
-                        .wrap_err_with(|| format!("Failed to create file: {:?}", file_path))?
-                        .write(buf.as_bytes())
-                        .wrap_err_with(|| format!("Failed to write to file: {:?}", file_path))?;
-                    eprintln!("\nThe file {:?} was saved successfully", &file_path);
-
-                    Ok(())
+                    super::save_implicit_account_file(
+                        folder_path,
+                        &key_pair_properties.implicit_account_id,
+                        &buf,
+                    )
                 }
             });
         Ok(Self(super::SaveImplicitAccountContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_folder_path_callback,
         }))
     }
@@ -73,8 +73,9 @@ impl From<SaveWithSeedPhraseContext> for super::SaveImplicitAccountContext {
 
 impl SaveWithSeedPhrase {
     pub fn input_seed_phrase_hd_path(
-        _context: &crate::GlobalContext,
+        context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context.1.ensure_interactive_mode("--seed-phrase-hd-path")?;
         Ok(Some(
             crate::types::slip10::BIP32Path::from_str(
                 &Text::new("Enter seed phrase HD Path (if you not sure leave blank for default)")
@@ -86,3 +87,11 @@ impl SaveWithSeedPhrase {
         ))
     }
 }
+
+impl SeedPhraseOptions for SaveWithSeedPhrase {
+    type InputContext = crate::GlobalContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        context
+    }
+}
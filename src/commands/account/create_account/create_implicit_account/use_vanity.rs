@@ -0,0 +1,249 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use inquire::Text;
+
+/// Patterns longer than this are expected to take weeks or more even on many CPU cores
+const MAX_PATTERN_LEN: usize = 10;
+/// Patterns longer than this are expected to take from minutes to days, so the search has to be
+/// confirmed
+const CONFIRMED_PATTERN_LEN: usize = 7;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SaveWithVanityContext)]
+pub struct SaveWithVanity {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Hex characters the implicit account ID starts with
+    prefix: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Hex characters the implicit account ID ends with
+    suffix: Option<String>,
+    #[interactive_clap(long)]
+    /// Start a search that is expected to take long without a confirmation
+    yes: bool,
+    #[interactive_clap(named_arg)]
+    /// Specify a folder to save the implicit account file
+    save_to_folder: super::SaveToFolder,
+}
+
+#[derive(Clone)]
+struct SaveWithVanityContext(super::SaveImplicitAccountContext);
+
+impl SaveWithVanityContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SaveWithVanity as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let prefix = parse_hex_pattern("--prefix", scope.prefix.as_deref())?;
+        let suffix = parse_hex_pattern("--suffix", scope.suffix.as_deref())?;
+        if prefix.is_empty() && suffix.is_empty() {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                "At least one of --prefix and --suffix has to be specified",
+            )
+            .into());
+        }
+        let pattern_len = prefix.len() + suffix.len();
+        if pattern_len > MAX_PATTERN_LEN {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                format!(
+                    "The prefix and the suffix have {} hex characters together, but at most {} are supported: every character makes the search 16 times longer",
+                    pattern_len, MAX_PATTERN_LEN
+                ),
+            )
+            .into());
+        }
+        if pattern_len > CONFIRMED_PATTERN_LEN
+            && !crate::common::confirm(
+                previous_context.1.scripting_mode,
+                scope.yes,
+                &format!(
+                    "About {:.0} attempts are expected for {} hex characters, which may take hours. Do you want to start the search?",
+                    expected_attempts(pattern_len),
+                    pattern_len
+                ),
+            )?
+        {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                "The vanity key pair search was cancelled",
+            )
+            .into());
+        }
+
+        let on_after_getting_folder_path_callback: super::OnAfterGettingFolderPathCallback =
+            std::sync::Arc::new({
+                move |folder_path| {
+                    let (secret_key, implicit_account_id) =
+                        search_vanity_key_pair(&prefix, &suffix)?;
+                    let buf = serde_json::json!({
+                        "implicit_account_id": implicit_account_id,
+                        "public_key": secret_key.public_key().to_string(),
+                        "private_key": secret_key.to_string(),
+                    })
+                    .to_string();
+                    super::save_implicit_account_file(folder_path, &implicit_account_id, &buf)
+                }
+            });
+        Ok(Self(super::SaveImplicitAccountContext {
+            global_context: previous_context,
+            on_after_getting_folder_path_callback,
+        }))
+    }
+}
+
+impl From<SaveWithVanityContext> for super::SaveImplicitAccountContext {
+    fn from(item: SaveWithVanityContext) -> Self {
+        item.0
+    }
+}
+
+impl SaveWithVanity {
+    fn input_prefix(context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<String>> {
+        input_hex_pattern(context, "Enter the hex characters the implicit account ID starts with (leave blank for no prefix)")
+    }
+
+    fn input_suffix(context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<String>> {
+        input_hex_pattern(context, "Enter the hex characters the implicit account ID ends with (leave blank for no suffix)")
+    }
+}
+
+fn input_hex_pattern(
+    context: &crate::GlobalContext,
+    message: &str,
+) -> color_eyre::eyre::Result<Option<String>> {
+    if context.1.scripting_mode {
+        return Ok(None);
+    }
+    let pattern = Text::new(message).prompt()?;
+    Ok((!pattern.trim().is_empty()).then(|| pattern.trim().to_string()))
+}
+
+fn parse_hex_pattern(flag: &str, pattern: Option<&str>) -> color_eyre::eyre::Result<String> {
+    let pattern = pattern.unwrap_or_default().to_lowercase();
+    if !pattern.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(crate::common::CliError::new(
+            crate::common::ErrorCategory::UserInput,
+            format!(
+                "The value <{}> of {} is not valid: implicit account IDs consist of hex characters (0-9, a-f) only",
+                pattern, flag
+            ),
+        )
+        .into());
+    }
+    Ok(pattern)
+}
+
+/// Generates random ED25519 key pairs on every CPU core until the implicit account ID (the hex
+/// encoded public key) starts with the prefix and ends with the suffix.
+fn search_vanity_key_pair(
+    prefix: &str,
+    suffix: &str,
+) -> color_eyre::eyre::Result<(near_crypto::SecretKey, String)> {
+    let threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let expected_attempts = expected_attempts(prefix.len() + suffix.len());
+    eprintln!(
+        "\nSearching for an implicit account ID {}...{} on {} threads (about {:.0} attempts expected)",
+        prefix, suffix, threads, expected_attempts
+    );
+
+    let attempts = std::sync::Arc::new(AtomicU64::new(0));
+    let found = std::sync::Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let workers = (0..threads)
+        .map(|_| {
+            let (prefix, suffix) = (prefix.to_string(), suffix.to_string());
+            let (attempts, found, sender) = (attempts.clone(), found.clone(), sender.clone());
+            std::thread::spawn(move || {
+                while !found.load(Ordering::Relaxed) {
+                    let secret_key =
+                        near_crypto::SecretKey::from_random(near_crypto::KeyType::ED25519);
+                    let implicit_account_id = hex::encode(secret_key.public_key().key_data());
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    if implicit_account_id.starts_with(&prefix)
+                        && implicit_account_id.ends_with(&suffix)
+                    {
+                        found.store(true, Ordering::Relaxed);
+                        let _ = sender.send((secret_key, implicit_account_id));
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(sender);
+
+    let started_at = std::time::Instant::now();
+    let result = loop {
+        match receiver.recv_timeout(std::time::Duration::from_secs(1)) {
+            Ok(result) => break Ok(result),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                let attempts = attempts.load(Ordering::Relaxed);
+                let rate = attempts as f64 / started_at.elapsed().as_secs_f64();
+                let eta = if attempts as f64 >= expected_attempts {
+                    "any moment now".to_string()
+                } else {
+                    format_duration((expected_attempts - attempts as f64) / rate)
+                };
+                eprint!(
+                    "\r{} attempts ({:.0} keys/s), expected time left: {}        ",
+                    attempts, rate, eta
+                );
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                break Err(color_eyre::eyre::eyre!(
+                    "The vanity key pair search stopped unexpectedly"
+                ))
+            }
+        }
+    };
+    found.store(true, Ordering::Relaxed);
+    for worker in workers {
+        let _ = worker.join();
+    }
+    if result.is_ok() {
+        eprintln!(
+            "\nFound after {} attempts in {}",
+            attempts.load(Ordering::Relaxed),
+            format_duration(started_at.elapsed().as_secs_f64())
+        );
+    }
+    result
+}
+
+fn expected_attempts(pattern_len: usize) -> f64 {
+    // Every hex character matches with the probability of 1/16
+    16f64.powi(pattern_len as i32)
+}
+
+fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_patterns() {
+        assert_eq!(parse_hex_pattern("--prefix", Some("AbC0")).unwrap(), "abc0");
+        assert_eq!(parse_hex_pattern("--suffix", None).unwrap(), "");
+        assert!(parse_hex_pattern("--prefix", Some("xyz")).is_err());
+        assert!(parse_hex_pattern("--prefix", Some("ab c")).is_err());
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(0.4), "0s");
+        assert_eq!(format_duration(59.0), "59s");
+        assert_eq!(format_duration(61.0), "1m 1s");
+        assert_eq!(format_duration(7322.0), "2h 2m");
+    }
+}
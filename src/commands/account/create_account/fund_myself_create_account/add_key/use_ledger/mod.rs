@@ -15,7 +15,7 @@ impl AddAccessWithLedgerContext {
         previous_context: super::super::NewAccountContext,
         _scope: &<AddAccessWithLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path()?.unwrap();
+        let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path(&previous_context.global_context.1)?.unwrap();
         eprintln!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
             seed_phrase_hd_path
@@ -39,7 +39,7 @@ impl AddAccessWithLedgerContext {
         };
 
         Ok(Self(super::super::AccountPropertiesContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             account_properties,
             on_before_sending_transaction_callback: std::sync::Arc::new(
                 |_signed_transaction, _network_config, _message| Ok(()),
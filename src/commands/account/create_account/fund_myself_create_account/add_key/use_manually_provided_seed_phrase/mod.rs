@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -6,6 +7,14 @@ use std::str::FromStr;
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed-phrase for this sub-account
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
     #[interactive_clap(named_arg)]
     /// What is the signer account ID?
     sign_as: super::super::sign_as::SignerAccountId,
@@ -25,6 +34,8 @@ impl AddAccessWithSeedPhraseActionContext {
         let public_key = crate::common::get_public_key_from_seed_phrase(
             near_wallet_seed_phrase_hd_path_default,
             &scope.master_seed_phrase,
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
         let account_properties = super::super::AccountProperties {
             new_account_id: previous_context.new_account_id,
@@ -33,7 +44,7 @@ impl AddAccessWithSeedPhraseActionContext {
         };
 
         Ok(Self(super::super::AccountPropertiesContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             account_properties,
             on_before_sending_transaction_callback: std::sync::Arc::new(
                 |_signed_transaction, _network_config, _message| Ok(()),
@@ -47,3 +58,11 @@ impl From<AddAccessWithSeedPhraseActionContext> for super::super::AccountPropert
         item.0
     }
 }
+
+impl SeedPhraseOptions for AddAccessWithSeedPhraseAction {
+    type InputContext = super::super::NewAccountContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
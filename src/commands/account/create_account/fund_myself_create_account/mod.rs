@@ -23,7 +23,7 @@ pub struct NewAccount {
 
 #[derive(Debug, Clone)]
 pub struct NewAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     new_account_id: crate::types::account_id::AccountId,
     initial_balance: crate::common::NearBalance,
 }
@@ -34,7 +34,7 @@ impl NewAccountContext {
         scope: &<NewAccount as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             new_account_id: scope.new_account_id.clone(),
             initial_balance: scope.initial_balance.clone(),
         })
@@ -146,6 +146,7 @@ fn find_network_where_account_exist(
             network.1.clone(),
             new_account_id.clone(),
             near_primitives::types::BlockReference::latest(),
+            context.1.scripting_mode,
         )
         .is_ok()
         {
@@ -173,7 +174,7 @@ fn ask_if_different_account_id_wanted() -> color_eyre::eyre::Result<bool> {
 
 #[derive(Clone)]
 pub struct AccountPropertiesContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub account_properties: AccountProperties,
     pub on_before_sending_transaction_callback:
         crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
@@ -18,7 +18,7 @@ pub struct SignerAccountId {
 
 #[derive(Clone)]
 pub struct SignerAccountIdContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     account_properties: super::AccountProperties,
     signer_account_id: near_primitives::types::AccountId,
     on_before_sending_transaction_callback:
@@ -31,7 +31,7 @@ impl SignerAccountIdContext {
         scope: &<SignerAccountId as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             account_properties: previous_context.account_properties,
             signer_account_id: scope.signer_account_id.clone().into(),
             on_before_sending_transaction_callback: previous_context
@@ -42,7 +42,7 @@ impl SignerAccountIdContext {
 
 impl From<SignerAccountIdContext> for crate::commands::ActionContext {
     fn from(item: SignerAccountIdContext) -> Self {
-        let config = item.config;
+        let scripting_mode = item.global_context.1.scripting_mode;
 
         let on_after_getting_network_callback: crate::commands::OnAfterGettingNetworkCallback =
             std::sync::Arc::new({
@@ -51,7 +51,7 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
                 let signer_id = item.signer_account_id.clone();
 
                 move |network_config| {
-                    validate_signer_account_id(network_config, &signer_id)?;
+                    validate_signer_account_id(network_config, &signer_id, scripting_mode)?;
 
                     if new_account_id.as_str().chars().count()
                         < super::MIN_ALLOWED_TOP_LEVEL_ACCOUNT_LENGTH
@@ -62,7 +62,7 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
                             new_account_id, new_account_id.as_str().chars().count()
                         ));
                     }
-                    validate_new_account_id(network_config, &new_account_id)?;
+                    validate_new_account_id(network_config, &new_account_id, scripting_mode)?;
 
                     let (actions, receiver_id) = if new_account_id.is_sub_account_of(&signer_id) {
                         (
@@ -141,7 +141,7 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
             });
 
         Self {
-            config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -165,9 +165,10 @@ impl SignerAccountId {
             .get_parent_account_id_from_sub_account();
         if !parent_account_id.0.is_top_level() {
             if crate::common::is_account_exist(
-                &context.config.network_connection,
+                &context.global_context.0.network_connection,
                 parent_account_id.clone().into(),
-            ) {
+                context.global_context.1.scripting_mode,
+            )? {
                 Ok(Some(parent_account_id))
             } else {
                 Self::input_account_id(context)
@@ -181,12 +182,19 @@ impl SignerAccountId {
         context: &super::AccountPropertiesContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         loop {
+            let mut signer_account_id_input = CustomType::new("What is the signer account ID?");
+            if let Some(default_account_id) =
+                crate::common::default_account_id(&context.global_context.0)
+            {
+                signer_account_id_input = signer_account_id_input.with_default(default_account_id);
+            }
             let signer_account_id: crate::types::account_id::AccountId =
-                CustomType::new("What is the signer account ID?").prompt()?;
+                signer_account_id_input.prompt()?;
             if !crate::common::is_account_exist(
-                &context.config.network_connection,
+                &context.global_context.0.network_connection,
                 signer_account_id.clone().into(),
-            ) {
+                context.global_context.1.scripting_mode,
+            )? {
                 eprintln!("\nThe account <{}> does not yet exist.", &signer_account_id);
                 #[derive(strum_macros::Display)]
                 enum ConfirmOptions {
@@ -213,11 +221,13 @@ impl SignerAccountId {
 fn validate_signer_account_id(
     network_config: &crate::config::NetworkConfig,
     account_id: &near_primitives::types::AccountId,
+    scripting_mode: bool,
 ) -> crate::CliResult {
     match crate::common::get_account_state(
         network_config.clone(),
         account_id.clone(),
         near_primitives::types::BlockReference::latest(),
+        scripting_mode,
     ) {
         Ok(_) => Ok(()),
         Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
@@ -239,11 +249,13 @@ fn validate_signer_account_id(
 fn validate_new_account_id(
     network_config: &crate::config::NetworkConfig,
     account_id: &near_primitives::types::AccountId,
+    scripting_mode: bool,
 ) -> crate::CliResult {
     match crate::common::get_account_state(
         network_config.clone(),
         account_id.clone(),
         near_primitives::types::BlockReference::latest(),
+        scripting_mode,
     )
     {
         Ok(_) => {
@@ -12,7 +12,7 @@ pub struct GenerateKeypair {
 
 #[derive(Clone)]
 pub struct GenerateKeypairContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     new_account_id: crate::types::account_id::AccountId,
     public_key: near_crypto::PublicKey,
     key_pair_properties: crate::common::KeyPairProperties,
@@ -29,7 +29,7 @@ impl GenerateKeypairContext {
         let public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
 
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             new_account_id: previous_context.new_account_id,
             public_key,
             key_pair_properties,
@@ -77,7 +77,7 @@ impl SaveModeContext {
             std::sync::Arc::new({
                 let new_account_id_str = previous_context.new_account_id.to_string();
                 let key_pair_properties = previous_context.key_pair_properties.clone();
-                let credentials_home_dir = previous_context.config.credentials_home_dir.clone();
+                let credentials_home_dir = previous_context.global_context.0.credentials_home_dir.clone();
 
                 move |network_config, storage_message| {
                     match scope {
@@ -121,7 +121,7 @@ impl SaveModeContext {
             });
 
         Ok(Self(super::super::SponsorServiceContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             new_account_id: previous_context.new_account_id,
             public_key: previous_context.public_key,
             on_after_getting_network_callback,
@@ -15,7 +15,7 @@ impl AddAccessWithLedgerContext {
         previous_context: super::super::NewAccountContext,
         _scope: &<AddAccessWithLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path()?.unwrap();
+        let seed_phrase_hd_path = crate::transaction_signature_options::sign_with_ledger::SignLedger::input_seed_phrase_hd_path(&previous_context.global_context.1)?.unwrap();
         eprintln!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
             seed_phrase_hd_path
@@ -33,7 +33,7 @@ impl AddAccessWithLedgerContext {
         ));
 
         Ok(Self(super::super::SponsorServiceContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             new_account_id: previous_context.new_account_id,
             public_key,
             on_after_getting_network_callback: std::sync::Arc::new(
@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -6,6 +7,14 @@ use std::str::FromStr;
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed-phrase for this account
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: super::super::network::Network,
@@ -25,10 +34,12 @@ impl AddAccessWithSeedPhraseActionContext {
         let public_key = crate::common::get_public_key_from_seed_phrase(
             near_wallet_seed_phrase_hd_path_default,
             &scope.master_seed_phrase,
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
 
         Ok(Self(super::super::SponsorServiceContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             new_account_id: previous_context.new_account_id,
             public_key,
             on_after_getting_network_callback: std::sync::Arc::new(
@@ -45,3 +56,11 @@ impl From<AddAccessWithSeedPhraseActionContext> for super::super::SponsorService
         item.0
     }
 }
+
+impl SeedPhraseOptions for AddAccessWithSeedPhraseAction {
+    type InputContext = super::super::NewAccountContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
@@ -18,7 +18,7 @@ impl AddPublicKeyActionContext {
         scope: &<AddPublicKeyAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self(super::super::SponsorServiceContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             new_account_id: previous_context.new_account_id,
             public_key: scope.public_key.clone().into(),
             on_after_getting_network_callback: std::sync::Arc::new(
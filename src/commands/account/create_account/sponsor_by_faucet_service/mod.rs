@@ -3,7 +3,7 @@ mod network;
 
 #[derive(Clone)]
 pub struct SponsorServiceContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub new_account_id: crate::types::account_id::AccountId,
     pub public_key: near_crypto::PublicKey,
     pub on_after_getting_network_callback: self::network::OnAfterGettingNetworkCallback,
@@ -23,7 +23,7 @@ pub struct NewAccount {
 
 #[derive(Clone)]
 pub struct NewAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     new_account_id: crate::types::account_id::AccountId,
     on_before_creating_account_callback: self::network::OnBeforeCreatingAccountCallback,
 }
@@ -35,6 +35,7 @@ impl NewAccountContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_before_creating_account_callback: self::network::OnBeforeCreatingAccountCallback =
             std::sync::Arc::new({
+                let output_format = previous_context.1.output_format.clone();
                 move |network_config, new_account_id, public_key| {
                     let faucet_service_url = match &network_config.faucet_url {
                         Some(url) => url,
@@ -47,12 +48,15 @@ impl NewAccountContext {
                     data.insert("newAccountId", new_account_id.to_string());
                     data.insert("newAccountPublicKey", public_key.to_string());
 
-                    let client = reqwest::blocking::Client::new();
-                    match client.post(faucet_service_url.clone()).json(&data).send() {
+                    let client = network_config.http_client()?;
+                    let runtime = tokio::runtime::Runtime::new()?;
+                    match runtime
+                        .block_on(client.post(faucet_service_url.clone()).json(&data).send())
+                    {
                         Ok(response) => {
-                            let account_creation_transaction = response
+                            let account_creation_transaction = runtime.block_on(response
                                 .json::<near_jsonrpc_client::methods::tx::RpcTransactionStatusResponse>(
-                                )?;
+                                ))?;
                             match account_creation_transaction.status {
                                 near_primitives::views::FinalExecutionStatus::SuccessValue(
                                     ref value,
@@ -77,6 +81,7 @@ impl NewAccountContext {
                                     crate::common::print_transaction_status(
                                         &account_creation_transaction,
                                         network_config,
+                                        &output_format,
                                     )?;
                                 }
                             }
@@ -88,7 +93,7 @@ impl NewAccountContext {
             });
 
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             new_account_id: scope.new_account_id.clone(),
             on_before_creating_account_callback,
         })
@@ -25,7 +25,7 @@ impl NetworkContext {
         previous_context: super::SponsorServiceContext,
         scope: &<Network as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let networks = previous_context.config.network_connection.clone();
+        let networks = previous_context.global_context.0.network_connection.clone();
         let network_config = networks
             .get(&scope.network_name)
             .expect("Failed to get network config!")
@@ -61,7 +61,7 @@ impl Network {
     fn input_network_name(
         context: &super::SponsorServiceContext,
     ) -> color_eyre::eyre::Result<Option<String>> {
-        crate::common::input_network_name(&(context.config.clone(),))
+        crate::common::input_network_name(&context.global_context)
     }
 }
 
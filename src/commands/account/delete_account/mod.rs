@@ -2,6 +2,7 @@
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = DeleteAccountContext)]
 pub struct DeleteAccount {
+    #[interactive_clap(skip_default_input_arg)]
     /// What Account ID to be deleted
     account_id: crate::types::account_id::AccountId,
     #[interactive_clap(named_arg)]
@@ -9,9 +10,17 @@ pub struct DeleteAccount {
     beneficiary: BeneficiaryAccount,
 }
 
+impl DeleteAccount {
+    fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(&context.0, "What Account ID to be deleted")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DeleteAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     account_id: near_primitives::types::AccountId,
 }
 
@@ -21,7 +30,7 @@ impl DeleteAccountContext {
         scope: &<DeleteAccount as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             account_id: scope.account_id.clone().into(),
         })
     }
@@ -40,7 +49,7 @@ pub struct BeneficiaryAccount {
 
 #[derive(Debug, Clone)]
 pub struct BeneficiaryAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     account_id: near_primitives::types::AccountId,
     beneficiary_account_id: near_primitives::types::AccountId,
 }
@@ -51,7 +60,7 @@ impl BeneficiaryAccountContext {
         scope: &<BeneficiaryAccount as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             account_id: previous_context.account_id,
             beneficiary_account_id: scope.beneficiary_account_id.clone().into(),
         })
@@ -73,7 +82,7 @@ impl From<BeneficiaryAccountContext> for crate::commands::ActionContext {
                 })
             });
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -2,6 +2,7 @@
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = DeleteKeyCommandContext)]
 pub struct DeleteKeyCommand {
+    #[interactive_clap(skip_default_input_arg)]
     /// Which account should You delete the access key for?
     owner_account_id: crate::types::account_id::AccountId,
     /// Enter the public key You wish to delete
@@ -11,9 +12,20 @@ pub struct DeleteKeyCommand {
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
 }
 
+impl DeleteKeyCommand {
+    fn input_owner_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(
+            &context.0,
+            "Which account should You delete the access key for?",
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DeleteKeyCommandContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     owner_account_id: near_primitives::types::AccountId,
     public_key: near_crypto::PublicKey,
 }
@@ -24,7 +36,7 @@ impl DeleteKeyCommandContext {
         scope: &<DeleteKeyCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             owner_account_id: scope.owner_account_id.clone().into(),
             public_key: scope.public_key.clone().into(),
         })
@@ -47,7 +59,7 @@ impl From<DeleteKeyCommandContext> for crate::commands::ActionContext {
             });
 
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
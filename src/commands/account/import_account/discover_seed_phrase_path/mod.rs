@@ -0,0 +1,398 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
+use color_eyre::eyre::WrapErr;
+use inquire::{CustomType, Select, Text};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+/// Number of account indexes checked for every HD path pattern by default
+const DEFAULT_MAX_INDEX: u64 = 10;
+
+/// HD path patterns checked for every seed phrase, `{N}` is replaced with the account index.
+/// `m/44'/397'/0'` (index 0 of the first pattern) is the path used by the legacy near-wallet.
+const HD_PATH_PATTERNS: &[&str] = &["m/44'/397'/{N}'", "m/44'/397'/0'/0'/{N}'"];
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = DiscoverSeedPhrasePathContext)]
+pub struct DiscoverSeedPhrasePath {
+    /// Enter the seed-phrase for this account
+    master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Account ID whose access keys are checked (the implicit accounts of the derived keys are checked when omitted)
+    account_id: Option<crate::types::account_id::AccountId>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Number of account indexes checked for every HD path pattern (10 by default)
+    max_index: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Additional comma-separated HD path patterns, where {N} is replaced with the account index (e.g. m/44'/397'/{N}'/0')
+    hd_path_patterns: Option<crate::types::vec_string::VecString>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct DiscoverSeedPhrasePathContext(crate::network::NetworkContext);
+
+impl DiscoverSeedPhrasePathContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<DiscoverSeedPhrasePath as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let hd_paths = candidate_hd_paths(
+            scope.max_index.unwrap_or(DEFAULT_MAX_INDEX),
+            scope
+                .hd_path_patterns
+                .clone()
+                .map(Vec::from)
+                .unwrap_or_default(),
+        )?;
+        let key_pairs = hd_paths
+            .into_iter()
+            .map(|hd_path| {
+                crate::common::get_key_pair_properties_from_seed_phrase(
+                    hd_path,
+                    scope.master_seed_phrase.clone(),
+                    scope.seed_phrase_language,
+                    scope.seed_phrase_passphrase.as_deref(),
+                )
+            })
+            .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+
+        let global_options = previous_context.1.clone();
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                eprintln!(
+                    "Checking {} keys derived from the seed phrase on <{}> network ...",
+                    key_pairs.len(),
+                    network_config.network_name
+                );
+                let matches = find_matching_key_pairs(network_config, &account_id, &key_pairs)?;
+
+                if let crate::common::OutputFormat::Json = global_options.output_format {
+                    crate::common::print_json_output(&serde_json::json!({
+                        "network_name": network_config.network_name,
+                        "checked_hd_paths": key_pairs.len(),
+                        "matches": matches
+                            .iter()
+                            .map(|(account_id, key_pair_properties)| serde_json::json!({
+                                "account_id": account_id,
+                                "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path,
+                                "public_key": key_pair_properties.public_key_str,
+                            }))
+                            .collect::<Vec<_>>(),
+                    }))?;
+                } else if matches.is_empty() {
+                    eprintln!(
+                        "\nNone of the {} checked HD paths derives a key of {}. Try more account indexes with --max-index, other patterns with --hd-path-patterns, or check the seed phrase passphrase and language.",
+                        key_pairs.len(),
+                        account_id.as_ref().map_or_else(
+                            || "an existing implicit account".to_string(),
+                            |account_id| format!("<{}>", account_id)
+                        )
+                    );
+                } else {
+                    let mut table = prettytable::Table::new();
+                    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+                    table.set_titles(prettytable::row!["Account ID", "HD path", "Public key"]);
+                    for (account_id, key_pair_properties) in &matches {
+                        table.add_row(prettytable::row![
+                            Fg->account_id,
+                            key_pair_properties.seed_phrase_hd_path,
+                            key_pair_properties.public_key_str
+                        ]);
+                    }
+                    eprintln!();
+                    table.printstd();
+                }
+                if matches.is_empty() {
+                    return Ok(());
+                }
+
+                if global_options.scripting_mode {
+                    eprintln!(
+                        "\nTo import the account, run `near account import-account using-seed-phrase` with the matching --seed-phrase-hd-path."
+                    );
+                    return Ok(());
+                }
+                let Some((account_id, key_pair_properties)) = select_match(matches)? else {
+                    return Ok(());
+                };
+                super::save_access_key(
+                    account_id,
+                    &serde_json::to_string(&key_pair_properties)?,
+                    &key_pair_properties.public_key_str,
+                    network_config.clone(),
+                    credentials_home_dir.clone(),
+                )
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<DiscoverSeedPhrasePathContext> for crate::network::NetworkContext {
+    fn from(item: DiscoverSeedPhrasePathContext) -> Self {
+        item.0
+    }
+}
+
+impl DiscoverSeedPhrasePath {
+    fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, I want to check the access keys of my account")]
+            Yes,
+            #[strum(to_string = "No, I want to check the implicit accounts of the derived keys")]
+            No,
+        }
+        let select_choose_input = Select::new(
+            "Do you know the account ID that uses this seed phrase?",
+            vec![ConfirmOptions::Yes, ConfirmOptions::No],
+        )
+        .prompt()?;
+        if let ConfirmOptions::Yes = select_choose_input {
+            let account_id: crate::types::account_id::AccountId =
+                CustomType::new("What is the account ID?").prompt()?;
+            Ok(Some(account_id))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn input_max_index(_context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<u64>> {
+        Ok(None)
+    }
+
+    fn input_hd_path_patterns(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::vec_string::VecString>> {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
+        let hd_path_patterns = Text::new(
+            "Enter additional comma-separated HD path patterns, where {N} is the account index (leave blank to check only the standard paths)",
+        )
+        .prompt()?;
+        if hd_path_patterns.trim().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(hd_path_patterns.parse()?))
+    }
+}
+
+/// Expands the standard and the custom patterns for the account indexes `0..max_index`,
+/// skipping the paths that were already listed.
+fn candidate_hd_paths(
+    max_index: u64,
+    custom_patterns: Vec<String>,
+) -> color_eyre::eyre::Result<Vec<crate::types::slip10::BIP32Path>> {
+    let mut hd_paths: Vec<crate::types::slip10::BIP32Path> = vec![];
+    let patterns = HD_PATH_PATTERNS
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .chain(
+            custom_patterns
+                .into_iter()
+                .filter(|pattern| !pattern.is_empty()),
+        );
+    for pattern in patterns {
+        let indexes = if pattern.contains("{N}") {
+            0..max_index
+        } else {
+            0..1
+        };
+        for index in indexes {
+            let hd_path: crate::types::slip10::BIP32Path = pattern
+                .replace("{N}", &index.to_string())
+                .parse()
+                .map_err(|err| {
+                    crate::common::CliError::new(
+                        crate::common::ErrorCategory::UserInput,
+                        format!("The HD path pattern <{}> is not valid: {}", pattern, err),
+                    )
+                })?;
+            if !hd_paths.contains(&hd_path) {
+                hd_paths.push(hd_path);
+            }
+        }
+    }
+    Ok(hd_paths)
+}
+
+/// Returns the derived key pairs that are access keys of the account, or that belong to an
+/// existing implicit account when no account ID is given.
+fn find_matching_key_pairs(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &Option<near_primitives::types::AccountId>,
+    key_pairs: &[crate::common::KeyPairProperties],
+) -> color_eyre::eyre::Result<
+    Vec<(
+        near_primitives::types::AccountId,
+        crate::common::KeyPairProperties,
+    )>,
+> {
+    let json_rpc_client = network_config.json_rpc_client()?;
+    if let Some(account_id) = account_id {
+        let access_key_list = json_rpc_client
+            .blocking_call_view_access_key_list(
+                account_id,
+                near_primitives::types::Finality::Final.into(),
+            )
+            .wrap_err_with(|| format!("Failed to fetch query AccessKeyList for {}", account_id))?
+            .access_key_list_view()?;
+        return Ok(key_pairs
+            .iter()
+            .filter(|key_pair_properties| {
+                access_key_list.keys.iter().any(|access_key| {
+                    access_key.public_key.to_string() == key_pair_properties.public_key_str
+                })
+            })
+            .map(|key_pair_properties| (account_id.clone(), key_pair_properties.clone()))
+            .collect());
+    }
+
+    let mut matches = vec![];
+    for key_pair_properties in key_pairs {
+        match json_rpc_client.blocking_call_view_account(
+            &key_pair_properties.implicit_account_id,
+            near_primitives::types::Finality::Final.into(),
+        ) {
+            Ok(_) => matches.push((
+                key_pair_properties.implicit_account_id.clone(),
+                key_pair_properties.clone(),
+            )),
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                ),
+            )) => {}
+            Err(err) => {
+                return Err(err).wrap_err_with(|| {
+                    format!(
+                        "Failed to fetch query ViewAccount for {}",
+                        key_pair_properties.implicit_account_id
+                    )
+                })
+            }
+        }
+    }
+    Ok(matches)
+}
+
+fn select_match(
+    mut matches: Vec<(
+        near_primitives::types::AccountId,
+        crate::common::KeyPairProperties,
+    )>,
+) -> color_eyre::eyre::Result<
+    Option<(
+        near_primitives::types::AccountId,
+        crate::common::KeyPairProperties,
+    )>,
+> {
+    let mut options = matches
+        .iter()
+        .map(|(account_id, key_pair_properties)| {
+            format!(
+                "Import the access key of <{}> derived with {}",
+                account_id, key_pair_properties.seed_phrase_hd_path
+            )
+        })
+        .collect::<Vec<_>>();
+    options.push("Don't import any access key".to_string());
+    eprintln!();
+    let selected =
+        Select::new("Which access key do you want to import?", options.clone()).prompt()?;
+    Ok(options
+        .iter()
+        .position(|option| *option == selected)
+        .filter(|index| *index < matches.len())
+        .map(|index| matches.swap_remove(index)))
+}
+
+impl SeedPhraseOptions for DiscoverSeedPhrasePath {
+    type InputContext = crate::GlobalContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate_hd_path_strings(max_index: u64, custom_patterns: &[&str]) -> Vec<String> {
+        candidate_hd_paths(
+            max_index,
+            custom_patterns
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+        )
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn expands_standard_patterns() {
+        assert_eq!(
+            candidate_hd_path_strings(2, &[]),
+            [
+                "m/44'/397'/0'",
+                "m/44'/397'/1'",
+                "m/44'/397'/0'/0'/0'",
+                "m/44'/397'/0'/0'/1'"
+            ]
+        );
+        assert!(candidate_hd_path_strings(0, &[]).is_empty());
+    }
+
+    #[test]
+    fn expands_custom_patterns_without_duplicates() {
+        assert_eq!(
+            candidate_hd_path_strings(1, &["m/44'/397'/{N}'/0'", "", "m/44'/397'/0'", "m/1'"]),
+            [
+                "m/44'/397'/0'",
+                "m/44'/397'/0'/0'/0'",
+                "m/44'/397'/0'/0'",
+                "m/1'"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let err = candidate_hd_paths(1, vec!["m/44'/x'/{N}'".to_string()]).unwrap_err();
+        assert_eq!(
+            crate::common::categorize_error(&err).unwrap().category,
+            crate::common::ErrorCategory::UserInput
+        );
+    }
+}
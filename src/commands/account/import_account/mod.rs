@@ -5,6 +5,7 @@ use color_eyre::eyre::Context;
 use inquire::{CustomType, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod discover_seed_phrase_path;
 mod using_private_key;
 mod using_seed_phrase;
 mod using_web_wallet;
@@ -36,6 +37,11 @@ pub enum ImportAccountActions {
     ))]
     /// Import existing account using a private key
     UsingPrivateKey(self::using_private_key::LoginFromPrivateKey),
+    #[strum_discriminants(strum(
+        message = "discover-seed-phrase-path - Find the HD path of a seed phrase by checking the derived keys on-chain"
+    ))]
+    /// Find the HD path of a seed phrase by checking the derived keys on-chain
+    DiscoverSeedPhrasePath(self::discover_seed_phrase_path::DiscoverSeedPhrasePath),
 }
 
 pub fn login(
@@ -44,9 +50,16 @@ pub fn login(
     key_pair_properties_buf: &str,
     public_key_str: &str,
     error_message: &str,
+    scripting_mode: bool,
 ) -> crate::CliResult {
     let public_key: near_crypto::PublicKey = near_crypto::PublicKey::from_str(public_key_str)?;
 
+    if scripting_mode {
+        return Err(color_eyre::eyre::eyre!(
+            "Importing an account requires entering the account ID interactively, which is not available in scripting mode"
+        ));
+    }
+
     let account_id = loop {
         let account_id_from_cli = input_account_id()?;
         eprintln!();
@@ -54,6 +67,7 @@ pub fn login(
             account_id_from_cli.clone(),
             public_key.clone(),
             network_config.clone(),
+            scripting_mode,
         )
         .is_err()
         {
@@ -18,6 +18,7 @@ impl LoginFromPrivateKeyContext {
         scope: &<LoginFromPrivateKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let config = previous_context.0.clone();
+        let scripting_mode = previous_context.1.scripting_mode;
         let private_key: near_crypto::SecretKey = scope.private_key.clone().into();
         let public_key = private_key.public_key();
         let key_pair_properties = KeyPairProperties {
@@ -36,12 +37,13 @@ impl LoginFromPrivateKeyContext {
                         &key_pair_properties_buf,
                         &public_key.to_string(),
                         error_message,
+                        scripting_mode,
                     )
                 }
             });
 
         Ok(Self(crate::network::NetworkContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_network_callback,
         }))
     }
@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use inquire::Text;
 use std::str::FromStr;
 
@@ -9,6 +10,14 @@ pub struct LoginFromSeedPhrase {
     master_seed_phrase: String,
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     #[interactive_clap(named_arg)]
     /// Select network
@@ -24,11 +33,14 @@ impl LoginFromSeedPhraseContext {
         scope: &<LoginFromSeedPhrase as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let config = previous_context.0.clone();
+        let scripting_mode = previous_context.1.scripting_mode;
         let seed_phrase_hd_path = scope.seed_phrase_hd_path.clone();
         let master_seed_phrase = scope.master_seed_phrase.clone();
         let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
             seed_phrase_hd_path,
             master_seed_phrase,
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
         let key_pair_properties_buf = serde_json::to_string(&key_pair_properties).unwrap();
         let error_message = "\nIt is currently not possible to verify the account access key.\nYou may have entered an incorrect account_id.\nYou have the option to reconfirm your account or save your access key information.\n";
@@ -42,12 +54,13 @@ impl LoginFromSeedPhraseContext {
                         &key_pair_properties_buf,
                         &key_pair_properties.public_key_str,
                         error_message,
+                        scripting_mode,
                     )
                 }
             });
 
         Ok(Self(crate::network::NetworkContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_network_callback,
         }))
     }
@@ -61,8 +74,9 @@ impl From<LoginFromSeedPhraseContext> for crate::network::NetworkContext {
 
 impl LoginFromSeedPhrase {
     pub fn input_seed_phrase_hd_path(
-        _context: &crate::GlobalContext,
+        context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context.1.ensure_interactive_mode("--seed-phrase-hd-path")?;
         Ok(Some(
             crate::types::slip10::BIP32Path::from_str(
                 &Text::new("Enter seed phrase HD Path (if you not sure leave blank for default)")
@@ -74,3 +88,11 @@ impl LoginFromSeedPhrase {
         ))
     }
 }
+
+impl SeedPhraseOptions for LoginFromSeedPhrase {
+    type InputContext = crate::GlobalContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        context
+    }
+}
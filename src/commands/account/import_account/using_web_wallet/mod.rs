@@ -16,6 +16,7 @@ impl LoginFromWebWalletContext {
         _scope: &<LoginFromWebWallet as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let config = previous_context.0.clone();
+        let scripting_mode = previous_context.1.scripting_mode;
 
         let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
             std::sync::Arc::new({
@@ -43,12 +44,13 @@ impl LoginFromWebWalletContext {
                         &key_pair_properties_buf,
                         &key_pair_properties.public_key_str,
                         &error_message,
+                        scripting_mode,
                     )
                 }
             });
 
         Ok(Self(crate::network::NetworkContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_network_callback,
         }))
     }
@@ -23,11 +23,13 @@ impl ViewListKeysContext {
         scope: &<ViewListKeys as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let output_format = previous_context.1.output_format.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
                 let access_key_list = network_config
-                    .json_rpc_client()
+                    .json_rpc_client()?
                     .blocking_call_view_access_key_list(
                         &account_id,
                         block_reference.clone(),
@@ -40,12 +42,20 @@ impl ViewListKeysContext {
                     })?
                     .access_key_list_view()?;
 
-                crate::common::display_access_key_list(&access_key_list.keys);
-                Ok(())
+                let local_keys = crate::commands::local_keys::storage::local_key_availability(
+                    &credentials_home_dir,
+                    network_config,
+                    &account_id,
+                )?;
+                crate::common::display_access_key_list(
+                    &access_key_list.keys,
+                    &local_keys,
+                    &output_format,
+                )
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -6,7 +6,11 @@ mod delete_account;
 mod delete_key;
 mod import_account;
 mod list_keys;
+mod rotate_key;
+mod sign_message;
 pub mod storage_management;
+mod verify_message;
+mod view_account_diff;
 mod view_account_summary;
 
 pub const MIN_ALLOWED_TOP_LEVEL_ACCOUNT_LENGTH: usize = 32;
@@ -29,6 +33,11 @@ pub enum AccountActions {
     ))]
     /// View properties for an account
     ViewAccountSummary(self::view_account_summary::ViewAccountSummary),
+    #[strum_discriminants(strum(
+        message = "view-account-diff       - Compare the properties of an account at two blocks"
+    ))]
+    /// Compare the properties of an account at two blocks
+    ViewAccountDiff(self::view_account_diff::ViewAccountDiff),
     #[strum_discriminants(strum(
         message = "import-account          - Import existing account (a.k.a. \"sign in\")"
     ))]
@@ -55,6 +64,21 @@ pub enum AccountActions {
     ))]
     /// Delete an access key from an account
     DeleteKey(self::delete_key::DeleteKeyCommand),
+    #[strum_discriminants(strum(
+        message = "rotate-key              - Replace an access key of an account with a new one in a single transaction"
+    ))]
+    /// Replace an access key of an account with a new one in a single transaction
+    RotateKey(self::rotate_key::RotateKeyCommand),
+    #[strum_discriminants(strum(
+        message = "sign-message            - Sign an off-chain message (NEP-413)"
+    ))]
+    /// Sign an off-chain message (NEP-413)
+    SignMessage(self::sign_message::SignMessage),
+    #[strum_discriminants(strum(
+        message = "verify-message          - Verify the signature of an off-chain message (NEP-413)"
+    ))]
+    /// Verify the signature of an off-chain message (NEP-413)
+    VerifyMessage(self::verify_message::VerifyMessage),
     #[strum_discriminants(strum(
         message = "manage-storage-deposit  - Storage management: deposit, withdrawal, balance review"
     ))]
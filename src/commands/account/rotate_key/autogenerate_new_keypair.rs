@@ -0,0 +1,66 @@
+use inquire::Select;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyCommandContext)]
+#[interactive_clap(output_context = GenerateKeypairContext)]
+pub struct GenerateKeypair {
+    #[interactive_clap(value_enum)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Where do you want to save the new access key?
+    save_to: crate::commands::local_keys::storage::KeyStorage,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct GenerateKeypairContext(crate::commands::ActionContext);
+
+impl GenerateKeypairContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyCommandContext,
+        scope: &<GenerateKeypair as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let key_pair_properties = crate::common::generate_keypair()?;
+        let new_key = super::NewKey {
+            public_key: key_pair_properties.public_key_str.parse()?,
+            key_pair: Some((scope.save_to, serde_json::to_string(&key_pair_properties)?)),
+            seed_phrase_hd_path: None,
+        };
+        Ok(Self(super::rotate_key_action_context(
+            previous_context,
+            new_key,
+        )))
+    }
+}
+
+impl From<GenerateKeypairContext> for crate::commands::ActionContext {
+    fn from(item: GenerateKeypairContext) -> Self {
+        item.0
+    }
+}
+
+impl GenerateKeypair {
+    fn input_save_to(
+        _context: &super::RotateKeyCommandContext,
+    ) -> color_eyre::eyre::Result<Option<crate::commands::local_keys::storage::KeyStorage>> {
+        input_key_storage()
+    }
+}
+
+pub fn input_key_storage(
+) -> color_eyre::eyre::Result<Option<crate::commands::local_keys::storage::KeyStorage>> {
+    #[allow(unused_mut)]
+    let mut variants = vec![
+        crate::commands::local_keys::storage::KeyStorage::LegacyKeychain,
+        crate::commands::local_keys::storage::KeyStorage::EncryptedKeychain,
+    ];
+    #[cfg(target_os = "macos")]
+    variants.insert(
+        0,
+        crate::commands::local_keys::storage::KeyStorage::MacosKeychain,
+    );
+    let selected =
+        Select::new("Where do you want to save the new access key?", variants).prompt()?;
+    Ok(Some(selected))
+}
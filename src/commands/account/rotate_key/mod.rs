@@ -0,0 +1,243 @@
+use color_eyre::eyre::WrapErr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+mod autogenerate_new_keypair;
+#[cfg(feature = "ledger")]
+mod use_ledger;
+mod use_manually_provided_seed_phrase;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RotateKeyCommandContext)]
+pub struct RotateKeyCommand {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which account should You rotate an access key of?
+    owner_account_id: crate::types::account_id::AccountId,
+    /// Which access key do you want to replace?
+    old_public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(subcommand)]
+    new_key_mode: NewKeyMode,
+}
+
+impl RotateKeyCommand {
+    fn input_owner_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(
+            &context.0,
+            "Which account should You rotate an access key of?",
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateKeyCommandContext {
+    global_context: crate::GlobalContext,
+    owner_account_id: near_primitives::types::AccountId,
+    old_public_key: near_crypto::PublicKey,
+}
+
+impl RotateKeyCommandContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<RotateKeyCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            owner_account_id: scope.owner_account_id.clone().into(),
+            old_public_key: scope.old_public_key.clone().into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, EnumDiscriminants, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = RotateKeyCommandContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// How do you want to get the new access key?
+pub enum NewKeyMode {
+    #[strum_discriminants(strum(
+        message = "autogenerate-new-keypair          - Automatically generate a key pair"
+    ))]
+    /// Automatically generate a key pair
+    AutogenerateNewKeypair(self::autogenerate_new_keypair::GenerateKeypair),
+    #[strum_discriminants(strum(
+        message = "use-manually-provided-seed-prase  - Use the provided seed phrase manually"
+    ))]
+    /// Use the provided seed phrase manually
+    UseManuallyProvidedSeedPhrase(self::use_manually_provided_seed_phrase::RotateWithSeedPhrase),
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(message = "use-ledger                        - Use a ledger"))]
+    /// Use a ledger
+    UseLedger(self::use_ledger::RotateWithLedger),
+}
+
+#[derive(Debug, Clone)]
+pub struct NewKey {
+    pub public_key: near_crypto::PublicKey,
+    /// The key pair (in the JSON format of the keychains) and the keychain to save it to
+    pub key_pair: Option<(crate::commands::local_keys::storage::KeyStorage, String)>,
+    /// The HD path of a Ledger key: only the path is saved, the private key stays on the device
+    pub seed_phrase_hd_path: Option<crate::types::slip10::BIP32Path>,
+}
+
+/// A single transaction replaces the old key with the new one (with the same permission), so the
+/// account cannot end up with none of the keys or with both of them.
+pub fn rotate_key_action_context(
+    previous_context: RotateKeyCommandContext,
+    new_key: NewKey,
+) -> crate::commands::ActionContext {
+    let RotateKeyCommandContext {
+        global_context,
+        owner_account_id,
+        old_public_key,
+    } = previous_context;
+    let credentials_home_dir = global_context.0.credentials_home_dir.clone();
+
+    let on_after_getting_network_callback: crate::commands::OnAfterGettingNetworkCallback =
+        std::sync::Arc::new({
+            let owner_account_id = owner_account_id.clone();
+            let old_public_key = old_public_key.clone();
+            let new_public_key = new_key.public_key.clone();
+            move |network_config| {
+                let old_access_key = network_config
+                    .json_rpc_client()?
+                    .blocking_call_view_access_key(
+                        &owner_account_id,
+                        &old_public_key,
+                        near_primitives::types::Finality::Final.into(),
+                    )
+                    .wrap_err_with(|| {
+                        format!(
+                            "Failed to fetch the access key {} of <{}>",
+                            old_public_key, owner_account_id
+                        )
+                    })?
+                    .access_key_view()?;
+                Ok(crate::commands::PrepopulatedTransaction {
+                    signer_id: owner_account_id.clone(),
+                    receiver_id: owner_account_id.clone(),
+                    actions: vec![
+                        near_primitives::transaction::Action::AddKey(
+                            near_primitives::transaction::AddKeyAction {
+                                public_key: new_public_key.clone(),
+                                access_key: near_primitives::account::AccessKey {
+                                    nonce: 0,
+                                    permission: old_access_key.permission.into(),
+                                },
+                            },
+                        ),
+                        near_primitives::transaction::Action::DeleteKey(
+                            near_primitives::transaction::DeleteKeyAction {
+                                public_key: old_public_key.clone(),
+                            },
+                        ),
+                    ],
+                })
+            }
+        });
+
+    let on_before_signing_callback: crate::commands::OnBeforeSigningCallback = std::sync::Arc::new(
+        {
+            let old_public_key = old_public_key.clone();
+            move |prepopulated_unsigned_transaction, _network_config| {
+                let is_full_access_key = prepopulated_unsigned_transaction.actions.iter().any(|action| {
+                    matches!(
+                        action,
+                        near_primitives::transaction::Action::AddKey(near_primitives::transaction::AddKeyAction {
+                            access_key: near_primitives::account::AccessKey {
+                                permission: near_primitives::account::AccessKeyPermission::FullAccess,
+                                ..
+                            },
+                            ..
+                        })
+                    )
+                });
+                // A function-call access key cannot sign the transaction, so another full access
+                // key has to be used then
+                if is_full_access_key
+                    && prepopulated_unsigned_transaction.public_key != old_public_key
+                {
+                    return Err(crate::common::CliError::new(
+                        crate::common::ErrorCategory::LocalKeyStorage,
+                        format!(
+                            "The transaction has to be signed with the key that is rotated ({}), but the signer found the key {}",
+                            old_public_key, prepopulated_unsigned_transaction.public_key
+                        ),
+                    )
+                    .into());
+                }
+                Ok(())
+            }
+        },
+    );
+
+    let on_before_sending_transaction_callback: crate::transaction_signature_options::OnBeforeSendingTransactionCallback =
+        std::sync::Arc::new({
+            let credentials_home_dir = credentials_home_dir.clone();
+            let owner_account_id = owner_account_id.clone();
+            move |_signed_transaction, network_config, storage_message| {
+                if let Some((key_storage, key_pair)) = &new_key.key_pair {
+                    *storage_message = crate::commands::local_keys::storage::save_key_pair(
+                        *key_storage,
+                        &credentials_home_dir,
+                        network_config,
+                        &owner_account_id,
+                        &new_key.public_key,
+                        key_pair,
+                    )
+                    .wrap_err_with(|| {
+                        format!(
+                            "Failed to save the new access key: {}",
+                            &new_key.public_key
+                        )
+                    })?;
+                } else if let Some(seed_phrase_hd_path) = &new_key.seed_phrase_hd_path {
+                    *storage_message =
+                        crate::commands::local_keys::storage::save_ledger_hd_path(
+                            &credentials_home_dir,
+                            network_config,
+                            &owner_account_id,
+                            &new_key.public_key,
+                            seed_phrase_hd_path,
+                        )
+                        .wrap_err_with(|| {
+                            format!(
+                                "Failed to save the HD path of the new access key: {}",
+                                &new_key.public_key
+                            )
+                        })?;
+                }
+                Ok(())
+            }
+        });
+
+    let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback =
+        std::sync::Arc::new(move |_outcome_view, network_config| {
+            let old_local_keys = crate::commands::local_keys::storage::list_local_keys(
+                &credentials_home_dir,
+                network_config,
+            )?
+            .into_iter()
+            .filter(|local_key| {
+                local_key.account_id == owner_account_id && local_key.public_key == old_public_key
+            });
+            for local_key in old_local_keys {
+                crate::commands::local_keys::storage::delete_key_pair(&local_key)?;
+                eprintln!(
+                    "The old access key {} was deleted from the {}",
+                    old_public_key, local_key.storage
+                );
+            }
+            Ok(())
+        });
+
+    crate::commands::ActionContext {
+        global_context,
+        on_after_getting_network_callback,
+        on_before_signing_callback,
+        on_before_sending_transaction_callback,
+        on_after_sending_transaction_callback,
+    }
+}
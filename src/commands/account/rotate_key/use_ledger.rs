@@ -0,0 +1,78 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyCommandContext)]
+#[interactive_clap(output_context = RotateWithLedgerContext)]
+pub struct RotateWithLedger {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Enter seed phrase HD Path of the new key on the Ledger device
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct RotateWithLedgerContext(crate::commands::ActionContext);
+
+impl RotateWithLedgerContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyCommandContext,
+        scope: &<RotateWithLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let seed_phrase_hd_path = scope.seed_phrase_hd_path.clone();
+        eprintln!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone().into()).map_err(
+            |near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            },
+        )?;
+        // The private key stays on the Ledger device, so only the HD path is saved
+        let new_key = super::NewKey {
+            public_key: near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+                public_key.to_bytes(),
+            )),
+            key_pair: None,
+            seed_phrase_hd_path: Some(seed_phrase_hd_path),
+        };
+        Ok(Self(super::rotate_key_action_context(
+            previous_context,
+            new_key,
+        )))
+    }
+}
+
+impl From<RotateWithLedgerContext> for crate::commands::ActionContext {
+    fn from(item: RotateWithLedgerContext) -> Self {
+        item.0
+    }
+}
+
+impl RotateWithLedger {
+    fn input_seed_phrase_hd_path(
+        context: &super::RotateKeyCommandContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--seed-phrase-hd-path")?;
+        loop {
+            let seed_phrase_hd_path = inquire::Text::new(
+                "Enter seed phrase HD Path of the new key (if you not sure leave blank for default)",
+            )
+            .with_initial_value("44'/397'/0'/0'/1'")
+            .prompt()?;
+            match crate::types::slip10::BIP32Path::from_str(&seed_phrase_hd_path) {
+                Ok(seed_phrase_hd_path) => return Ok(Some(seed_phrase_hd_path)),
+                Err(err) => eprintln!("Invalid seed phrase HD Path: {}", err),
+            }
+        }
+    }
+}
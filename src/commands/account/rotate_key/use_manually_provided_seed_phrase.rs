@@ -0,0 +1,75 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::RotateKeyCommandContext)]
+#[interactive_clap(output_context = RotateWithSeedPhraseContext)]
+pub struct RotateWithSeedPhrase {
+    /// Enter the seed-phrase for the new access key
+    master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(value_enum)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Where do you want to save the new access key?
+    save_to: crate::commands::local_keys::storage::KeyStorage,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct RotateWithSeedPhraseContext(crate::commands::ActionContext);
+
+impl RotateWithSeedPhraseContext {
+    pub fn from_previous_context(
+        previous_context: super::RotateKeyCommandContext,
+        scope: &<RotateWithSeedPhrase as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let seed_phrase_hd_path_default =
+            crate::types::slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
+        let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
+            seed_phrase_hd_path_default,
+            scope.master_seed_phrase.clone(),
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
+        )?;
+        let new_key = super::NewKey {
+            public_key: key_pair_properties.public_key_str.parse()?,
+            key_pair: Some((scope.save_to, serde_json::to_string(&key_pair_properties)?)),
+            seed_phrase_hd_path: None,
+        };
+        Ok(Self(super::rotate_key_action_context(
+            previous_context,
+            new_key,
+        )))
+    }
+}
+
+impl From<RotateWithSeedPhraseContext> for crate::commands::ActionContext {
+    fn from(item: RotateWithSeedPhraseContext) -> Self {
+        item.0
+    }
+}
+
+impl RotateWithSeedPhrase {
+    fn input_save_to(
+        _context: &super::RotateKeyCommandContext,
+    ) -> color_eyre::eyre::Result<Option<crate::commands::local_keys::storage::KeyStorage>> {
+        super::autogenerate_new_keypair::input_key_storage()
+    }
+}
+
+impl SeedPhraseOptions for RotateWithSeedPhrase {
+    type InputContext = super::RotateKeyCommandContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
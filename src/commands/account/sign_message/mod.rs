@@ -0,0 +1,141 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod sign_with_keychain;
+#[cfg(feature = "ledger")]
+mod sign_with_ledger;
+mod sign_with_private_key;
+mod sign_with_seed_phrase;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SignMessageContext)]
+pub struct SignMessage {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the signer account ID?
+    signer_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    /// What message do you want to sign?
+    message: String,
+    #[interactive_clap(long)]
+    /// Who is the recipient of the message (e.g. the account of the app)?
+    recipient: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Base64 encoded 32 bytes of the nonce (a random nonce is used when omitted)
+    nonce: Option<crate::types::nep413::Nonce>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// URL the signed message is passed to by wallets
+    callback_url: Option<String>,
+    #[interactive_clap(subcommand)]
+    sign_with: SignMessageWith,
+}
+
+#[derive(Clone)]
+pub struct SignMessageContext {
+    global_context: crate::GlobalContext,
+    signer_account_id: near_primitives::types::AccountId,
+    payload: crate::types::nep413::Payload,
+}
+
+impl SignMessageContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SignMessage as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            signer_account_id: scope.signer_account_id.clone().into(),
+            payload: crate::types::nep413::Payload {
+                message: scope.message.clone(),
+                nonce: scope
+                    .nonce
+                    .unwrap_or_else(crate::types::nep413::Nonce::random),
+                recipient: scope.recipient.clone(),
+                callback_url: scope.callback_url.clone(),
+            },
+        })
+    }
+}
+
+impl SignMessage {
+    fn input_signer_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(&context.0, "What is the signer account ID?")
+    }
+
+    fn input_nonce(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::nep413::Nonce>> {
+        Ok(None)
+    }
+
+    fn input_callback_url(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = SignMessageContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Select a key to sign the message with
+pub enum SignMessageWith {
+    #[strum_discriminants(strum(
+        message = "sign-with-keychain       - Sign the message with a key saved in the keychain"
+    ))]
+    /// Sign the message with a key saved in the legacy, encrypted or macOS keychain
+    SignWithKeychain(self::sign_with_keychain::SignKeychain),
+    #[cfg(feature = "ledger")]
+    #[strum_discriminants(strum(
+        message = "sign-with-ledger         - Sign the message with Ledger Nano device"
+    ))]
+    /// Sign the message with Ledger Nano device
+    SignWithLedger(self::sign_with_ledger::SignLedger),
+    #[strum_discriminants(strum(
+        message = "sign-with-seed-phrase    - Sign the message using the seed phrase"
+    ))]
+    /// Sign the message using the seed phrase
+    SignWithSeedPhrase(self::sign_with_seed_phrase::SignSeedPhrase),
+    #[strum_discriminants(strum(
+        message = "sign-with-private-key    - Sign the message with a plaintext private key"
+    ))]
+    /// Sign the message with a plaintext private key
+    SignWithPrivateKey(self::sign_with_private_key::SignPrivateKey),
+}
+
+/// Signs the NEP-413 payload and prints the signed message in the format wallets return it.
+fn print_signed_message(
+    context: &SignMessageContext,
+    secret_key: &near_crypto::SecretKey,
+) -> crate::CliResult {
+    let signature = context.payload.sign(secret_key)?;
+    print_signature(context, &secret_key.public_key(), &signature)
+}
+
+/// Prints the signed message in the format wallets return it.
+fn print_signature(
+    context: &SignMessageContext,
+    public_key: &near_crypto::PublicKey,
+    signature: &crate::types::nep413::Signature,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = context.global_context.1.output_format {
+        return crate::common::print_json_output(&serde_json::json!({
+            "accountId": context.signer_account_id,
+            "publicKey": public_key.to_string(),
+            "signature": signature.to_string(),
+            "message": context.payload.message,
+            "recipient": context.payload.recipient,
+            "nonce": context.payload.nonce.to_string(),
+            "callbackUrl": context.payload.callback_url,
+        }));
+    }
+    eprintln!("\nThe message was signed successfully.");
+    println!("Account ID: {}", context.signer_account_id);
+    println!("Public key: {}", public_key);
+    println!("Nonce: {}", context.payload.nonce);
+    println!("Signature: {}", signature);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use color_eyre::eyre::WrapErr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::SignMessageContext)]
+#[interactive_clap(output_context = SignKeychainContext)]
+pub struct SignKeychain {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Public key of the stored access key (required when several keys of the account are stored)
+    signer_public_key: Option<crate::types::public_key::PublicKey>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct SignKeychainContext(crate::network::NetworkContext);
+
+impl SignKeychainContext {
+    pub fn from_previous_context(
+        previous_context: super::SignMessageContext,
+        scope: &<SignKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let global_context = previous_context.global_context.clone();
+        let signer_public_key: Option<near_crypto::PublicKey> =
+            scope.signer_public_key.clone().map(Into::into);
+
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let mut local_keys = crate::commands::local_keys::storage::list_local_keys(
+                    &previous_context.global_context.0.credentials_home_dir,
+                    network_config,
+                )?
+                .into_iter()
+                .filter(|local_key| {
+                    local_key.account_id == previous_context.signer_account_id
+                        && signer_public_key
+                            .as_ref()
+                            .map_or(true, |public_key| *public_key == local_key.public_key)
+                })
+                .collect::<Vec<_>>();
+                let local_key = match local_keys.len() {
+                    0 => {
+                        return Err(crate::common::CliError::new(
+                            crate::common::ErrorCategory::LocalKeyStorage,
+                            format!(
+                                "There is no access key of <{}> stored for <{}> network",
+                                previous_context.signer_account_id, network_config.network_name
+                            ),
+                        )
+                        .into())
+                    }
+                    1 => local_keys.remove(0),
+                    _ if previous_context.global_context.1.scripting_mode => {
+                        return Err(crate::common::CliError::new(
+                            crate::common::ErrorCategory::UserInput,
+                            format!(
+                                "Several access keys of <{}> are stored, select one with --signer-public-key",
+                                previous_context.signer_account_id
+                            ),
+                        )
+                        .into())
+                    }
+                    _ => {
+                        let public_keys = local_keys
+                            .iter()
+                            .map(|local_key| local_key.public_key.to_string())
+                            .collect::<Vec<_>>();
+                        let selected = inquire::Select::new(
+                            "Which access key do you want to sign the message with?",
+                            public_keys.clone(),
+                        )
+                        .prompt()?;
+                        let index = public_keys
+                            .iter()
+                            .position(|public_key| *public_key == selected)
+                            .expect("The selected public key is one of the options");
+                        local_keys.remove(index)
+                    }
+                };
+                let key_pair = crate::commands::local_keys::storage::read_key_pair(&local_key)?;
+                let account_key_pair: crate::transaction_signature_options::AccountKeyPair =
+                    serde_json::from_str(&key_pair).wrap_err_with(|| {
+                        format!(
+                            "Error reading the access key <{}> of <{}>",
+                            local_key.public_key, local_key.account_id
+                        )
+                    })?;
+                super::print_signed_message(&previous_context, &account_key_pair.private_key)
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<SignKeychainContext> for crate::network::NetworkContext {
+    fn from(item: SignKeychainContext) -> Self {
+        item.0
+    }
+}
+
+impl SignKeychain {
+    fn input_signer_public_key(
+        _context: &super::SignMessageContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+const CLA: u8 = 0x80;
+/// The instruction of the NEAR Ledger app which signs NEP-413 messages (near-ledger 0.2 only
+/// sends the instruction which signs transactions)
+const INS_SIGN_NEP413_MESSAGE: u8 = 7;
+const P1_SIGN_NORMAL: u8 = 0;
+const P1_SIGN_NORMAL_LAST_CHUNK: u8 = 0x80;
+const NETWORK_ID: u8 = b'W';
+const RETURN_CODE_OK: u16 = 0x9000;
+const CHUNK_SIZE: usize = 128;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::SignMessageContext)]
+#[interactive_clap(output_context = SignLedgerContext)]
+pub struct SignLedger {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+}
+
+#[derive(Clone)]
+pub struct SignLedgerContext;
+
+impl SignLedgerContext {
+    pub fn from_previous_context(
+        previous_context: super::SignMessageContext,
+        scope: &<SignLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let seed_phrase_hd_path: slip10::BIP32Path = scope.seed_phrase_hd_path.clone().into();
+        eprintln!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone()).map_err(
+            |near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            },
+        )?;
+        let public_key = near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+            public_key.to_bytes(),
+        ));
+
+        eprintln!(
+            "Confirm the message signing on your Ledger device (HD Path: {})",
+            seed_phrase_hd_path,
+        );
+        let signature = sign_nep413_message(
+            &previous_context.payload.borsh_bytes()?,
+            &seed_phrase_hd_path,
+        )?;
+        let signature = crate::types::nep413::Signature(
+            near_crypto::Signature::from_parts(near_crypto::KeyType::ED25519, &signature).map_err(
+                |err| {
+                    color_eyre::Report::msg(format!(
+                        "The Ledger device returned an invalid signature: {}",
+                        err
+                    ))
+                },
+            )?,
+        );
+        // The device could have signed other bytes than the NEP-413 hash (e.g. an older app)
+        if !previous_context.payload.verify(&signature, &public_key)? {
+            return Err(color_eyre::Report::msg(
+                "The signature returned by the Ledger device does not match the message. Update the NEAR app on your Ledger device.",
+            ));
+        }
+        super::print_signature(&previous_context, &public_key, &signature)?;
+        Ok(Self)
+    }
+}
+
+impl SignLedger {
+    fn input_seed_phrase_hd_path(
+        context: &super::SignMessageContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--seed-phrase-hd-path")?;
+        Ok(Some(
+            inquire::CustomType::new(
+                "Enter seed phrase HD Path (if you not sure leave blank for default)",
+            )
+            .with_default(crate::types::slip10::BIP32Path::from_str("44'/397'/0'/0'/1'").unwrap())
+            .prompt()?,
+        ))
+    }
+}
+
+/// Sends the HD path followed by the borsh-serialized payload to the NEAR Ledger app in chunks,
+/// the same way near-ledger sends transactions; the app adds the NEP-413 tag and signs the hash.
+fn sign_nep413_message(
+    payload: &[u8],
+    seed_phrase_hd_path: &slip10::BIP32Path,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let hidapi = ledger_transport_hid::hidapi::HidApi::new().map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to access the USB devices: {}", err))
+    })?;
+    let transport = ledger_transport_hid::TransportNativeHID::new(&hidapi)
+        .map_err(|err| color_eyre::Report::msg(err.to_string()))?;
+
+    let mut data: Vec<u8> = (0..seed_phrase_hd_path.depth())
+        .flat_map(|depth| {
+            seed_phrase_hd_path
+                .index(depth)
+                .expect("The index is within the depth of the HD path")
+                .to_be_bytes()
+        })
+        .collect();
+    data.extend_from_slice(payload);
+
+    let chunks_count = data.chunks(CHUNK_SIZE).len();
+    for (index, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
+        let is_last_chunk = index + 1 == chunks_count;
+        let response = transport
+            .exchange(&ledger_transport::APDUCommand {
+                cla: CLA,
+                ins: INS_SIGN_NEP413_MESSAGE,
+                p1: if is_last_chunk {
+                    P1_SIGN_NORMAL_LAST_CHUNK
+                } else {
+                    P1_SIGN_NORMAL
+                },
+                p2: NETWORK_ID,
+                data: chunk.to_vec(),
+            })
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Error occurred while signing the message: {}",
+                    err
+                ))
+            })?;
+        if response.retcode() != RETURN_CODE_OK {
+            return Err(color_eyre::Report::msg(format!(
+                "Error occurred while signing the message: Ledger APDU retcode 0x{:X} (does the NEAR app on your Ledger device support NEP-413 messages?)",
+                response.retcode()
+            )));
+        }
+        if is_last_chunk {
+            return Ok(response.data().to_vec());
+        }
+    }
+    unreachable!("The data holds at least the HD path")
+}
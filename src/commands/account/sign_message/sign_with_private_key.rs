@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::SignMessageContext)]
+#[interactive_clap(output_context = SignPrivateKeyContext)]
+pub struct SignPrivateKey {
+    /// Enter sender (signer) private (secret) key
+    signer_private_key: crate::types::secret_key::SecretKey,
+}
+
+#[derive(Clone)]
+pub struct SignPrivateKeyContext;
+
+impl SignPrivateKeyContext {
+    pub fn from_previous_context(
+        previous_context: super::SignMessageContext,
+        scope: &<SignPrivateKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        super::print_signed_message(&previous_context, &scope.signer_private_key.clone().into())?;
+        Ok(Self)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::SignMessageContext)]
+#[interactive_clap(output_context = SignSeedPhraseContext)]
+pub struct SignSeedPhrase {
+    /// Enter the seed-phrase for this account
+    master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+}
+
+#[derive(Clone)]
+pub struct SignSeedPhraseContext;
+
+impl SignSeedPhraseContext {
+    pub fn from_previous_context(
+        previous_context: super::SignMessageContext,
+        scope: &<SignSeedPhrase as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
+            scope.seed_phrase_hd_path.clone(),
+            scope.master_seed_phrase.clone(),
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
+        )?;
+        let secret_key = near_crypto::SecretKey::from_str(&key_pair_properties.secret_keypair_str)?;
+        super::print_signed_message(&previous_context, &secret_key)?;
+        Ok(Self)
+    }
+}
+
+impl SignSeedPhrase {
+    fn input_seed_phrase_hd_path(
+        context: &super::SignMessageContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--seed-phrase-hd-path")?;
+        Ok(Some(
+            inquire::CustomType::new("Enter seed phrase HD Path [if not sure, keep the default]")
+                .with_default(crate::types::slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap())
+                .prompt()?,
+        ))
+    }
+}
+
+impl SeedPhraseOptions for SignSeedPhrase {
+    type InputContext = super::SignMessageContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
@@ -16,7 +16,7 @@ pub struct Contract {
 
 #[derive(Clone)]
 pub struct ContractContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub get_contract_account_id: GetContractAccountId,
 }
 
@@ -29,7 +29,7 @@ impl ContractContext {
         let get_contract_account_id: GetContractAccountId =
             std::sync::Arc::new(move |_network_config| Ok(contract_account_id.clone().into()));
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             get_contract_account_id,
         })
     }
@@ -17,7 +17,7 @@ pub struct DepositArgs {
 
 #[derive(Clone)]
 pub struct DepositArgsContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     get_contract_account_id: super::GetContractAccountId,
     receiver_account_id: near_primitives::types::AccountId,
     deposit: crate::common::NearBalance,
@@ -29,7 +29,7 @@ impl DepositArgsContext {
         scope: &<DepositArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             get_contract_account_id: previous_context.get_contract_account_id,
             receiver_account_id: scope.receiver_account_id.clone().into(),
             deposit: scope.deposit.clone(),
@@ -99,7 +99,7 @@ impl SignerAccountIdContext {
         );
 
         Ok(Self(crate::commands::ActionContext {
-            config: previous_context.config.clone(),
+            global_context: previous_context.global_context.clone(),
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -125,12 +125,16 @@ impl SignerAccountId {
         loop {
             let signer_account_id: crate::types::account_id::AccountId =
                 CustomType::new(" What is the signer account ID?")
-                    .with_default(context.receiver_account_id.clone().into())
+                    .with_default(
+                        crate::common::default_account_id(&context.global_context.0)
+                            .unwrap_or_else(|| context.receiver_account_id.clone().into()),
+                    )
                     .prompt()?;
             if !crate::common::is_account_exist(
-                &context.config.network_connection,
+                &context.global_context.0.network_connection,
                 signer_account_id.clone().into(),
-            ) {
+                context.global_context.1.scripting_mode,
+            )? {
                 eprintln!("\nThe account <{signer_account_id}> does not yet exist.");
                 #[derive(strum_macros::Display)]
                 enum ConfirmOptions {
@@ -13,7 +13,7 @@ pub struct WithdrawArgs {
 
 #[derive(Clone)]
 pub struct WithdrawArgsContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     get_contract_account_id: super::GetContractAccountId,
     amount: crate::common::NearBalance,
 }
@@ -24,7 +24,7 @@ impl WithdrawArgsContext {
         scope: &<WithdrawArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             get_contract_account_id: previous_context.get_contract_account_id,
             amount: scope.amount.clone(),
         })
@@ -35,6 +35,7 @@ impl WithdrawArgsContext {
 #[interactive_clap(input_context = WithdrawArgsContext)]
 #[interactive_clap(output_context = SignerAccountIdContext)]
 pub struct SignerAccountId {
+    #[interactive_clap(skip_default_input_arg)]
     /// What is the signer account ID?
     signer_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(named_arg)]
@@ -42,6 +43,17 @@ pub struct SignerAccountId {
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
 }
 
+impl SignerAccountId {
+    fn input_signer_account_id(
+        context: &WithdrawArgsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(
+            &context.global_context.0,
+            "What is the signer account ID?",
+        )
+    }
+}
+
 #[derive(Clone)]
 pub struct SignerAccountIdContext(crate::commands::ActionContext);
 
@@ -90,7 +102,7 @@ impl SignerAccountIdContext {
         );
 
         Ok(Self(crate::commands::ActionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -25,12 +25,13 @@ impl AccountContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback =
             std::sync::Arc::new({
                 let account_id = scope.account_id.clone();
+                let output_format = previous_context.global_context.1.output_format.clone();
 
                 move |network_config, block_reference| {
                     let contract_account_id = (previous_context.get_contract_account_id)(network_config)?;
 
                     let storage_balance = network_config
-                        .json_rpc_client()
+                        .json_rpc_client()?
                         .blocking_call_view_function(
                             &contract_account_id,
                             "storage_balance_of",
@@ -48,6 +49,14 @@ impl AccountContext {
                         .wrap_err_with(|| {
                             "Failed to parse return value of view function call for StorageBalance."
                         })?;
+                    if let crate::common::OutputFormat::Json = output_format {
+                        return crate::common::print_json_output(&serde_json::json!({
+                            "account_id": account_id.to_string(),
+                            "contract_account_id": contract_account_id,
+                            "available": storage_balance.available.to_string(),
+                            "total": storage_balance.total.to_string(),
+                        }));
+                    }
                     eprintln!("storage balance for <{account_id}>:");
                     eprintln!(" {:<13} {:>10}   ({} [{:>28} yoctoNEAR])",
                         "available:",
@@ -67,7 +76,7 @@ impl AccountContext {
             });
 
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -0,0 +1,218 @@
+use color_eyre::eyre::WrapErr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = VerifyMessageContext)]
+pub struct VerifyMessage {
+    #[interactive_clap(long)]
+    /// What message was signed?
+    message: String,
+    #[interactive_clap(long)]
+    /// Who is the recipient of the message?
+    recipient: String,
+    #[interactive_clap(long)]
+    /// Enter the base64 encoded nonce of the message
+    nonce: crate::types::nep413::Nonce,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// URL the signed message was passed to by the wallet
+    callback_url: Option<String>,
+    #[interactive_clap(long)]
+    /// Enter the public key the message was signed with
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(long)]
+    /// Enter the base64 encoded signature
+    signature: crate::types::nep413::Signature,
+    #[interactive_clap(subcommand)]
+    verify_mode: VerifyMode,
+}
+
+#[derive(Clone)]
+pub struct VerifyMessageContext {
+    global_context: crate::GlobalContext,
+    public_key: near_crypto::PublicKey,
+}
+
+impl VerifyMessageContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<VerifyMessage as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let payload = crate::types::nep413::Payload {
+            message: scope.message.clone(),
+            nonce: scope.nonce,
+            recipient: scope.recipient.clone(),
+            callback_url: scope.callback_url.clone(),
+        };
+        let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+        if !payload.verify(&scope.signature, &public_key)? {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                format!(
+                    "The signature is not valid: the message was not signed with the key <{}>",
+                    public_key
+                ),
+            )
+            .into());
+        }
+        eprintln!(
+            "\nThe signature of the message by <{}> is valid.",
+            public_key
+        );
+        Ok(Self {
+            global_context: previous_context,
+            public_key,
+        })
+    }
+}
+
+impl VerifyMessage {
+    fn input_callback_url(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = VerifyMessageContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Do you want to check that the key belongs to an account?
+pub enum VerifyMode {
+    #[strum_discriminants(strum(
+        message = "signature-only      - Only check the signature against the public key"
+    ))]
+    /// Only check the signature against the public key
+    SignatureOnly(SignatureOnly),
+    #[strum_discriminants(strum(
+        message = "full-access-key-of  - Also check that the public key is a full access key of the account"
+    ))]
+    /// Also check that the public key is a full access key of the account
+    FullAccessKeyOf(FullAccessKeyOf),
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = VerifyMessageContext)]
+#[interactive_clap(output_context = SignatureOnlyContext)]
+pub struct SignatureOnly;
+
+#[derive(Clone)]
+pub struct SignatureOnlyContext;
+
+impl SignatureOnlyContext {
+    pub fn from_previous_context(
+        previous_context: VerifyMessageContext,
+        _scope: &<SignatureOnly as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if let crate::common::OutputFormat::Json = previous_context.global_context.1.output_format {
+            crate::common::print_json_output(&serde_json::json!({
+                "public_key": previous_context.public_key.to_string(),
+                "valid_signature": true,
+            }))?;
+        }
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = VerifyMessageContext)]
+#[interactive_clap(output_context = FullAccessKeyOfContext)]
+pub struct FullAccessKeyOf {
+    /// Which account is claimed to have signed the message?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct FullAccessKeyOfContext(crate::network::NetworkContext);
+
+impl FullAccessKeyOfContext {
+    pub fn from_previous_context(
+        previous_context: VerifyMessageContext,
+        scope: &<FullAccessKeyOf as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let public_key = previous_context.public_key;
+        let output_format = previous_context.global_context.1.output_format.clone();
+
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let permission = match network_config
+                    .json_rpc_client()?
+                    .blocking_call_view_access_key(
+                        &account_id,
+                        &public_key,
+                        near_primitives::types::Finality::Final.into(),
+                    ) {
+                    Ok(rpc_query_response) => {
+                        Some(rpc_query_response.access_key_view()?.permission)
+                    }
+                    Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                        near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                            near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey {
+                                ..
+                            },
+                        ),
+                    )) => None,
+                    Err(err) => {
+                        return Err(err).wrap_err_with(|| {
+                            format!(
+                                "Failed to fetch query AccessKey {} for {}",
+                                public_key, account_id
+                            )
+                        })
+                    }
+                };
+                let is_full_access_key = matches!(
+                    permission,
+                    Some(near_primitives::views::AccessKeyPermissionView::FullAccess)
+                );
+                if let crate::common::OutputFormat::Json = output_format {
+                    crate::common::print_json_output(&serde_json::json!({
+                        "public_key": public_key.to_string(),
+                        "valid_signature": true,
+                        "account_id": account_id,
+                        "network_name": network_config.network_name,
+                        "full_access_key": is_full_access_key,
+                    }))?;
+                }
+                if is_full_access_key {
+                    eprintln!(
+                        "The key <{}> is currently a full access key of <{}> on <{}> network.",
+                        public_key, account_id, network_config.network_name
+                    );
+                    return Ok(());
+                }
+                Err(crate::common::CliError::new(
+                    crate::common::ErrorCategory::UserInput,
+                    match permission {
+                        Some(_) => format!(
+                            "The key <{}> is a function call access key of <{}>, not a full access key",
+                            public_key, account_id
+                        ),
+                        None => format!(
+                            "The key <{}> is not an access key of <{}> on <{}> network",
+                            public_key, account_id, network_config.network_name
+                        ),
+                    },
+                )
+                .into())
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context.global_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<FullAccessKeyOfContext> for crate::network::NetworkContext {
+    fn from(item: FullAccessKeyOfContext) -> Self {
+        item.0
+    }
+}
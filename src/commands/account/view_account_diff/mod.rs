@@ -0,0 +1,736 @@
+use color_eyre::eyre::Context;
+use strum::{EnumMessage, IntoEnumIterator};
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ViewAccountDiffContext)]
+pub struct ViewAccountDiff {
+    /// What Account ID do you need to compare?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Also compare the contract state under this UTF-8 key prefix ("" compares the whole state)
+    contract_state_prefix: Option<String>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: DiffNetworkArgs,
+}
+
+#[derive(Clone)]
+pub struct ViewAccountDiffContext {
+    global_context: crate::GlobalContext,
+    account_id: near_primitives::types::AccountId,
+    contract_state_prefix: Option<String>,
+}
+
+impl ViewAccountDiffContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ViewAccountDiff as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            account_id: scope.account_id.clone().into(),
+            contract_state_prefix: scope.contract_state_prefix.clone(),
+        })
+    }
+}
+
+impl ViewAccountDiff {
+    fn input_contract_state_prefix(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = ViewAccountDiffContext)]
+#[interactive_clap(output_context = DiffNetworkArgsContext)]
+pub struct DiffNetworkArgs {
+    /// What is the name of the network
+    #[interactive_clap(skip_default_input_arg)]
+    network_name: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// The block to compare from: "now", a block height or a block hash
+    from_block: crate::types::block_reference::BlockReference,
+    #[interactive_clap(subcommand)]
+    to_block: crate::network_view_at_block::ViewAtBlock,
+}
+
+/// The context of the block to compare to: the account is compared once it is known.
+#[derive(Clone)]
+pub struct DiffNetworkArgsContext(crate::network_view_at_block::NetworkViewAtBlockArgsContext);
+
+impl DiffNetworkArgsContext {
+    pub fn from_previous_context(
+        previous_context: ViewAccountDiffContext,
+        scope: &<DiffNetworkArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let network_config = previous_context
+            .global_context
+            .0
+            .network_connection
+            .get(&scope.network_name)
+            .expect("Failed to get network config!")
+            .clone();
+        let account_id = previous_context.account_id;
+        let contract_state_prefix = previous_context.contract_state_prefix;
+        let output_format = previous_context.global_context.1.output_format;
+        let from_block_reference: near_primitives::types::BlockReference =
+            scope.from_block.clone().into();
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback =
+            std::sync::Arc::new(move |network_config, to_block_reference| {
+                display_account_diff(
+                    network_config,
+                    &account_id,
+                    &from_block_reference,
+                    to_block_reference,
+                    contract_state_prefix.as_deref(),
+                    &output_format,
+                )
+            });
+        Ok(Self(
+            crate::network_view_at_block::NetworkViewAtBlockArgsContext {
+                network_config,
+                on_after_getting_block_reference_callback,
+            },
+        ))
+    }
+}
+
+impl From<DiffNetworkArgsContext> for crate::network_view_at_block::NetworkViewAtBlockArgsContext {
+    fn from(item: DiffNetworkArgsContext) -> Self {
+        item.0
+    }
+}
+
+impl DiffNetworkArgs {
+    fn input_network_name(
+        context: &ViewAccountDiffContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        crate::common::input_network_name_or_selected(&context.global_context)
+    }
+
+    /// The block to compare from is chosen the same way as the block to compare to.
+    fn input_from_block(
+        context: &ViewAccountDiffContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::block_reference::BlockReference>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--from-block")?;
+        let variants =
+            crate::network_view_at_block::ViewAtBlockDiscriminants::iter().collect::<Vec<_>>();
+        let selected = inquire::Select::new(
+            "Choose the block to compare from",
+            variants
+                .iter()
+                .map(|variant| variant.get_message().unwrap_or_default())
+                .collect(),
+        )
+        .raw_prompt()?;
+        let block_id = match variants[selected.index] {
+            crate::network_view_at_block::ViewAtBlockDiscriminants::Now => {
+                return Ok(Some(crate::types::block_reference::BlockReference(
+                    near_primitives::types::Finality::Final.into(),
+                )));
+            }
+            crate::network_view_at_block::ViewAtBlockDiscriminants::AtBlockHeight => {
+                near_primitives::types::BlockId::Height(
+                    inquire::CustomType::new("Type the block ID height to compare from")
+                        .prompt()?,
+                )
+            }
+            crate::network_view_at_block::ViewAtBlockDiscriminants::AtBlockHash => {
+                near_primitives::types::BlockId::Hash(
+                    inquire::CustomType::<crate::types::crypto_hash::CryptoHash>::new(
+                        "Type the block ID hash to compare from",
+                    )
+                    .prompt()?
+                    .into(),
+                )
+            }
+        };
+        Ok(Some(crate::types::block_reference::BlockReference(
+            near_primitives::types::BlockReference::BlockId(block_id),
+        )))
+    }
+}
+
+/// The contract state as returned by the JSON RPC: base64 encoded keys and values
+#[derive(Debug, serde::Deserialize)]
+struct ContractState {
+    values: Vec<ContractStateItem>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ContractStateItem {
+    key: String,
+    value: String,
+}
+
+struct AccountSnapshot {
+    block_height: near_primitives::types::BlockHeight,
+    block_hash: near_primitives::hash::CryptoHash,
+    /// None when the account does not exist in the block
+    account: Option<AccountState>,
+}
+
+struct AccountState {
+    account_view: near_primitives::views::AccountView,
+    access_keys: Vec<near_primitives::views::AccessKeyInfoView>,
+    /// Base64 encoded keys and values, sorted by key
+    contract_state: Option<std::collections::BTreeMap<String, String>>,
+}
+
+static NO_CONTRACT_STATE: std::collections::BTreeMap<String, String> =
+    std::collections::BTreeMap::new();
+
+impl AccountSnapshot {
+    fn access_keys(&self) -> &[near_primitives::views::AccessKeyInfoView] {
+        self.account
+            .as_ref()
+            .map_or(&[], |account| &account.access_keys)
+    }
+
+    fn contract_state(&self) -> &std::collections::BTreeMap<String, String> {
+        self.account
+            .as_ref()
+            .and_then(|account| account.contract_state.as_ref())
+            .unwrap_or(&NO_CONTRACT_STATE)
+    }
+
+    /// An absent account compares as an account without balance, storage and contract code.
+    fn account_view_field<T: Default>(
+        &self,
+        field: impl Fn(&near_primitives::views::AccountView) -> T,
+    ) -> T {
+        self.account
+            .as_ref()
+            .map_or_else(T::default, |account| field(&account.account_view))
+    }
+
+    /// The table cell of an account property, "-" when the account does not exist
+    fn display_account_view_field<T: std::fmt::Display>(
+        &self,
+        field: impl Fn(&near_primitives::views::AccountView) -> T,
+    ) -> String {
+        self.account.as_ref().map_or_else(
+            || "-".to_string(),
+            |account| field(&account.account_view).to_string(),
+        )
+    }
+}
+
+fn fetch_account_snapshot(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: &near_primitives::types::BlockReference,
+    contract_state_prefix: Option<&str>,
+) -> color_eyre::eyre::Result<AccountSnapshot> {
+    let json_rpc_client = network_config.json_rpc_client()?;
+    let rpc_query_response =
+        match json_rpc_client.blocking_call_view_account(account_id, block_reference.clone()) {
+            Ok(rpc_query_response) => rpc_query_response,
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount {
+                        block_height,
+                        block_hash,
+                        ..
+                    },
+                ),
+            )) => {
+                return Ok(AccountSnapshot {
+                    block_height,
+                    block_hash,
+                    account: None,
+                })
+            }
+            Err(err) => {
+                return Err(err).wrap_err_with(|| {
+                    format!("Failed to fetch query ViewAccount for <{}>", account_id)
+                })
+            }
+        };
+    let account_view = rpc_query_response.account_view()?;
+    // The access keys and the contract state are viewed in the very same block as the account
+    let block_reference = near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(rpc_query_response.block_hash),
+    );
+    let access_keys = json_rpc_client
+        .blocking_call_view_access_key_list(account_id, block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch ViewAccessKeyList for {}", account_id))?
+        .access_key_list_view()?
+        .keys;
+    let contract_state = match contract_state_prefix {
+        Some(prefix) => Some(fetch_contract_state(
+            &json_rpc_client,
+            account_id,
+            block_reference,
+            prefix,
+        )?),
+        None => None,
+    };
+    Ok(AccountSnapshot {
+        block_height: rpc_query_response.block_height,
+        block_hash: rpc_query_response.block_hash,
+        account: Some(AccountState {
+            account_view,
+            access_keys,
+            contract_state,
+        }),
+    })
+}
+
+fn fetch_contract_state(
+    json_rpc_client: &crate::common::FailoverJsonRpcClient,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+    prefix: &str,
+) -> color_eyre::eyre::Result<std::collections::BTreeMap<String, String>> {
+    let rpc_query_response = json_rpc_client
+        .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewState {
+                account_id: account_id.clone(),
+                prefix: near_primitives::types::StoreKey::from(prefix.as_bytes().to_vec()),
+                include_proof: false,
+            },
+        })
+        .wrap_err_with(|| format!("Failed to fetch query ViewState for <{}>", account_id))?;
+    let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(view_state_result) =
+        rpc_query_response.kind
+    else {
+        color_eyre::eyre::bail!(
+            "Internal error: Received unexpected query kind in response to a View State query call",
+        );
+    };
+    let contract_state: ContractState =
+        serde_json::from_value(serde_json::to_value(view_state_result)?)?;
+    Ok(contract_state
+        .values
+        .into_iter()
+        .map(|item| (item.key, item.value))
+        .collect())
+}
+
+fn display_account_diff(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    from_block_reference: &near_primitives::types::BlockReference,
+    to_block_reference: &near_primitives::types::BlockReference,
+    contract_state_prefix: Option<&str>,
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let from = fetch_account_snapshot(
+        network_config,
+        account_id,
+        from_block_reference,
+        contract_state_prefix,
+    )?;
+    let to = fetch_account_snapshot(
+        network_config,
+        account_id,
+        to_block_reference,
+        contract_state_prefix,
+    )?;
+
+    if from.account.is_none() && to.account.is_none() {
+        return Err(crate::common::CliError::new(
+            crate::common::ErrorCategory::RpcHandler,
+            format!(
+                "Account <{}> does not exist in either of the blocks #{} and #{}",
+                account_id, from.block_height, to.block_height
+            ),
+        )
+        .into());
+    }
+
+    let added_keys = to
+        .access_keys()
+        .iter()
+        .filter(|key| find_access_key(from.access_keys(), &key.public_key).is_none())
+        .collect::<Vec<_>>();
+    let removed_keys = from
+        .access_keys()
+        .iter()
+        .filter(|key| find_access_key(to.access_keys(), &key.public_key).is_none())
+        .collect::<Vec<_>>();
+    let changed_keys = from
+        .access_keys()
+        .iter()
+        .filter_map(|from_key| {
+            let to_key = find_access_key(to.access_keys(), &from_key.public_key)?;
+            (to_key.access_key != from_key.access_key).then_some((from_key, to_key))
+        })
+        .collect::<Vec<_>>();
+    let contract_state_diff = contract_state_prefix
+        .map(|_| diff_contract_state(from.contract_state(), to.contract_state()));
+    let from_code_hash = from.account_view_field(|account_view| account_view.code_hash);
+    let to_code_hash = to.account_view_field(|account_view| account_view.code_hash);
+
+    if let crate::common::OutputFormat::Json = output_format {
+        return crate::common::print_json_output(&serde_json::json!({
+            "account_id": account_id,
+            "from": {
+                "block_height": from.block_height,
+                "block_hash": from.block_hash,
+            },
+            "to": {
+                "block_height": to.block_height,
+                "block_hash": to.block_hash,
+            },
+            "exists": {
+                "from": from.account.is_some(),
+                "to": to.account.is_some(),
+            },
+            "balance": {
+                "from": from.account.as_ref().map(|account| account.account_view.amount.to_string()),
+                "to": to.account.as_ref().map(|account| account.account_view.amount.to_string()),
+            },
+            "locked": {
+                "from": from.account.as_ref().map(|account| account.account_view.locked.to_string()),
+                "to": to.account.as_ref().map(|account| account.account_view.locked.to_string()),
+            },
+            "storage_usage": {
+                "from": from.account.as_ref().map(|account| account.account_view.storage_usage),
+                "to": to.account.as_ref().map(|account| account.account_view.storage_usage),
+            },
+            "contract_sha256": {
+                "from": code_hash_hex(&from_code_hash),
+                "to": code_hash_hex(&to_code_hash),
+            },
+            "access_keys": {
+                "added": added_keys,
+                "removed": removed_keys,
+                "changed": changed_keys
+                    .iter()
+                    .map(|(from_key, to_key)| serde_json::json!({
+                        "public_key": from_key.public_key,
+                        "from": from_key.access_key,
+                        "to": to_key.access_key,
+                    }))
+                    .collect::<Vec<_>>(),
+            },
+            "contract_state": contract_state_diff.as_ref().map(|diff| serde_json::json!({
+                "added": diff.added.iter().map(|(key, value)| serde_json::json!({
+                    "key": key,
+                    "value": value,
+                })).collect::<Vec<_>>(),
+                "removed": diff.removed.iter().map(|(key, value)| serde_json::json!({
+                    "key": key,
+                    "value": value,
+                })).collect::<Vec<_>>(),
+                "changed": diff.changed.iter().map(|(key, from_value, to_value)| serde_json::json!({
+                    "key": key,
+                    "from": from_value,
+                    "to": to_value,
+                })).collect::<Vec<_>>(),
+            })),
+        }));
+    }
+
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(prettytable::row![
+        Fy->account_id,
+        format!("At block #{}\n({})", from.block_height, from.block_hash),
+        format!("At block #{}\n({})", to.block_height, to.block_hash),
+        "Change"
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Account",
+        display_existence(from.account.is_some()),
+        display_existence(to.account.is_some()),
+        Fy->existence_change(from.account.is_some(), to.account.is_some())
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Native account balance",
+        from.display_account_view_field(|account_view| crate::common::NearBalance::from_yoctonear(account_view.amount)),
+        to.display_account_view_field(|account_view| crate::common::NearBalance::from_yoctonear(account_view.amount)),
+        Fy->near_balance_change(
+            from.account_view_field(|account_view| account_view.amount),
+            to.account_view_field(|account_view| account_view.amount)
+        )
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Validator stake",
+        from.display_account_view_field(|account_view| crate::common::NearBalance::from_yoctonear(account_view.locked)),
+        to.display_account_view_field(|account_view| crate::common::NearBalance::from_yoctonear(account_view.locked)),
+        Fy->near_balance_change(
+            from.account_view_field(|account_view| account_view.locked),
+            to.account_view_field(|account_view| account_view.locked)
+        )
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Storage used by the account",
+        from.display_account_view_field(|account_view| bytesize::ByteSize(account_view.storage_usage)),
+        to.display_account_view_field(|account_view| bytesize::ByteSize(account_view.storage_usage)),
+        Fy->storage_usage_change(
+            from.account_view_field(|account_view| account_view.storage_usage),
+            to.account_view_field(|account_view| account_view.storage_usage)
+        )
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Contract (SHA-256 checksum hex)",
+        from.display_account_view_field(|account_view| {
+            code_hash_hex(&account_view.code_hash).unwrap_or_else(|| "No contract code".to_string())
+        }),
+        to.display_account_view_field(|account_view| {
+            code_hash_hex(&account_view.code_hash).unwrap_or_else(|| "No contract code".to_string())
+        }),
+        Fy->if from_code_hash == to_code_hash {
+            "no change"
+        } else {
+            "changed"
+        }
+    ]);
+    table.add_row(prettytable::row![
+        Fg->"Access keys",
+        from.display_account_view_field(|_| from.access_keys().len()),
+        to.display_account_view_field(|_| to.access_keys().len()),
+        Fy->format!(
+            "{} added, {} removed, {} changed",
+            added_keys.len(),
+            removed_keys.len(),
+            changed_keys.len()
+        )
+    ]);
+    table.printstd();
+
+    if !added_keys.is_empty() || !removed_keys.is_empty() || !changed_keys.is_empty() {
+        let mut table = prettytable::Table::new();
+        table.set_titles(prettytable::row![Fg=>"", "Public Key", "Nonce", "Permissions"]);
+        for access_key in added_keys {
+            table.add_row(prettytable::row![
+                Fg->"+",
+                access_key.public_key,
+                access_key.access_key.nonce,
+                permission_summary(&access_key.access_key.permission)
+            ]);
+        }
+        for access_key in removed_keys {
+            table.add_row(prettytable::row![
+                Fr->"-",
+                access_key.public_key,
+                access_key.access_key.nonce,
+                permission_summary(&access_key.access_key.permission)
+            ]);
+        }
+        for (from_key, to_key) in changed_keys {
+            let permissions = if from_key.access_key.permission == to_key.access_key.permission {
+                permission_summary(&to_key.access_key.permission)
+            } else {
+                format!(
+                    "{}\n-> {}",
+                    permission_summary(&from_key.access_key.permission),
+                    permission_summary(&to_key.access_key.permission)
+                )
+            };
+            table.add_row(prettytable::row![
+                Fy->"~",
+                to_key.public_key,
+                format!("{} -> {}", from_key.access_key.nonce, to_key.access_key.nonce),
+                permissions
+            ]);
+        }
+        table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+        table.printstd();
+    }
+
+    if let Some(diff) = contract_state_diff {
+        if diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty() {
+            eprintln!("\nThe contract state has not changed.");
+            return Ok(());
+        }
+        let mut table = prettytable::Table::new();
+        table.set_titles(prettytable::row![Fg=>"", "Contract state key", "Value"]);
+        for (key, value) in diff.added {
+            table.add_row(prettytable::row![
+                Fg->"+",
+                display_state_bytes(key),
+                display_state_bytes(value)
+            ]);
+        }
+        for (key, value) in diff.removed {
+            table.add_row(prettytable::row![
+                Fr->"-",
+                display_state_bytes(key),
+                display_state_bytes(value)
+            ]);
+        }
+        for (key, from_value, to_value) in diff.changed {
+            table.add_row(prettytable::row![
+                Fy->"~",
+                display_state_bytes(key),
+                format!(
+                    "{}\n-> {}",
+                    display_state_bytes(from_value),
+                    display_state_bytes(to_value)
+                )
+            ]);
+        }
+        table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+        table.printstd();
+    }
+    Ok(())
+}
+
+fn find_access_key<'a>(
+    access_keys: &'a [near_primitives::views::AccessKeyInfoView],
+    public_key: &near_crypto::PublicKey,
+) -> Option<&'a near_primitives::views::AccessKeyInfoView> {
+    access_keys
+        .iter()
+        .find(|access_key| access_key.public_key == *public_key)
+}
+
+#[derive(Default)]
+struct ContractStateDiff<'a> {
+    added: Vec<(&'a String, &'a String)>,
+    removed: Vec<(&'a String, &'a String)>,
+    changed: Vec<(&'a String, &'a String, &'a String)>,
+}
+
+fn diff_contract_state<'a>(
+    from_state: &'a std::collections::BTreeMap<String, String>,
+    to_state: &'a std::collections::BTreeMap<String, String>,
+) -> ContractStateDiff<'a> {
+    let mut diff = ContractStateDiff::default();
+    for (key, from_value) in from_state {
+        match to_state.get(key) {
+            None => diff.removed.push((key, from_value)),
+            Some(to_value) if to_value != from_value => {
+                diff.changed.push((key, from_value, to_value))
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, to_value) in to_state {
+        if !from_state.contains_key(key) {
+            diff.added.push((key, to_value));
+        }
+    }
+    diff
+}
+
+fn code_hash_hex(code_hash: &near_primitives::hash::CryptoHash) -> Option<String> {
+    (*code_hash != near_primitives::hash::CryptoHash::default())
+        .then(|| hex::encode(code_hash.as_ref()))
+}
+
+fn display_existence(exists: bool) -> &'static str {
+    if exists {
+        "exists"
+    } else {
+        "does not exist"
+    }
+}
+
+fn existence_change(from_exists: bool, to_exists: bool) -> &'static str {
+    match (from_exists, to_exists) {
+        (false, true) => "created",
+        (true, false) => "deleted",
+        _ => "no change",
+    }
+}
+
+fn near_balance_change(from: u128, to: u128) -> String {
+    match to.cmp(&from) {
+        std::cmp::Ordering::Equal => "no change".to_string(),
+        std::cmp::Ordering::Greater => {
+            format!("+{}", crate::common::NearBalance::from_yoctonear(to - from))
+        }
+        std::cmp::Ordering::Less => {
+            format!("-{}", crate::common::NearBalance::from_yoctonear(from - to))
+        }
+    }
+}
+
+fn storage_usage_change(
+    from: near_primitives::types::StorageUsage,
+    to: near_primitives::types::StorageUsage,
+) -> String {
+    match to.cmp(&from) {
+        std::cmp::Ordering::Equal => "no change".to_string(),
+        std::cmp::Ordering::Greater => format!("+{}", bytesize::ByteSize(to - from)),
+        std::cmp::Ordering::Less => format!("-{}", bytesize::ByteSize(from - to)),
+    }
+}
+
+fn permission_summary(permission: &near_primitives::views::AccessKeyPermissionView) -> String {
+    match permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess => "full access".to_string(),
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            receiver_id,
+            method_names,
+            ..
+        } => {
+            if method_names.is_empty() {
+                format!("any method of {}", receiver_id)
+            } else {
+                format!("{} of {}", method_names.join(", "), receiver_id)
+            }
+        }
+    }
+}
+
+/// Contract state keys and values are shown as text when they are printable UTF-8, and base64
+/// encoded otherwise.
+fn display_state_bytes(base64_bytes: &str) -> String {
+    match base64::decode(base64_bytes)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+    {
+        Some(text) if !text.chars().any(char::is_control) => format!("{:?}", text),
+        _ => format!("base64:{}", base64_bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_created_and_deleted_accounts() {
+        assert_eq!(existence_change(false, true), "created");
+        assert_eq!(existence_change(true, false), "deleted");
+        assert_eq!(existence_change(true, true), "no change");
+    }
+
+    #[test]
+    fn diffs_contract_state() {
+        let from_state = [("a", "1"), ("b", "2"), ("c", "3")]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let to_state = [("b", "2"), ("c", "4"), ("d", "5")]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let diff = diff_contract_state(&from_state, &to_state);
+        let [one, three, four, five] = ["1", "3", "4", "5"].map(String::from);
+        let [a, c, d] = ["a", "c", "d"].map(String::from);
+        assert_eq!(diff.added, vec![(&d, &five)]);
+        assert_eq!(diff.removed, vec![(&a, &one)]);
+        assert_eq!(diff.changed, vec![(&c, &three, &four)]);
+
+        let diff = diff_contract_state(&NO_CONTRACT_STATE, &to_state);
+        assert_eq!(diff.added.len(), 3);
+        assert!(diff.removed.is_empty() && diff.changed.is_empty());
+    }
+
+    #[test]
+    fn displays_state_bytes_as_text_when_printable() {
+        assert_eq!(display_state_bytes(&base64::encode("STATE")), "\"STATE\"");
+        assert_eq!(
+            display_state_bytes(&base64::encode([0u8, 159])),
+            format!("base64:{}", base64::encode([0u8, 159]))
+        );
+    }
+}
@@ -1,14 +1,33 @@
 use color_eyre::eyre::Context;
+use futures::StreamExt;
 
 use crate::common::JsonRpcClientExt;
 use crate::common::RpcQueryResponseExt;
 
+/// Number of accounts fetched at the same time when several accounts are viewed
+const MAX_CONCURRENT_REQUESTS: usize = 16;
+
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = ViewAccountSummaryContext)]
 pub struct ViewAccountSummary {
-    /// What Account ID do you need to view?
-    account_id: crate::types::account_id::AccountId,
+    /// What Account ID do you need to view? (several IDs are separated by commas, @<file> reads them from a file)
+    account_id: crate::types::account_id_list::AccountIdList,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Other networks to view the accounts on as well: comma-separated connection names, or "all" for every connection
+    networks: Option<crate::types::vec_string::VecString>,
+    #[interactive_clap(long)]
+    /// View the accounts on every network connection of config.toml as well
+    all_networks: bool,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Re-check the account on new final blocks at this interval (e.g. 30s) and print only the changes
+    watch: Option<crate::types::duration::Duration>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// With --watch, exit with an error once the balance is below:<NEAR>, above:<NEAR> or equal:<NEAR>
+    exit_if: Option<crate::types::threshold::Threshold>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
@@ -22,47 +41,53 @@ impl ViewAccountSummaryContext {
         previous_context: crate::GlobalContext,
         scope: &<ViewAccountSummary as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        crate::common::check_exit_if_needs_watch(scope.watch.as_ref(), scope.exit_if.as_ref())?;
+        let account_ids: Vec<near_primitives::types::AccountId> = scope.account_id.clone().into();
+        let networks: Option<Vec<String>> = scope.networks.clone().map(Into::into);
+        let all_networks = scope.all_networks;
+        let watch = scope.watch;
+        let exit_if = scope.exit_if.clone();
+        let config = previous_context.0.clone();
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let output_format = previous_context.1.output_format.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
-                let rpc_query_response = network_config
-                    .json_rpc_client()
-                    .blocking_call_view_account(&account_id.clone(), block_reference.clone())
-                    .wrap_err_with(|| {
-                        format!(
-                            "Failed to fetch query ViewAccount for <{}>",
-                            &account_id
+                let network_configs = selected_network_configs(&config, network_config, networks.as_deref(), all_networks)?;
+                if let Some(watch) = watch {
+                    let ([account_id], [(_, network_config)]) = (account_ids.as_slice(), network_configs.as_slice()) else {
+                        return Err(crate::common::CliError::new(
+                            crate::common::ErrorCategory::UserInput,
+                            "--watch follows a single account on a single network",
                         )
-                    })?;
-                let account_view = rpc_query_response.account_view()?;
-
-                let access_key_list = network_config
-                    .json_rpc_client()
-                    .blocking_call_view_access_key_list(
-                        &account_id,
-                        block_reference.clone(),
+                        .into());
+                    };
+                    return crate::common::watch_final_blocks(network_config, block_reference, watch.into(), &output_format, |block_reference| {
+                        watched_account_summary(network_config, account_id, block_reference, exit_if.as_ref())
+                    });
+                }
+                if let ([account_id], [(_, network_config)]) = (account_ids.as_slice(), network_configs.as_slice()) {
+                    return display_account_summary(
+                        &credentials_home_dir,
+                        network_config,
+                        account_id,
+                        block_reference,
+                        &output_format,
+                    );
+                }
+                if network_configs.len() > 1 && !matches!(block_reference, near_primitives::types::BlockReference::Finality(_)) {
+                    return Err(crate::common::CliError::new(
+                        crate::common::ErrorCategory::UserInput,
+                        "Block heights and hashes are specific to a network, so several networks can only be viewed in the final block (now)",
                     )
-                    .wrap_err_with(|| {
-                        format!(
-                            "Failed to fetch ViewAccessKeyList for {}",
-                            &account_id
-                        )
-                    })?
-                    .access_key_list_view()?;
-
-                crate::common::display_account_info(
-                    &rpc_query_response.block_hash,
-                    &rpc_query_response.block_height,
-                    &account_id,
-                    &account_view,
-                    &access_key_list.keys,
-                );
-                Ok(())
+                    .into());
+                }
+                let summaries = fetch_account_summaries(&network_configs, &account_ids, block_reference)?;
+                display_account_summaries(&summaries, &output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -73,3 +98,560 @@ impl From<ViewAccountSummaryContext> for crate::network_view_at_block::ArgsForVi
         item.0
     }
 }
+
+impl ViewAccountSummary {
+    fn input_networks(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::vec_string::VecString>> {
+        Ok(None)
+    }
+
+    fn input_watch(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::duration::Duration>> {
+        Ok(None)
+    }
+
+    fn input_exit_if(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::threshold::Threshold>> {
+        Ok(None)
+    }
+}
+
+/// The selected connection followed by the other requested connections, in the order of
+/// config.toml, with their connection names. Several connections to the same network are all
+/// viewed, so the rows and totals are told apart by the connection name.
+fn selected_network_configs(
+    config: &crate::config::Config,
+    network_config: &crate::config::NetworkConfig,
+    networks: Option<&[String]>,
+    all_networks: bool,
+) -> color_eyre::eyre::Result<Vec<(String, crate::config::NetworkConfig)>> {
+    let connection_name = config
+        .network_connection
+        .iter()
+        .find(|(_, other_network_config)| other_network_config.is_same_connection(network_config))
+        .map_or_else(
+            || network_config.network_name.clone(),
+            |(connection_name, _)| connection_name.clone(),
+        );
+    let mut network_configs = vec![(connection_name, network_config.clone())];
+    let networks = networks.unwrap_or_default();
+    let all_networks = all_networks || networks.iter().any(|network_name| network_name == "all");
+    for network_name in networks {
+        if network_name != "all" && !config.network_connection.contains_key(network_name) {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                format!(
+                    "There is no network connection \"{}\" in config.toml",
+                    network_name
+                ),
+            )
+            .into());
+        }
+    }
+    for (network_name, other_network_config) in &config.network_connection {
+        if !other_network_config.is_same_connection(network_config)
+            && (all_networks || networks.contains(network_name))
+        {
+            network_configs.push((network_name.clone(), other_network_config.clone()));
+        }
+    }
+    Ok(network_configs)
+}
+
+fn display_account_summary(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: &near_primitives::types::BlockReference,
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let rpc_query_response = network_config
+        .json_rpc_client()?
+        .blocking_call_view_account(&account_id.clone(), block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch query ViewAccount for <{}>", &account_id))?;
+    let account_view = rpc_query_response.account_view()?;
+
+    let access_key_list = network_config
+        .json_rpc_client()?
+        .blocking_call_view_access_key_list(account_id, block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch ViewAccessKeyList for {}", &account_id))?
+        .access_key_list_view()?;
+
+    crate::common::display_account_info(
+        &rpc_query_response.block_hash,
+        &rpc_query_response.block_height,
+        account_id,
+        &account_view,
+        &access_key_list.keys,
+        &crate::commands::local_keys::storage::local_key_availability(
+            credentials_home_dir,
+            network_config,
+            account_id,
+        )?,
+        output_format,
+    )
+}
+
+fn watched_account_summary(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: &near_primitives::types::BlockReference,
+    exit_if: Option<&crate::types::threshold::Threshold>,
+) -> color_eyre::eyre::Result<crate::common::WatchedValue> {
+    let account_view = network_config
+        .json_rpc_client()?
+        .blocking_call_view_account(account_id, block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch query ViewAccount for <{}>", &account_id))?
+        .account_view()?;
+    let access_keys = network_config
+        .json_rpc_client()?
+        .blocking_call_view_access_key_list(account_id, block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch ViewAccessKeyList for {}", &account_id))?
+        .access_key_list_view()?
+        .keys;
+    let (full_access_keys_count, function_call_keys_count) = count_access_keys(&access_keys);
+    let contract_sha256 = (account_view.code_hash != near_primitives::hash::CryptoHash::default())
+        .then(|| hex::encode(account_view.code_hash.as_ref()));
+    Ok(crate::common::WatchedValue {
+        text: format!(
+            "balance {}, validator stake {}, storage {}, {} full access / {} function call access keys, {}",
+            crate::common::NearBalance::from_yoctonear(account_view.amount),
+            crate::common::NearBalance::from_yoctonear(account_view.locked),
+            bytesize::ByteSize(account_view.storage_usage),
+            full_access_keys_count,
+            function_call_keys_count,
+            contract_sha256
+                .as_ref()
+                .map_or("no contract code".to_string(), |contract_sha256| format!(
+                    "contract {}",
+                    contract_sha256
+                ))
+        ),
+        json: serde_json::json!({
+            "account_id": account_id,
+            "balance": account_view.amount.to_string(),
+            "locked": account_view.locked.to_string(),
+            "storage_usage": account_view.storage_usage,
+            "contract_sha256": contract_sha256,
+            "access_keys": access_keys,
+        }),
+        threshold_met: match exit_if {
+            Some(exit_if) => exit_if.is_met(account_view.amount, 24)?,
+            None => false,
+        },
+    })
+}
+
+struct AccountSummary {
+    connection_name: String,
+    network_name: String,
+    account_id: near_primitives::types::AccountId,
+    /// The account and its access keys, or the reason they could not be fetched
+    state: Result<
+        (
+            near_primitives::views::AccountView,
+            Vec<near_primitives::views::AccessKeyInfoView>,
+        ),
+        (crate::common::ErrorCategory, String),
+    >,
+}
+
+fn fetch_account_summaries(
+    network_configs: &[(String, crate::config::NetworkConfig)],
+    account_ids: &[near_primitives::types::AccountId],
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Vec<AccountSummary>> {
+    eprintln!(
+        "Fetching {} account(s) on {} network(s) ...",
+        account_ids.len(),
+        network_configs.len()
+    );
+    let json_rpc_clients = network_configs
+        .iter()
+        .map(|(connection_name, network_config)| {
+            Ok((
+                connection_name,
+                network_config,
+                network_config.json_rpc_client()?,
+            ))
+        })
+        .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+    let requests =
+        json_rpc_clients
+            .iter()
+            .flat_map(|(connection_name, network_config, json_rpc_client)| {
+                account_ids.iter().map(move |account_id| {
+                    fetch_account_summary(
+                        connection_name,
+                        network_config,
+                        json_rpc_client,
+                        account_id,
+                        block_reference,
+                    )
+                })
+            });
+    Ok(tokio::runtime::Runtime::new()?.block_on(
+        futures::stream::iter(requests)
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+async fn fetch_account_summary(
+    connection_name: &str,
+    network_config: &crate::config::NetworkConfig,
+    json_rpc_client: &crate::common::FailoverJsonRpcClient,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: &near_primitives::types::BlockReference,
+) -> AccountSummary {
+    let (account_view, access_key_list) = futures::join!(
+        json_rpc_client.call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_reference.clone(),
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: account_id.clone(),
+            },
+        }),
+        json_rpc_client.call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_reference.clone(),
+            request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                account_id: account_id.clone(),
+            },
+        })
+    );
+    let state = match (account_view, access_key_list) {
+        (
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                ),
+            )),
+            _,
+        ) => Err((
+            crate::common::ErrorCategory::RpcHandler,
+            "account does not exist".to_string(),
+        )),
+        (Ok(account_view), Ok(access_key_list)) => account_view
+            .account_view()
+            .and_then(|account_view| {
+                Ok((account_view, access_key_list.access_key_list_view()?.keys))
+            })
+            .map_err(|err| (crate::common::ErrorCategory::RpcHandler, err.to_string())),
+        (Err(err), _) | (_, Err(err)) => Err((
+            crate::common::json_rpc_error_category(&err),
+            err.to_string(),
+        )),
+    };
+    AccountSummary {
+        connection_name: connection_name.to_string(),
+        network_name: network_config.network_name.clone(),
+        account_id: account_id.clone(),
+        state,
+    }
+}
+
+fn count_access_keys(access_keys: &[near_primitives::views::AccessKeyInfoView]) -> (usize, usize) {
+    let full_access_keys_count = access_keys
+        .iter()
+        .filter(|access_key| {
+            matches!(
+                access_key.access_key.permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+            )
+        })
+        .count();
+    (
+        full_access_keys_count,
+        access_keys.len() - full_access_keys_count,
+    )
+}
+
+/// Sums of the balances, stakes and storage of the fetched accounts of every network connection,
+/// with the first summary of the connection
+fn connection_totals(summaries: &[AccountSummary]) -> Vec<(&AccountSummary, u128, u128, u64)> {
+    let mut totals: Vec<(&AccountSummary, u128, u128, u64)> = vec![];
+    for summary in summaries {
+        let Ok((account_view, _)) = &summary.state else {
+            continue;
+        };
+        let index = match totals.iter().position(|(connection_summary, ..)| {
+            connection_summary.connection_name == summary.connection_name
+        }) {
+            Some(index) => index,
+            None => {
+                totals.push((summary, 0, 0, 0));
+                totals.len() - 1
+            }
+        };
+        totals[index].1 += account_view.amount;
+        totals[index].2 += account_view.locked;
+        totals[index].3 += account_view.storage_usage;
+    }
+    totals
+}
+
+fn display_account_summaries(
+    summaries: &[AccountSummary],
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    print_account_summaries(summaries, output_format)?;
+    all_accounts_failed_error(summaries).map_or(Ok(()), |err| Err(err.into()))
+}
+
+/// Scripts get a non-zero exit code when none of the accounts could be fetched; a connectivity
+/// problem takes precedence over the errors returned by the RPC servers.
+fn all_accounts_failed_error(summaries: &[AccountSummary]) -> Option<crate::common::CliError> {
+    let categories = summaries
+        .iter()
+        .map(|summary| summary.state.as_ref().err().map(|(category, _)| *category))
+        .collect::<Option<Vec<_>>>()?;
+    if categories.is_empty() {
+        return None;
+    }
+    let category = if categories.contains(&crate::common::ErrorCategory::RpcTransport) {
+        crate::common::ErrorCategory::RpcTransport
+    } else {
+        crate::common::ErrorCategory::RpcHandler
+    };
+    Some(crate::common::CliError::new(
+        category,
+        format!(
+            "None of the {} account(s) could be fetched",
+            summaries.len()
+        ),
+    ))
+}
+
+fn print_account_summaries(
+    summaries: &[AccountSummary],
+    output_format: &crate::common::OutputFormat,
+) -> crate::CliResult {
+    let totals = connection_totals(summaries);
+    if let crate::common::OutputFormat::Json = output_format {
+        return crate::common::print_json_output(&serde_json::json!({
+            "accounts": summaries
+                .iter()
+                .map(|summary| match &summary.state {
+                    Ok((account_view, access_keys)) => {
+                        let (full_access_keys_count, function_call_keys_count) =
+                            count_access_keys(access_keys);
+                        serde_json::json!({
+                            "connection_name": summary.connection_name,
+                            "network_name": summary.network_name,
+                            "account_id": summary.account_id,
+                            "balance": account_view.amount.to_string(),
+                            "locked": account_view.locked.to_string(),
+                            "storage_usage": account_view.storage_usage,
+                            "full_access_keys": full_access_keys_count,
+                            "function_call_access_keys": function_call_keys_count,
+                        })
+                    }
+                    Err((_, err)) => serde_json::json!({
+                        "connection_name": summary.connection_name,
+                        "network_name": summary.network_name,
+                        "account_id": summary.account_id,
+                        "error": err,
+                    }),
+                })
+                .collect::<Vec<_>>(),
+            "totals": totals
+                .iter()
+                .map(|(summary, balance, locked, storage_usage)| serde_json::json!({
+                    "connection_name": summary.connection_name,
+                    "network_name": summary.network_name,
+                    "balance": balance.to_string(),
+                    "locked": locked.to_string(),
+                    "storage_usage": storage_usage,
+                }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+
+    let mut table = prettytable::Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(prettytable::row![
+        "Network connection",
+        "Account ID",
+        "Native account balance",
+        "Validator stake",
+        "Storage",
+        "Access keys (full / function call)"
+    ]);
+    for summary in summaries {
+        match &summary.state {
+            Ok((account_view, access_keys)) => {
+                let (full_access_keys_count, function_call_keys_count) =
+                    count_access_keys(access_keys);
+                table.add_row(prettytable::row![
+                    summary.connection_name,
+                    Fy->summary.account_id,
+                    crate::common::NearBalance::from_yoctonear(account_view.amount),
+                    crate::common::NearBalance::from_yoctonear(account_view.locked),
+                    bytesize::ByteSize(account_view.storage_usage),
+                    format!("{} / {}", full_access_keys_count, function_call_keys_count)
+                ]);
+            }
+            Err((_, err)) => {
+                table.add_row(prettytable::row![
+                    summary.connection_name,
+                    Fy->summary.account_id,
+                    Fr->err,
+                    "",
+                    "",
+                    ""
+                ]);
+            }
+        }
+    }
+    for (summary, balance, locked, storage_usage) in totals {
+        table.add_row(prettytable::row![
+            Fg->summary.connection_name,
+            Fg->"Total",
+            Fg->crate::common::NearBalance::from_yoctonear(balance),
+            Fg->crate::common::NearBalance::from_yoctonear(locked),
+            Fg->bytesize::ByteSize(storage_usage),
+            ""
+        ]);
+    }
+    table.printstd();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpc_urls(network_configs: &[(String, crate::config::NetworkConfig)]) -> Vec<String> {
+        network_configs
+            .iter()
+            .map(|(connection_name, network_config)| {
+                format!("{} {}", connection_name, network_config.rpc_url)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn selects_other_connections_to_the_same_network() {
+        let mut config = crate::config::Config::default();
+        let testnet = config.network_connection["testnet"].clone();
+        let mut testnet_rpc = crate::config::NetworkConfig {
+            http_client: Default::default(),
+            ..testnet.clone()
+        };
+        testnet_rpc.rpc_url = "https://rpc.testnet.near.org".parse().unwrap();
+        config
+            .network_connection
+            .insert("testnet-rpc".to_string(), testnet_rpc);
+
+        let all = [
+            "testnet https://archival-rpc.testnet.near.org/",
+            "mainnet https://archival-rpc.mainnet.near.org/",
+            "testnet-rpc https://rpc.testnet.near.org/",
+        ];
+        assert_eq!(
+            rpc_urls(
+                &selected_network_configs(&config, &testnet, Some(&["all".to_string()]), false)
+                    .unwrap()
+            ),
+            all
+        );
+        assert_eq!(
+            rpc_urls(&selected_network_configs(&config, &testnet, None, true).unwrap()),
+            all
+        );
+        let selected = selected_network_configs(
+            &config,
+            &testnet,
+            Some(&["testnet".to_string(), "testnet-rpc".to_string()]),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            rpc_urls(&selected),
+            [
+                "testnet https://archival-rpc.testnet.near.org/",
+                "testnet-rpc https://rpc.testnet.near.org/"
+            ]
+        );
+        assert_eq!(
+            rpc_urls(&selected_network_configs(&config, &testnet, None, false).unwrap()),
+            ["testnet https://archival-rpc.testnet.near.org/"]
+        );
+        assert!(
+            selected_network_configs(&config, &testnet, Some(&["devnet".to_string()]), false)
+                .is_err()
+        );
+    }
+
+    fn summary(state: Result<(), crate::common::ErrorCategory>) -> AccountSummary {
+        connection_summary("testnet", state.map(|_| 0))
+    }
+
+    fn connection_summary(
+        connection_name: &str,
+        state: Result<u128, crate::common::ErrorCategory>,
+    ) -> AccountSummary {
+        AccountSummary {
+            connection_name: connection_name.to_string(),
+            network_name: "testnet".to_string(),
+            account_id: "alice.testnet".parse().unwrap(),
+            state: state
+                .map(|amount| {
+                    (
+                        near_primitives::views::AccountView {
+                            amount,
+                            locked: 0,
+                            code_hash: Default::default(),
+                            storage_usage: 0,
+                            storage_paid_at: 0,
+                        },
+                        vec![],
+                    )
+                })
+                .map_err(|category| (category, "error".to_string())),
+        }
+    }
+
+    #[test]
+    fn fails_only_when_every_account_failed() {
+        use crate::common::ErrorCategory::{RpcHandler, RpcTransport};
+        assert!(all_accounts_failed_error(&[]).is_none());
+        assert!(
+            all_accounts_failed_error(&[summary(Ok(())), summary(Err(RpcTransport))]).is_none()
+        );
+        assert_eq!(
+            all_accounts_failed_error(&[summary(Err(RpcHandler)), summary(Err(RpcHandler))])
+                .unwrap()
+                .category,
+            RpcHandler
+        );
+        assert_eq!(
+            all_accounts_failed_error(&[summary(Err(RpcHandler)), summary(Err(RpcTransport))])
+                .unwrap()
+                .category,
+            RpcTransport
+        );
+    }
+
+    #[test]
+    fn totals_every_connection_separately() {
+        let summaries = [
+            connection_summary("testnet", Ok(1)),
+            connection_summary("testnet-rpc", Ok(2)),
+            connection_summary("testnet", Ok(3)),
+            connection_summary(
+                "testnet-rpc",
+                Err(crate::common::ErrorCategory::RpcTransport),
+            ),
+        ];
+        assert_eq!(
+            connection_totals(&summaries)
+                .iter()
+                .map(|(summary, balance, ..)| (summary.connection_name.as_str(), *balance))
+                .collect::<Vec<_>>(),
+            [("testnet", 4), ("testnet-rpc", 2)]
+        );
+    }
+}
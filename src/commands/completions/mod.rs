@@ -0,0 +1,253 @@
+use inquire::Select;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = CompletionsContext)]
+pub struct Completions {
+    #[interactive_clap(value_enum)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// For which shell do you want to generate the completion script?
+    shell: Shell,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, clap::ValueEnum)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// For which shell do you want to generate the completion script?
+pub enum Shell {
+    #[strum_discriminants(strum(message = "bash"))]
+    /// Bash
+    Bash,
+    #[strum_discriminants(strum(message = "zsh"))]
+    /// Zsh
+    Zsh,
+    #[strum_discriminants(strum(message = "fish"))]
+    /// Fish
+    Fish,
+    #[strum_discriminants(strum(message = "powershell"))]
+    /// PowerShell
+    Powershell,
+}
+
+impl interactive_clap::ToCli for Shell {
+    type CliVariant = Shell;
+}
+
+impl std::str::FromStr for Shell {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "powershell" => Ok(Self::Powershell),
+            _ => Err("Shell: incorrect value entered".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Shell {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Bash => write!(f, "bash"),
+            Self::Zsh => write!(f, "zsh"),
+            Self::Fish => write!(f, "fish"),
+            Self::Powershell => write!(f, "powershell"),
+        }
+    }
+}
+
+impl std::fmt::Display for ShellDiscriminants {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.get_message().unwrap_or_default())
+    }
+}
+
+impl From<Shell> for clap_complete::Shell {
+    fn from(shell: Shell) -> Self {
+        match shell {
+            Shell::Bash => Self::Bash,
+            Shell::Zsh => Self::Zsh,
+            Shell::Fish => Self::Fish,
+            Shell::Powershell => Self::PowerShell,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CompletionsContext;
+
+impl CompletionsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Completions as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut command = completion_command(&previous_context.0);
+        clap_complete::generate(
+            clap_complete::Shell::from(scope.shell.clone()),
+            &mut command,
+            "near",
+            &mut std::io::stdout(),
+        );
+        Ok(Self)
+    }
+}
+
+/// The command the completion script is generated from: the root command with the suggested
+/// values of the local setup and the scripting mode flags.
+fn completion_command(config: &crate::config::Config) -> clap::Command {
+    // The scripting mode flags are taken off the command line before it is parsed (wherever they
+    // are), so they are not arguments of `Cmd`
+    DynamicValues::from_config(config)
+        .add_to_command(<super::CliCmd as clap::CommandFactory>::command())
+        .args(crate::common::SCRIPTING_MODE_FLAGS.map(|flag| {
+            let name = flag.trim_start_matches("--");
+            clap::Arg::new(name)
+                .long(name)
+                .action(clap::ArgAction::SetTrue)
+                .global(true)
+                .help("Never prompt, report missing arguments as errors")
+        }))
+}
+
+impl Completions {
+    fn input_shell(_context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<Shell>> {
+        let variants = ShellDiscriminants::iter().collect::<Vec<_>>();
+        let selected = Select::new(
+            "For which shell do you want to generate the completion script?",
+            variants,
+        )
+        .prompt()?;
+        match selected {
+            ShellDiscriminants::Bash => Ok(Some(Shell::Bash)),
+            ShellDiscriminants::Zsh => Ok(Some(Shell::Zsh)),
+            ShellDiscriminants::Fish => Ok(Some(Shell::Fish)),
+            ShellDiscriminants::Powershell => Ok(Some(Shell::Powershell)),
+        }
+    }
+}
+
+/// Values which depend on the local setup: connection names from the config, and account IDs and
+/// public keys of the access keys stored locally (the legacy keychain, and the macOS keychain on
+/// macOS).
+struct DynamicValues {
+    connection_names: Vec<String>,
+    account_ids: std::collections::BTreeSet<String>,
+    public_keys: std::collections::BTreeSet<String>,
+}
+
+impl DynamicValues {
+    fn from_config(config: &crate::config::Config) -> Self {
+        let mut account_ids = std::collections::BTreeSet::new();
+        let mut public_keys = std::collections::BTreeSet::new();
+        for network_config in config.network_connection.values() {
+            // A storage which can't be read just has nothing to suggest
+            let local_keys = crate::commands::local_keys::storage::list_local_keys(
+                &config.credentials_home_dir,
+                network_config,
+            )
+            .unwrap_or_default();
+            for local_key in local_keys {
+                account_ids.insert(local_key.account_id.to_string());
+                public_keys.insert(local_key.public_key.to_string());
+            }
+        }
+        Self {
+            connection_names: config.network_connection.keys().cloned().collect(),
+            account_ids,
+            public_keys,
+        }
+    }
+
+    fn values_for(&self, arg_id: &str) -> Option<Vec<String>> {
+        let values = match arg_id {
+            "network_name" | "connection_name" => self.connection_names.clone(),
+            "public_key" | "signer_public_key" => self.public_keys.iter().cloned().collect(),
+            // New accounts are not in the keychain yet
+            "new_account_id" => return None,
+            arg_id if arg_id.ends_with("account_id") => self.account_ids.iter().cloned().collect(),
+            _ => return None,
+        };
+        (!values.is_empty()).then_some(values)
+    }
+
+    /// Suggests the values for the matching arguments of every subcommand. The values are baked
+    /// into the script as possible values (`PossibleValuesParser`), so they are a snapshot taken
+    /// when the script is generated: connections, accounts and keys added later are only suggested
+    /// once the script is generated again (e.g. in every new shell session, see the guide).
+    /// The resulting command is only used to generate the completion script, so the values don't
+    /// restrict the input.
+    fn add_to_command(&self, mut command: clap::Command) -> clap::Command {
+        let arg_values = command
+            .get_arguments()
+            .filter_map(|arg| {
+                let arg_id = arg.get_id().as_str().to_owned();
+                self.values_for(&arg_id).map(|values| (arg_id, values))
+            })
+            .collect::<Vec<_>>();
+        for (arg_id, values) in arg_values {
+            command = command.mut_arg(arg_id, |arg| {
+                arg.value_parser(clap::builder::PossibleValuesParser::new(values))
+            });
+        }
+        let subcommand_names = command
+            .get_subcommands()
+            .map(|subcommand| subcommand.get_name().to_owned())
+            .collect::<Vec<_>>();
+        for subcommand_name in subcommand_names {
+            command = command.mut_subcommand(subcommand_name, |subcommand| {
+                self.add_to_command(subcommand)
+            });
+        }
+        command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_the_global_arguments() {
+        let command = completion_command(&crate::config::Config::default());
+        for long in ["output-format", "config", "scripting", "no-interactive"] {
+            assert!(
+                command
+                    .get_arguments()
+                    .any(|arg| arg.get_long() == Some(long)),
+                "{}",
+                long
+            );
+        }
+        assert!(command.find_subcommand("account").is_some());
+    }
+
+    #[test]
+    fn suggests_the_connection_names() {
+        let config = crate::config::Config::default();
+        let command = completion_command(&config);
+        let network_name = command
+            .find_subcommand("account")
+            .and_then(|command| command.find_subcommand("view-account-summary"))
+            .and_then(|command| command.find_subcommand("network-config"))
+            .and_then(|command| {
+                command
+                    .get_arguments()
+                    .find(|arg| arg.get_id() == "network_name")
+            })
+            .unwrap();
+        let mut possible_values = network_name
+            .get_possible_values()
+            .into_iter()
+            .map(|value| value.get_name().to_string())
+            .collect::<Vec<_>>();
+        possible_values.sort();
+        let mut connection_names = config
+            .network_connection
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        connection_names.sort();
+        assert_eq!(possible_values, connection_names);
+    }
+}
@@ -53,10 +53,22 @@ impl AddNetworkConnectionContext {
                     .clone()
                     .map(|linkdrop_account_id| linkdrop_account_id.into()),
                 faucet_url: scope.faucet_url.clone().map(|faucet_url| faucet_url.into()),
+                default_signing_method: None,
+                default_account_id: None,
+                rpc_retries: None,
+                rpc_retry_backoff_ms: None,
+                request_timeout_secs: None,
+                proxy_url: None,
+                extra_root_certificates: Vec::new(),
+                http_headers: std::collections::BTreeMap::new(),
+                auth: None,
+                fallback_rpc_endpoints: Vec::new(),
+                scripting_mode: previous_context.1.scripting_mode,
+                http_client: Default::default(),
             },
         );
         eprintln!();
-        crate::common::write_config_toml(config)?;
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
         eprintln!(
             "Network connection \"{}\" was successfully added to config.toml",
             &scope.connection_name
@@ -80,7 +92,11 @@ impl interactive_clap::FromCli for AddNetworkConnection {
     {
         let mut clap_variant = optional_clap_variant.unwrap_or_default();
         if clap_variant.network_name.is_none() {
-            clap_variant.network_name = match Self::input_network_name(&context) {
+            clap_variant.network_name = match context
+                .1
+                .ensure_interactive_mode("--network-name")
+                .and_then(|_| Self::input_network_name(&context))
+            {
                 Ok(Some(network_name)) => Some(network_name),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -88,7 +104,11 @@ impl interactive_clap::FromCli for AddNetworkConnection {
         };
         let network_name = clap_variant.network_name.clone().expect("Unexpected error");
         if clap_variant.connection_name.is_none() {
-            clap_variant.connection_name = match Self::input_connection_name(&context) {
+            clap_variant.connection_name = match context
+                .1
+                .ensure_interactive_mode("--connection-name")
+                .and_then(|_| Self::input_connection_name(&context))
+            {
                 Ok(Some(connection_name)) => Some(connection_name),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -99,7 +119,11 @@ impl interactive_clap::FromCli for AddNetworkConnection {
             .clone()
             .expect("Unexpected error");
         if clap_variant.rpc_url.is_none() {
-            clap_variant.rpc_url = match Self::input_rpc_url(&context) {
+            clap_variant.rpc_url = match context
+                .1
+                .ensure_interactive_mode("--rpc-url")
+                .and_then(|_| Self::input_rpc_url(&context))
+            {
                 Ok(Some(rpc_url)) => Some(rpc_url),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -107,7 +131,11 @@ impl interactive_clap::FromCli for AddNetworkConnection {
         };
         let rpc_url = clap_variant.rpc_url.clone().expect("Unexpected error");
         if clap_variant.wallet_url.is_none() {
-            clap_variant.wallet_url = match Self::input_wallet_url(&context) {
+            clap_variant.wallet_url = match context
+                .1
+                .ensure_interactive_mode("--wallet-url")
+                .and_then(|_| Self::input_wallet_url(&context))
+            {
                 Ok(Some(wallet_url)) => Some(wallet_url),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -115,14 +143,15 @@ impl interactive_clap::FromCli for AddNetworkConnection {
         };
         let wallet_url = clap_variant.wallet_url.clone().expect("Unexpected error");
         if clap_variant.explorer_transaction_url.is_none() {
-            clap_variant.explorer_transaction_url =
-                match Self::input_explorer_transaction_url(&context) {
-                    Ok(Some(explorer_transaction_url)) => Some(explorer_transaction_url),
-                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
-                    Err(err) => {
-                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
-                    }
-                };
+            clap_variant.explorer_transaction_url = match context
+                .1
+                .ensure_interactive_mode("--explorer-transaction-url")
+                .and_then(|_| Self::input_explorer_transaction_url(&context))
+            {
+                Ok(Some(explorer_transaction_url)) => Some(explorer_transaction_url),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
         };
         let explorer_transaction_url = clap_variant
             .explorer_transaction_url
@@ -170,8 +199,11 @@ impl interactive_clap::FromCli for AddNetworkConnection {
 
 impl AddNetworkConnection {
     fn input_rpc_api_key(
-        _context: &crate::GlobalContext,
+        context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::api_key::ApiKey>> {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
         eprintln!();
         #[derive(strum_macros::Display)]
         enum ConfirmOptions {
@@ -195,8 +227,11 @@ impl AddNetworkConnection {
     }
 
     fn input_linkdrop_account_id(
-        _context: &crate::GlobalContext,
+        context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
         eprintln!();
         #[derive(strum_macros::Display)]
         enum ConfirmOptions {
@@ -222,8 +257,11 @@ impl AddNetworkConnection {
     }
 
     fn input_faucet_url(
-        _context: &crate::GlobalContext,
+        context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::url::Url>> {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
         eprintln!();
         #[derive(strum_macros::Display)]
         enum ConfirmOptions {
@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = GetCliSettingContext)]
+pub struct GetCliSetting {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which preference?
+    key: crate::config::CliSettingKey,
+}
+
+#[derive(Clone)]
+pub struct GetCliSettingContext;
+
+impl GetCliSettingContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<GetCliSetting as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let cli_settings = previous_context.0.cli;
+        if let crate::common::OutputFormat::Json = previous_context.1.output_format {
+            crate::common::print_json_output(&serde_json::json!({
+                "key": scope.key.to_string(),
+                "value": cli_settings.get(&scope.key),
+                "is_set": cli_settings.is_set(&scope.key),
+            }))?;
+            return Ok(Self);
+        }
+        println!("{}", cli_settings.get(&scope.key));
+        Ok(Self)
+    }
+}
+
+impl GetCliSetting {
+    fn input_key(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::config::CliSettingKey>> {
+        super::input_cli_setting_key(context)
+    }
+}
@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListCliSettingsContext)]
+pub struct ListCliSettings;
+
+#[derive(Clone)]
+pub struct ListCliSettingsContext;
+
+impl ListCliSettingsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        _scope: &<ListCliSettings as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let config = previous_context.0;
+        let keys = config.cli.keys(&config);
+        if let crate::common::OutputFormat::Json = previous_context.1.output_format {
+            crate::common::print_json_output(&serde_json::json!({
+                "settings": keys
+                    .iter()
+                    .map(|key| serde_json::json!({
+                        "key": key.to_string(),
+                        "value": config.cli.get(key),
+                        "is_set": config.cli.is_set(key),
+                    }))
+                    .collect::<Vec<_>>(),
+            }))?;
+            return Ok(Self);
+        }
+        let mut table = prettytable::Table::new();
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        for key in keys {
+            table.add_row(prettytable::row![
+                Fg->key,
+                config.cli.get(&key),
+                if config.cli.is_set(&key) { "" } else { "(default)" }
+            ]);
+        }
+        table.printstd();
+        Ok(Self)
+    }
+}
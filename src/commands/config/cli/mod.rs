@@ -0,0 +1,44 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod get;
+mod list;
+mod set;
+mod unset;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct CliSettingsCommands {
+    #[interactive_clap(subcommand)]
+    cli_settings_actions: CliSettingsActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[non_exhaustive]
+/// What do you want to do with the CLI preferences?
+pub enum CliSettingsActions {
+    #[strum_discriminants(strum(message = "get     - Show the value of a preference"))]
+    /// Show the value of a preference
+    Get(self::get::GetCliSetting),
+    #[strum_discriminants(strum(message = "set     - Change the value of a preference"))]
+    /// Change the value of a preference
+    Set(self::set::SetCliSetting),
+    #[strum_discriminants(strum(
+        message = "unset   - Remove a preference, so the built-in default is used"
+    ))]
+    /// Remove a preference, so the built-in default is used
+    Unset(self::unset::UnsetCliSetting),
+    #[strum_discriminants(strum(message = "list    - Show all the preferences"))]
+    /// Show all the preferences
+    List(self::list::ListCliSettings),
+}
+
+fn input_cli_setting_key(
+    context: &crate::GlobalContext,
+) -> color_eyre::eyre::Result<Option<crate::config::CliSettingKey>> {
+    let config = &context.0;
+    let variants = config.cli.keys(config);
+    let selected = inquire::Select::new("Which preference?", variants).prompt()?;
+    Ok(Some(selected))
+}
@@ -0,0 +1,47 @@
+use inquire::Text;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SetCliSettingContext)]
+pub struct SetCliSetting {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which preference?
+    key: crate::config::CliSettingKey,
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the new value?
+    value: String,
+}
+
+#[derive(Clone)]
+pub struct SetCliSettingContext;
+
+impl SetCliSettingContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SetCliSetting as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut config = previous_context.0;
+        config.cli.set(&scope.key, &scope.value).map_err(|err| {
+            crate::common::CliError::new(crate::common::ErrorCategory::UserInput, err)
+        })?;
+        eprintln!();
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
+        eprintln!(
+            "The preference \"{}\" was successfully set to \"{}\" in config.toml",
+            &scope.key, &scope.value
+        );
+        Ok(Self)
+    }
+}
+
+impl SetCliSetting {
+    fn input_key(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::config::CliSettingKey>> {
+        super::input_cli_setting_key(context)
+    }
+
+    fn input_value(_context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<String>> {
+        Ok(Some(Text::new("What is the new value?").prompt()?))
+    }
+}
@@ -0,0 +1,40 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = UnsetCliSettingContext)]
+pub struct UnsetCliSetting {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which preference?
+    key: crate::config::CliSettingKey,
+}
+
+#[derive(Clone)]
+pub struct UnsetCliSettingContext;
+
+impl UnsetCliSettingContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<UnsetCliSetting as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut config = previous_context.0;
+        config.cli.unset(&scope.key);
+        eprintln!();
+        crate::common::write_config_toml(
+            config.clone(),
+            previous_context.1.config_path.as_deref(),
+        )?;
+        eprintln!(
+            "The preference \"{}\" was successfully removed from config.toml (the default value is \"{}\")",
+            &scope.key,
+            config.cli.get(&scope.key)
+        );
+        Ok(Self)
+    }
+}
+
+impl UnsetCliSetting {
+    fn input_key(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::config::CliSettingKey>> {
+        super::input_cli_setting_key(context)
+    }
+}
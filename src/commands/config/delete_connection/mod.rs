@@ -18,7 +18,7 @@ impl DeleteNetworkConnectionContext {
         let mut config = previous_context.0;
         config.network_connection.remove(&scope.connection_name);
         eprintln!();
-        crate::common::write_config_toml(config)?;
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
         eprintln!(
             "Network connection \"{}\" was successfully removed from config.toml",
             &scope.connection_name
@@ -0,0 +1,280 @@
+use inquire::Text;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = EditNetworkConnectionContext)]
+#[interactive_clap(skip_default_from_cli)]
+pub struct EditNetworkConnection {
+    /// What is the network connection name?
+    #[interactive_clap(skip_default_input_arg)]
+    connection_name: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    rpc_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    rpc_api_key: Option<super::ConnectionValue<crate::types::api_key::ApiKey>>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    wallet_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    explorer_transaction_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    linkdrop_account_id: Option<super::ConnectionValue<crate::types::account_id::AccountId>>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    faucet_url: Option<super::ConnectionValue<crate::types::url::Url>>,
+}
+
+#[derive(Clone)]
+pub struct EditNetworkConnectionContext;
+
+impl EditNetworkConnectionContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<EditNetworkConnection as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut config = previous_context.0;
+        let network_config = config
+            .network_connection
+            .get_mut(&scope.connection_name)
+            .ok_or_else(|| no_connection_error(&scope.connection_name))?;
+        if let Some(rpc_url) = &scope.rpc_url {
+            network_config.rpc_url = rpc_url.clone().into();
+        }
+        if let Some(rpc_api_key) = scope.rpc_api_key.clone() {
+            network_config.rpc_api_key = rpc_api_key.into_option();
+        }
+        if let Some(wallet_url) = &scope.wallet_url {
+            network_config.wallet_url = wallet_url.clone().into();
+        }
+        if let Some(explorer_transaction_url) = &scope.explorer_transaction_url {
+            network_config.explorer_transaction_url = explorer_transaction_url.clone().into();
+        }
+        if let Some(linkdrop_account_id) = scope.linkdrop_account_id.clone() {
+            network_config.linkdrop_account_id = linkdrop_account_id.into_option().map(Into::into);
+        }
+        if let Some(faucet_url) = scope.faucet_url.clone() {
+            network_config.faucet_url = faucet_url.into_option().map(Into::into);
+        }
+
+        eprintln!("\nChecking the RPC endpoint {} ...", network_config.rpc_url);
+        let status = tokio::runtime::Runtime::new()?
+            .block_on(
+                network_config
+                    .connect_rpc_endpoint(&network_config.rpc_endpoints()[0])?
+                    .call(near_jsonrpc_client::methods::status::RpcStatusRequest),
+            )
+            .map_err(|err| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::RpcTransport,
+                    format!(
+                        "The RPC endpoint {} did not respond to the `status` request, so the network connection \"{}\" was not changed: {}",
+                        network_config.rpc_url, &scope.connection_name, err
+                    ),
+                )
+            })?;
+        if status.chain_id != network_config.network_name {
+            eprintln!(
+                "Warning: the RPC endpoint serves the <{}> chain, but the network name of the connection is <{}>",
+                status.chain_id, network_config.network_name
+            );
+        }
+
+        eprintln!();
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
+        eprintln!(
+            "Network connection \"{}\" was successfully updated in config.toml",
+            &scope.connection_name
+        );
+        Ok(Self)
+    }
+}
+
+fn no_connection_error(connection_name: &str) -> crate::common::CliError {
+    crate::common::CliError::new(
+        crate::common::ErrorCategory::UserInput,
+        format!(
+            "There is no network connection \"{}\" in config.toml",
+            connection_name
+        ),
+    )
+}
+
+impl interactive_clap::FromCli for EditNetworkConnection {
+    type FromCliContext = crate::GlobalContext;
+    type FromCliError = color_eyre::eyre::Error;
+    fn from_cli(
+        optional_clap_variant: Option<<Self as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+        if clap_variant.connection_name.is_none() {
+            clap_variant.connection_name = match Self::input_connection_name(&context) {
+                Ok(Some(connection_name)) => Some(connection_name),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        };
+        let connection_name = clap_variant
+            .connection_name
+            .clone()
+            .expect("Unexpected error");
+        let network_config = match context.0.network_connection.get(&connection_name) {
+            Some(network_config) => network_config.clone(),
+            None => {
+                return interactive_clap::ResultFromCli::Err(
+                    Some(clap_variant),
+                    no_connection_error(&connection_name).into(),
+                )
+            }
+        };
+
+        // Without flags, every field is offered for editing with the current value prefilled
+        let is_any_field_passed = clap_variant.rpc_url.is_some()
+            || clap_variant.rpc_api_key.is_some()
+            || clap_variant.wallet_url.is_some()
+            || clap_variant.explorer_transaction_url.is_some()
+            || clap_variant.linkdrop_account_id.is_some()
+            || clap_variant.faucet_url.is_some();
+        if !is_any_field_passed {
+            if let Err(err) = context.1.ensure_interactive_mode(
+                "--rpc-url, --rpc-api-key, --wallet-url, --explorer-transaction-url, --linkdrop-account-id or --faucet-url",
+            )
+            .and_then(|_| Self::input_changes(&mut clap_variant, &network_config))
+            {
+                return interactive_clap::ResultFromCli::Err(Some(clap_variant), err);
+            }
+        }
+
+        let new_context_scope = InteractiveClapContextScopeForEditNetworkConnection {
+            connection_name,
+            rpc_url: clap_variant.rpc_url.clone(),
+            rpc_api_key: clap_variant.rpc_api_key.clone(),
+            wallet_url: clap_variant.wallet_url.clone(),
+            explorer_transaction_url: clap_variant.explorer_transaction_url.clone(),
+            linkdrop_account_id: clap_variant.linkdrop_account_id.clone(),
+            faucet_url: clap_variant.faucet_url.clone(),
+        };
+        if let Err(err) =
+            EditNetworkConnectionContext::from_previous_context(context, &new_context_scope)
+        {
+            return interactive_clap::ResultFromCli::Err(Some(clap_variant), err);
+        };
+        interactive_clap::ResultFromCli::Ok(clap_variant)
+    }
+}
+
+impl EditNetworkConnection {
+    fn input_connection_name(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        crate::common::input_network_name(context)
+    }
+
+    /// Asks for every field with the current value prefilled and keeps only the changed ones.
+    fn input_changes(
+        clap_variant: &mut CliEditNetworkConnection,
+        network_config: &crate::config::NetworkConfig,
+    ) -> crate::CliResult {
+        eprintln!();
+        clap_variant.rpc_url = input_changed_value(
+            "What is the RPC endpoint?",
+            network_config.rpc_url.to_string(),
+        )?;
+        clap_variant.rpc_api_key = input_changed_optional_value(
+            "What is the API key of the RPC endpoint?",
+            network_config
+                .rpc_api_key
+                .as_ref()
+                .map(|rpc_api_key| rpc_api_key.to_string()),
+        )?;
+        clap_variant.wallet_url = input_changed_value(
+            "What is the wallet endpoint?",
+            network_config.wallet_url.to_string(),
+        )?;
+        clap_variant.explorer_transaction_url = input_changed_value(
+            "What is the transaction explorer endpoint?",
+            network_config.explorer_transaction_url.to_string(),
+        )?;
+        clap_variant.linkdrop_account_id = input_changed_optional_value(
+            "What is the name of the account that hosts the \"linkdrop\" program?",
+            network_config
+                .linkdrop_account_id
+                .as_ref()
+                .map(|linkdrop_account_id| linkdrop_account_id.to_string()),
+        )?;
+        clap_variant.faucet_url = input_changed_optional_value(
+            "What is the faucet url?",
+            network_config
+                .faucet_url
+                .as_ref()
+                .map(|faucet_url| faucet_url.to_string()),
+        )?;
+        Ok(())
+    }
+}
+
+/// Returns `None` if the value was not changed or was left empty, so the current value is kept.
+fn input_changed_value<T>(
+    message: &str,
+    current_value: String,
+) -> color_eyre::eyre::Result<Option<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    loop {
+        let value = Text::new(message)
+            .with_initial_value(&current_value)
+            .prompt()?;
+        let value = value.trim();
+        if value.is_empty() || value == current_value {
+            return Ok(None);
+        }
+        match value.parse() {
+            Ok(value) => return Ok(Some(value)),
+            Err(err) => eprintln!("Invalid value <{}>: {}", value, err),
+        }
+    }
+}
+
+/// Returns `None` if the value was not changed, and [`super::ConnectionValue::Unset`] if the
+/// current value was cleared, so it is removed from the connection.
+fn input_changed_optional_value<T>(
+    message: &str,
+    current_value: Option<String>,
+) -> color_eyre::eyre::Result<Option<super::ConnectionValue<T>>>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(current_value) = current_value else {
+        return Ok(input_changed_value(message, String::new())?.map(super::ConnectionValue::Set));
+    };
+    let message = format!("{} (clear the value to remove it)", message);
+    loop {
+        let value = Text::new(&message)
+            .with_initial_value(&current_value)
+            .prompt()?;
+        let value = value.trim();
+        if value == current_value {
+            return Ok(None);
+        }
+        if value.is_empty() {
+            return Ok(Some(super::ConnectionValue::Unset));
+        }
+        match value.parse() {
+            Ok(value) => return Ok(Some(super::ConnectionValue::Set(value))),
+            Err(err) => eprintln!("Invalid value <{}>: {}", value, err),
+        }
+    }
+}
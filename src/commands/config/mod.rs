@@ -1,7 +1,13 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod add_connection;
+mod cli;
 mod delete_connection;
+mod edit_connection;
+mod select_connection;
+mod set_connection_defaults;
+mod show_selected_connection;
+mod test_connections;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
@@ -17,18 +23,93 @@ pub struct ConfigCommands {
 /// What do you want to do with a near CLI config?
 pub enum ConfigActions {
     #[strum_discriminants(strum(
-        message = "show-connections       - Show a list of network connections"
+        message = "show-connections         - Show a list of network connections"
     ))]
     /// Show a list of network connections
     ShowConnections(ShowConnections),
-    #[strum_discriminants(strum(message = "add-connection         - Add a network connection"))]
+    #[strum_discriminants(strum(message = "add-connection           - Add a network connection"))]
     /// Add a network connection
     AddConnection(self::add_connection::AddNetworkConnection),
     #[strum_discriminants(strum(
-        message = "delete-connection      - Delete a network connection"
+        message = "delete-connection        - Delete a network connection"
     ))]
     /// Delete a network connection
     DeleteConnection(self::delete_connection::DeleteNetworkConnection),
+    #[strum_discriminants(strum(
+        message = "edit-connection          - Edit a network connection"
+    ))]
+    /// Edit a network connection
+    EditConnection(self::edit_connection::EditNetworkConnection),
+    #[strum_discriminants(strum(
+        message = "select-connection        - Select the network connection used when the network name is omitted"
+    ))]
+    /// Select the network connection used when the network name is omitted
+    SelectConnection(self::select_connection::SelectNetworkConnection),
+    #[strum_discriminants(strum(
+        message = "show-selected-connection - Show the selected network connection and its defaults"
+    ))]
+    /// Show the selected network connection and its defaults
+    ShowSelectedConnection(self::show_selected_connection::ShowSelectedConnection),
+    #[strum_discriminants(strum(
+        message = "set-connection-defaults  - Set the default signing method and account of a network connection"
+    ))]
+    /// Set the default signing method and account of a network connection
+    SetConnectionDefaults(self::set_connection_defaults::SetConnectionDefaults),
+    #[strum_discriminants(strum(
+        message = "test-connections         - Check the latency and the latest block height of every RPC endpoint"
+    ))]
+    /// Check the latency and the latest block height of every RPC endpoint
+    TestConnections(self::test_connections::TestConnections),
+    #[strum_discriminants(strum(
+        message = "cli                      - Manage CLI-wide preferences (the [cli] section)"
+    ))]
+    /// Manage CLI-wide preferences (the [cli] section)
+    Cli(self::cli::CliSettingsCommands),
+}
+
+/// A new value of an optional field of a network connection: a value, or `none` to remove the
+/// current one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionValue<T> {
+    Set(T),
+    Unset,
+}
+
+impl<T> ConnectionValue<T> {
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Set(value) => Some(value),
+            Self::Unset => None,
+        }
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for ConnectionValue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Set(value) => write!(f, "{}", value),
+            Self::Unset => write!(f, "none"),
+        }
+    }
+}
+
+impl<T> std::str::FromStr for ConnectionValue<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "none" {
+            return Ok(Self::Unset);
+        }
+        T::from_str(s).map(Self::Set).map_err(|err| err.to_string())
+    }
+}
+
+impl<T: Clone> interactive_clap::ToCli for ConnectionValue<T> {
+    type CliVariant = ConnectionValue<T>;
 }
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -44,8 +125,15 @@ impl ShowConnectionsContext {
         previous_context: crate::GlobalContext,
         _scope: &<ShowConnections as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let mut path_config_toml = dirs::config_dir().expect("Impossible to get your config dir!");
-        path_config_toml.push("near-cli/config.toml");
+        let path_config_toml = crate::common::config_path(previous_context.1.config_path.clone())
+            .expect("Impossible to get your config dir!");
+        if let crate::common::OutputFormat::Json = previous_context.1.output_format {
+            crate::common::print_json_output(&serde_json::json!({
+                "config_path": path_config_toml,
+                "config": previous_context.0,
+            }))?;
+            return Ok(Self);
+        }
         eprintln!(
             "\nConfiguration data is stored in a file {:?}",
             &path_config_toml
@@ -55,3 +143,31 @@ impl ShowConnectionsContext {
         Ok(Self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_connection_values() {
+        assert_eq!(
+            ConnectionValue::<crate::config::SigningMethod>::from_str("sign-with-keychain"),
+            Ok(ConnectionValue::Set(
+                crate::config::SigningMethod::SignWithKeychain
+            ))
+        );
+        assert_eq!(
+            ConnectionValue::<crate::config::SigningMethod>::from_str("none"),
+            Ok(ConnectionValue::Unset)
+        );
+        assert!(ConnectionValue::<crate::config::SigningMethod>::from_str("sign").is_err());
+        assert_eq!(
+            ConnectionValue::<crate::types::account_id::AccountId>::from_str("alice.testnet")
+                .unwrap()
+                .into_option()
+                .map(|account_id| account_id.to_string()),
+            Some("alice.testnet".to_string())
+        );
+    }
+}
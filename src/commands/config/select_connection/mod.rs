@@ -0,0 +1,49 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SelectNetworkConnectionContext)]
+pub struct SelectNetworkConnection {
+    /// What is the network connection name?
+    #[interactive_clap(skip_default_input_arg)]
+    connection_name: String,
+}
+
+#[derive(Clone)]
+pub struct SelectNetworkConnectionContext;
+
+impl SelectNetworkConnectionContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SelectNetworkConnection as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut config = previous_context.0;
+        if !config
+            .network_connection
+            .contains_key(&scope.connection_name)
+        {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                format!(
+                    "There is no network connection \"{}\" in config.toml",
+                    &scope.connection_name
+                ),
+            )
+            .into());
+        }
+        config.select_connection(scope.connection_name.clone());
+        eprintln!();
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
+        eprintln!(
+            "Network connection \"{}\" was successfully selected. It is used when the network name is omitted.",
+            &scope.connection_name
+        );
+        Ok(Self)
+    }
+}
+
+impl SelectNetworkConnection {
+    fn input_connection_name(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        crate::common::input_network_name(context)
+    }
+}
@@ -0,0 +1,158 @@
+use inquire::{CustomType, Select};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SetConnectionDefaultsContext)]
+pub struct SetConnectionDefaults {
+    /// What is the network connection name?
+    #[interactive_clap(skip_default_input_arg)]
+    connection_name: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Signing method used when it is omitted: sign-with-macos-keychain, sign-with-keychain, sign-with-ledger or none
+    signing_method: Option<super::ConnectionValue<crate::config::SigningMethod>>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Account ID suggested as the signer, or none
+    account_id: Option<super::ConnectionValue<crate::types::account_id::AccountId>>,
+}
+
+#[derive(Clone)]
+pub struct SetConnectionDefaultsContext;
+
+impl SetConnectionDefaultsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SetConnectionDefaults as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut config = previous_context.0;
+        let network_config = config
+            .network_connection
+            .get_mut(&scope.connection_name)
+            .ok_or_else(|| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::UserInput,
+                    format!(
+                        "There is no network connection \"{}\" in config.toml",
+                        &scope.connection_name
+                    ),
+                )
+            })?;
+        // The defaults which are not passed are left as they are
+        if let Some(signing_method) = scope.signing_method.clone() {
+            network_config.default_signing_method = signing_method.into_option();
+        }
+        if let Some(account_id) = scope.account_id.clone() {
+            network_config.default_account_id = account_id.into_option().map(Into::into);
+        }
+        eprintln!();
+        crate::common::write_config_toml(config, previous_context.1.config_path.as_deref())?;
+        eprintln!(
+            "The defaults of the network connection \"{}\" were successfully updated in config.toml",
+            &scope.connection_name
+        );
+        Ok(Self)
+    }
+}
+
+impl SetConnectionDefaults {
+    fn input_connection_name(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        crate::common::input_network_name(context)
+    }
+
+    fn input_signing_method(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<super::ConnectionValue<crate::config::SigningMethod>>>
+    {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum SigningMethodOptions {
+            #[cfg(target_os = "macos")]
+            #[strum(
+                to_string = "sign-with-macos-keychain - Sign with a key saved in macOS keychain"
+            )]
+            SignWithMacosKeychain,
+            #[strum(
+                to_string = "sign-with-keychain       - Sign with a key saved in legacy keychain"
+            )]
+            SignWithKeychain,
+            #[cfg(feature = "ledger")]
+            #[strum(to_string = "sign-with-ledger         - Sign with Ledger Nano device")]
+            SignWithLedger,
+            #[strum(to_string = "No default, I want to choose the signing method every time")]
+            None,
+            #[strum(to_string = "Keep the current default signing method")]
+            Keep,
+        }
+        let select_choose_input = Select::new(
+            "Which signing method do you want to use by default?",
+            vec![
+                #[cfg(target_os = "macos")]
+                SigningMethodOptions::SignWithMacosKeychain,
+                SigningMethodOptions::SignWithKeychain,
+                #[cfg(feature = "ledger")]
+                SigningMethodOptions::SignWithLedger,
+                SigningMethodOptions::None,
+                SigningMethodOptions::Keep,
+            ],
+        )
+        .prompt()?;
+        Ok(match select_choose_input {
+            #[cfg(target_os = "macos")]
+            SigningMethodOptions::SignWithMacosKeychain => Some(super::ConnectionValue::Set(
+                crate::config::SigningMethod::SignWithMacosKeychain,
+            )),
+            SigningMethodOptions::SignWithKeychain => Some(super::ConnectionValue::Set(
+                crate::config::SigningMethod::SignWithKeychain,
+            )),
+            #[cfg(feature = "ledger")]
+            SigningMethodOptions::SignWithLedger => Some(super::ConnectionValue::Set(
+                crate::config::SigningMethod::SignWithLedger,
+            )),
+            SigningMethodOptions::None => Some(super::ConnectionValue::Unset),
+            SigningMethodOptions::Keep => None,
+        })
+    }
+
+    fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<super::ConnectionValue<crate::types::account_id::AccountId>>>
+    {
+        if context.1.scripting_mode {
+            return Ok(None);
+        }
+        eprintln!();
+        #[derive(strum_macros::Display)]
+        enum ConfirmOptions {
+            #[strum(to_string = "Yes, I want to enter the default account ID")]
+            Yes,
+            #[strum(to_string = "No, I want to remove the default account ID")]
+            No,
+            #[strum(to_string = "Keep the current default account ID")]
+            Keep,
+        }
+        let select_choose_input = Select::new(
+            "Do you want to set the default account ID for this connection?",
+            vec![
+                ConfirmOptions::Yes,
+                ConfirmOptions::No,
+                ConfirmOptions::Keep,
+            ],
+        )
+        .prompt()?;
+        Ok(match select_choose_input {
+            ConfirmOptions::Yes => {
+                let account_id: crate::types::account_id::AccountId =
+                    CustomType::new("What is the default account ID?").prompt()?;
+                Some(super::ConnectionValue::Set(account_id))
+            }
+            ConfirmOptions::No => Some(super::ConnectionValue::Unset),
+            ConfirmOptions::Keep => None,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ShowSelectedConnectionContext)]
+pub struct ShowSelectedConnection;
+
+#[derive(Clone)]
+pub struct ShowSelectedConnectionContext;
+
+impl ShowSelectedConnectionContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        _scope: &<ShowSelectedConnection as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let config = previous_context.0;
+        let selected_network_config = config.selected_network_config();
+        if let crate::common::OutputFormat::Json = previous_context.1.output_format {
+            crate::common::print_json_output(&match selected_network_config {
+                Some((connection_name, network_config)) => serde_json::json!({
+                    "connection_name": connection_name,
+                    "config": network_config,
+                }),
+                None => serde_json::json!({
+                    "connection_name": null,
+                    "config": null,
+                }),
+            })?;
+            return Ok(Self);
+        }
+        match selected_network_config {
+            Some((connection_name, network_config)) => {
+                eprintln!("\nSelected network connection: {}", connection_name);
+                eprintln!(
+                    "Default signing method: {}",
+                    network_config
+                        .default_signing_method
+                        .map_or_else(|| "not set".to_string(), |method| method.to_string())
+                );
+                eprintln!(
+                    "Default account ID: {}",
+                    network_config
+                        .default_account_id
+                        .as_ref()
+                        .map_or_else(|| "not set".to_string(), |account_id| account_id.to_string())
+                );
+            }
+            None => eprintln!("\nNo network connection is selected. Use `near config select-connection <connection-name>` to select one."),
+        }
+        Ok(Self)
+    }
+}
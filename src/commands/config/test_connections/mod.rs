@@ -0,0 +1,83 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = TestConnectionsContext)]
+pub struct TestConnections;
+
+#[derive(Clone)]
+pub struct TestConnectionsContext;
+
+impl TestConnectionsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        _scope: &<TestConnections as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let config = previous_context.0;
+        let runtime = tokio::runtime::Runtime::new()?;
+        let mut results = vec![];
+        for (connection_name, network_config) in &config.network_connection {
+            for rpc_endpoint in network_config.rpc_endpoints() {
+                eprintln!("Checking the RPC endpoint {} ...", rpc_endpoint.rpc_url);
+                let started_at = std::time::Instant::now();
+                let status = runtime.block_on(
+                    network_config
+                        .connect_rpc_endpoint(&rpc_endpoint)?
+                        .call(near_jsonrpc_client::methods::status::RpcStatusRequest),
+                );
+                results.push((
+                    connection_name,
+                    rpc_endpoint.rpc_url,
+                    started_at.elapsed(),
+                    status.map_err(|err| err.to_string()),
+                ));
+            }
+        }
+
+        if let crate::common::OutputFormat::Json = previous_context.1.output_format {
+            crate::common::print_json_output(&serde_json::json!({
+                "endpoints": results
+                    .iter()
+                    .map(|(connection_name, rpc_url, latency, status)| match status {
+                        Ok(status) => serde_json::json!({
+                            "connection_name": connection_name,
+                            "rpc_url": rpc_url,
+                            "latency_ms": latency.as_millis() as u64,
+                            "chain_id": status.chain_id,
+                            "latest_block_height": status.sync_info.latest_block_height,
+                        }),
+                        Err(err) => serde_json::json!({
+                            "connection_name": connection_name,
+                            "rpc_url": rpc_url,
+                            "error": err,
+                        }),
+                    })
+                    .collect::<Vec<_>>(),
+            }))?;
+            return Ok(Self);
+        }
+        let mut table = prettytable::Table::new();
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.set_titles(prettytable::row![
+            "Connection",
+            "RPC endpoint",
+            "Latency",
+            "Latest block height"
+        ]);
+        for (connection_name, rpc_url, latency, status) in results {
+            match status {
+                Ok(status) => table.add_row(prettytable::row![
+                    Fg->connection_name,
+                    rpc_url,
+                    format!("{} ms", latency.as_millis()),
+                    status.sync_info.latest_block_height
+                ]),
+                Err(err) => {
+                    let err = format!("unavailable: {}", err);
+                    table.add_row(prettytable::row![Fg->connection_name, rpc_url, "-", Fr->err])
+                }
+            };
+        }
+        eprintln!();
+        table.printstd();
+        Ok(Self)
+    }
+}
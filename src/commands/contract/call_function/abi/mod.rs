@@ -0,0 +1,1035 @@
+use color_eyre::eyre::Context;
+use inquire::{Select, Text};
+
+use crate::common::{CallResultExt, JsonRpcClientExt, RpcQueryResponseExt};
+
+const CONTRACT_ABI_METHOD_NAME: &str = "__contract_abi";
+const CONTRACT_ABI_WASM_SECTION_NAME: &str = "near-abi";
+
+/// Where to take the contract ABI from (`--abi`):
+/// - `contract` - from the contract deployed on the account, at the selected block;
+/// - `file:<path>` - from a local ABI file (JSON, optionally zstd-compressed).
+///
+/// With the ABI, `json-args` are validated before the call and the result is printed according to
+/// the declared return type. Without `--abi`, the contract ABI is only fetched when the `abi`
+/// Borsh schema is used.
+#[derive(Debug, Clone)]
+pub enum AbiSource {
+    Contract,
+    File(std::path::PathBuf),
+}
+
+impl interactive_clap::ToCli for AbiSource {
+    type CliVariant = AbiSource;
+}
+
+impl std::str::FromStr for AbiSource {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "contract" {
+            Ok(Self::Contract)
+        } else if let Some(path) = s.strip_prefix("file:") {
+            Ok(Self::File(shellexpand::tilde(path).as_ref().into()))
+        } else {
+            Err(format!(
+                "AbiSource: incorrect value entered <{}> (expected contract or file:<path>)",
+                s
+            ))
+        }
+    }
+}
+
+impl std::fmt::Display for AbiSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Contract => write!(f, "contract"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+        }
+    }
+}
+
+impl AbiSource {
+    pub fn load(
+        &self,
+        network_config: &crate::config::NetworkConfig,
+        account_id: &near_primitives::types::AccountId,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> color_eyre::eyre::Result<near_abi::AbiRoot> {
+        match self {
+            Self::Contract => get_contract_abi(network_config, account_id, block_reference)?
+                .ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "The contract on <{}> does not provide an ABI",
+                        account_id
+                    )
+                }),
+            Self::File(path) => {
+                let data = std::fs::read(path)
+                    .wrap_err_with(|| format!("Access to ABI file <{:?}> not found!", path))?;
+                parse_abi(&data)
+            }
+        }
+    }
+}
+
+/// Loads the ABI only when it is needed for the call: when it was requested with `--abi` or when
+/// a Borsh schema refers to the contract ABI.
+pub fn requested_abi(
+    abi_source: Option<&AbiSource>,
+    borsh_schema_uses_abi: bool,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    match abi_source {
+        Some(abi_source) => abi_source
+            .load(network_config, account_id, block_reference)
+            .map(Some),
+        None if borsh_schema_uses_abi => AbiSource::Contract
+            .load(network_config, account_id, block_reference)
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Fetches the ABI of the contract deployed on the account (if any) at the given block.
+///
+/// The ABI is cached locally by the code hash of the contract, so it is only downloaded once per
+/// deployed contract version.
+pub fn get_contract_abi(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    let account_view = network_config
+        .json_rpc_client()?
+        .blocking_call_view_account(account_id, block_reference.clone())
+        .wrap_err_with(|| format!("Failed to fetch query ViewAccount for <{}>", account_id))?
+        .account_view()?;
+    if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+        return Ok(None);
+    }
+
+    let cache_file_path = abi_cache_file_path(network_config, account_id, &account_view.code_hash);
+    if let Some(cache_file_path) = &cache_file_path {
+        if let Ok(cached_abi) = std::fs::read(cache_file_path) {
+            if let Ok(abi_root) = serde_json::from_slice::<near_abi::AbiRoot>(&cached_abi) {
+                return Ok(Some(abi_root));
+            }
+        }
+    }
+
+    let abi_root =
+        match fetch_abi_from_view_function(network_config, account_id, block_reference.clone())? {
+            Some(abi_root) => abi_root,
+            None => match fetch_abi_from_wasm_section(network_config, account_id, block_reference)?
+            {
+                Some(abi_root) => abi_root,
+                None => return Ok(None),
+            },
+        };
+
+    if let Some(cache_file_path) = &cache_file_path {
+        if let Err(err) = write_abi_to_cache(cache_file_path, &abi_root) {
+            eprintln!(
+                "Failed to save the contract ABI to the cache {:?}: {}",
+                cache_file_path, err
+            );
+        }
+    }
+    Ok(Some(abi_root))
+}
+
+/// Looks up the contract ABI for the interactive prompts, which come before the network is
+/// selected: only the network given on the command line or the selected connection is queried.
+pub fn find_contract_abi(
+    config: &crate::config::Config,
+    network_name: Option<&str>,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    let network_config = match network_name {
+        Some(network_name) => config.network_connection.get(network_name),
+        None => config
+            .selected_network_config()
+            .map(|(_, network_config)| network_config),
+    };
+    match network_config {
+        Some(network_config) => get_contract_abi(
+            network_config,
+            account_id,
+            near_primitives::types::Finality::Final.into(),
+        ),
+        None => Ok(None),
+    }
+}
+
+fn abi_cache_file_path(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    code_hash: &near_primitives::hash::CryptoHash,
+) -> Option<std::path::PathBuf> {
+    let mut path = dirs::cache_dir()?;
+    path.push("near-cli");
+    path.push("abi");
+    path.push(&network_config.network_name);
+    path.push(account_id.as_str());
+    path.push(format!("{}.json", code_hash));
+    Some(path)
+}
+
+fn write_abi_to_cache(
+    cache_file_path: &std::path::Path,
+    abi_root: &near_abi::AbiRoot,
+) -> color_eyre::eyre::Result<()> {
+    if let Some(cache_dir) = cache_file_path.parent() {
+        std::fs::create_dir_all(cache_dir)?;
+    }
+    std::fs::write(cache_file_path, serde_json::to_vec(abi_root)?)?;
+    Ok(())
+}
+
+fn fetch_abi_from_view_function(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    let query_view_method_response = match network_config.json_rpc_client()?.blocking_call(
+        near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: account_id.clone(),
+                method_name: CONTRACT_ABI_METHOD_NAME.to_owned(),
+                args: near_primitives::types::FunctionArgs::from(vec![]),
+            },
+        },
+    ) {
+        Ok(query_view_method_response) => query_view_method_response,
+        // The contract does not export the ABI method
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::ContractExecutionError {
+                    ..
+                },
+            ),
+        )) => return Ok(None),
+        Err(err) => {
+            return Err(err).wrap_err_with(|| {
+                format!(
+                    "Failed to call the {} function of <{}>",
+                    CONTRACT_ABI_METHOD_NAME, account_id
+                )
+            })
+        }
+    };
+    parse_abi(&query_view_method_response.call_result()?.result).map(Some)
+}
+
+fn fetch_abi_from_wasm_section(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    block_reference: near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<Option<near_abi::AbiRoot>> {
+    let query_view_method_response = network_config
+        .json_rpc_client()?
+        .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: account_id.clone(),
+            },
+        })
+        .wrap_err_with(|| format!("Failed to fetch query ViewCode for <{}>", account_id))?;
+    let contract_code_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg("Error call result".to_string()));
+        };
+    match find_wasm_custom_section(&contract_code_view.code, CONTRACT_ABI_WASM_SECTION_NAME) {
+        Some(section) => parse_abi(section).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// ABI is usually embedded zstd-compressed, but plain JSON is accepted as well.
+fn parse_abi(data: &[u8]) -> color_eyre::eyre::Result<near_abi::AbiRoot> {
+    let abi_json = match zstd::decode_all(data) {
+        Ok(decompressed_data) => decompressed_data,
+        Err(_) => data.to_vec(),
+    };
+    serde_json::from_slice(&abi_json).wrap_err("Failed to parse the contract ABI")
+}
+
+fn find_wasm_custom_section<'a>(code: &'a [u8], section_name: &str) -> Option<&'a [u8]> {
+    const WASM_HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    if !code.starts_with(&WASM_HEADER) {
+        return None;
+    }
+    let mut position = WASM_HEADER.len();
+    while position < code.len() {
+        let section_id = code[position];
+        position += 1;
+        let section_size = read_leb128_u32(code, &mut position)? as usize;
+        let section_end = position.checked_add(section_size)?;
+        if section_end > code.len() {
+            return None;
+        }
+        if section_id == 0 {
+            let mut name_position = position;
+            let name_size = read_leb128_u32(code, &mut name_position)? as usize;
+            let name_end = name_position.checked_add(name_size)?;
+            if name_end <= section_end && &code[name_position..name_end] == section_name.as_bytes()
+            {
+                return Some(&code[name_end..section_end]);
+            }
+        }
+        position = section_end;
+    }
+    None
+}
+
+fn read_leb128_u32(data: &[u8], position: &mut usize) -> Option<u32> {
+    let mut result: u32 = 0;
+    for shift in (0..35).step_by(7) {
+        let byte = *data.get(*position)?;
+        *position += 1;
+        // The fifth byte only holds the 4 highest bits of u32
+        if shift == 28 && byte & 0x70 != 0 {
+            return None;
+        }
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+    }
+    None
+}
+
+pub fn get_function<'a>(
+    abi_root: &'a near_abi::AbiRoot,
+    function_name: &str,
+) -> Option<&'a near_abi::AbiFunction> {
+    abi_root
+        .body
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+}
+
+fn definitions(abi_root: &near_abi::AbiRoot) -> serde_json::Value {
+    serde_json::to_value(&abi_root.body.root_schema)
+        .ok()
+        .and_then(|root_schema| root_schema.get("definitions").cloned())
+        .unwrap_or_else(|| serde_json::Value::Object(Default::default()))
+}
+
+fn function_kind(function: &near_abi::AbiFunction) -> &'static str {
+    match function.kind {
+        near_abi::AbiFunctionKind::View => "view",
+        near_abi::AbiFunctionKind::Call => "call",
+    }
+}
+
+pub fn input_function_name(
+    abi_root: &near_abi::AbiRoot,
+    only_view_functions: bool,
+) -> color_eyre::eyre::Result<Option<String>> {
+    struct FunctionItem<'a>(Option<&'a near_abi::AbiFunction>);
+
+    impl std::fmt::Display for FunctionItem<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self.0 {
+                Some(function) => {
+                    write!(f, "{:<40} ({})", function.name, function_kind(function))?;
+                    if let Some(doc) = function
+                        .doc
+                        .as_ref()
+                        .and_then(|doc| doc.lines().map(str::trim).find(|line| !line.is_empty()))
+                    {
+                        write!(f, " - {}", doc)?;
+                    }
+                    Ok(())
+                }
+                None => write!(f, "Enter the function name manually"),
+            }
+        }
+    }
+
+    let mut functions = abi_root
+        .body
+        .functions
+        .iter()
+        .filter(|function| {
+            !only_view_functions || matches!(function.kind, near_abi::AbiFunctionKind::View)
+        })
+        .filter(|function| {
+            !function
+                .modifiers
+                .contains(&near_abi::AbiFunctionModifier::Private)
+        })
+        .collect::<Vec<_>>();
+    if functions.is_empty() {
+        return Ok(None);
+    }
+    functions.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut variants = functions
+        .into_iter()
+        .map(|function| FunctionItem(Some(function)))
+        .collect::<Vec<_>>();
+    variants.push(FunctionItem(None));
+
+    eprintln!();
+    match Select::new("What is the name of the function?", variants).prompt()? {
+        FunctionItem(Some(function)) => Ok(Some(function.name.clone())),
+        FunctionItem(None) => Ok(None),
+    }
+}
+
+/// Prompts for every JSON argument of the function by its type and returns the JSON arguments.
+///
+/// Returns `None` if the function arguments are not JSON-serialized.
+pub fn input_function_args(
+    abi_root: &near_abi::AbiRoot,
+    function: &near_abi::AbiFunction,
+) -> color_eyre::eyre::Result<Option<serde_json::Value>> {
+    let args = match &function.params {
+        near_abi::AbiParameters::Json { args } => args,
+        near_abi::AbiParameters::Borsh { .. } => return Ok(None),
+    };
+    let definitions = definitions(abi_root);
+    let mut function_args = serde_json::Map::new();
+    for arg in args {
+        let type_schema = serde_json::to_value(&arg.type_schema)?;
+        let type_name = schema_type_name(&type_schema);
+        let is_string = resolve_schema(&type_schema, &definitions)
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            == Some("string");
+        let value = loop {
+            eprintln!();
+            let input = Text::new(&format!(
+                "Enter a value for the argument <{}> ({}):",
+                arg.name, type_name
+            ))
+            .with_help_message("Use JSON for complex values; leave empty for optional arguments")
+            .prompt()?;
+            let value = if input.trim().is_empty() {
+                serde_json::Value::Null
+            } else {
+                match serde_json::from_str::<serde_json::Value>(&input) {
+                    Ok(value) if !is_string || value.is_string() => value,
+                    // Strings can be entered without quotes
+                    _ if is_string => serde_json::Value::String(input),
+                    Ok(value) => value,
+                    Err(err) => {
+                        eprintln!("The value is not valid JSON: {}", err);
+                        continue;
+                    }
+                }
+            };
+            match validate_value(&value, &type_schema, &definitions, &arg.name) {
+                Ok(()) => break value,
+                Err(err) => eprintln!("{}", err),
+            }
+        };
+        if !value.is_null() {
+            function_args.insert(arg.name.clone(), value);
+        }
+    }
+    Ok(Some(serde_json::Value::Object(function_args)))
+}
+
+/// Validates JSON function arguments against the argument types declared in the contract ABI.
+pub fn validate_function_args(
+    abi_root: &near_abi::AbiRoot,
+    function_name: &str,
+    function_args: &[u8],
+) -> color_eyre::eyre::Result<()> {
+    let function = get_function(abi_root, function_name).ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The contract ABI does not declare the function <{}>",
+            function_name
+        )
+    })?;
+    let args = match &function.params {
+        near_abi::AbiParameters::Json { args } => args,
+        near_abi::AbiParameters::Borsh { .. } => color_eyre::eyre::bail!(
+            "The function <{}> expects Borsh-serialized arguments, but JSON arguments were given",
+            function_name
+        ),
+    };
+    let definitions = definitions(abi_root);
+    let function_args: serde_json::Value = if function_args.is_empty() {
+        serde_json::Value::Object(Default::default())
+    } else {
+        serde_json::from_slice(function_args).wrap_err("Data not in JSON format!")?
+    };
+    let function_args = function_args.as_object().ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The arguments of the function <{}> must be a JSON object",
+            function_name
+        )
+    })?;
+
+    let mut errors = vec![];
+    for arg in args {
+        let type_schema = serde_json::to_value(&arg.type_schema)?;
+        let value = function_args
+            .get(&arg.name)
+            .unwrap_or(&serde_json::Value::Null);
+        if let Err(err) = validate_value(value, &type_schema, &definitions, &arg.name) {
+            if function_args.contains_key(&arg.name) {
+                errors.push(err);
+            } else {
+                errors.push(format!(
+                    "{}: the argument is missing ({} is expected)",
+                    arg.name,
+                    schema_type_name(&type_schema)
+                ));
+            }
+        }
+    }
+    for name in function_args.keys() {
+        if !args.iter().any(|arg| &arg.name == name) {
+            errors.push(format!(
+                "{}: the function does not accept this argument",
+                name
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        color_eyre::eyre::bail!(
+            "The arguments do not match the contract ABI of the function <{}>:\n  {}",
+            function_name,
+            errors.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// Decodes the result of the function call according to the return type declared in the ABI.
+///
+/// Returns the description of the type along with the decoded value, or `None` if the result
+/// could not be interpreted with the declared type.
+pub fn decode_function_result(
+    abi_root: &near_abi::AbiRoot,
+    function: &near_abi::AbiFunction,
+    call_result: &near_primitives::views::CallResult,
+) -> color_eyre::eyre::Result<Option<(String, serde_json::Value)>> {
+    match &function.result {
+        None => Ok(None),
+        Some(near_abi::AbiType::Json { type_schema }) => {
+            let definitions = definitions(abi_root);
+            let type_schema = serde_json::to_value(type_schema)?;
+            let json_result = match call_result.parse_result_from_json::<serde_json::Value>() {
+                Ok(json_result) => json_result,
+                Err(_) => return Ok(None),
+            };
+            if let Err(err) = validate_value(&json_result, &type_schema, &definitions, "result") {
+                eprintln!(
+                    "WARNING: The result does not match the contract ABI: {}",
+                    err
+                );
+            }
+            Ok(Some((schema_type_name(&type_schema), json_result)))
+        }
+        Some(near_abi::AbiType::Borsh { type_schema }) => {
+            let borsh_type = super::borsh_schema::BorshType::from_container(type_schema)?;
+            match borsh_type.deserialize(&call_result.result) {
+                Ok(json_result) => Ok(Some((
+                    format!("{}, Borsh-serialized", type_schema.declaration),
+                    json_result,
+                ))),
+                Err(err) => {
+                    eprintln!(
+                        "WARNING: The result does not match the contract ABI: {}",
+                        err
+                    );
+                    Ok(None)
+                }
+            }
+        }
+    }
+}
+
+fn resolve_schema<'a>(
+    schema: &'a serde_json::Value,
+    definitions: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let mut schema = schema;
+    // Guard against cyclic references
+    for _ in 0..32 {
+        match schema
+            .get("$ref")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|reference| reference.strip_prefix("#/definitions/"))
+            .and_then(|name| definitions.get(name))
+        {
+            Some(referenced_schema) => schema = referenced_schema,
+            None => break,
+        }
+    }
+    schema
+}
+
+fn schema_type_name(schema: &serde_json::Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(serde_json::Value::as_str) {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    for keyword in ["anyOf", "oneOf", "allOf"] {
+        if let Some(subschemas) = schema.get(keyword).and_then(serde_json::Value::as_array) {
+            return subschemas
+                .iter()
+                .map(schema_type_name)
+                .collect::<Vec<_>>()
+                .join(" | ");
+        }
+    }
+    let type_name = match schema.get("type") {
+        Some(serde_json::Value::String(instance_type)) => instance_type.clone(),
+        Some(serde_json::Value::Array(instance_types)) => instance_types
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" | "),
+        _ => {
+            if schema.get("enum").is_some() {
+                "enum".to_string()
+            } else {
+                "any".to_string()
+            }
+        }
+    };
+    if type_name == "array" {
+        if let Some(items) = schema.get("items").filter(|items| items.is_object()) {
+            return format!("array of {}", schema_type_name(items));
+        }
+    }
+    match schema.get("format").and_then(serde_json::Value::as_str) {
+        Some(format) => format!("{} ({})", type_name, format),
+        None => type_name,
+    }
+}
+
+fn validate_value(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    definitions: &serde_json::Value,
+    path: &str,
+) -> Result<(), String> {
+    let schema = resolve_schema(schema, definitions);
+    let schema_object = match schema {
+        serde_json::Value::Bool(true) => return Ok(()),
+        serde_json::Value::Bool(false) => return Err(format!("{}: no value is allowed", path)),
+        serde_json::Value::Object(schema_object) => schema_object,
+        _ => return Ok(()),
+    };
+
+    if let Some(subschemas) = schema_object.get("allOf").and_then(|s| s.as_array()) {
+        for subschema in subschemas {
+            validate_value(value, subschema, definitions, path)?;
+        }
+    }
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(subschemas) = schema_object.get(keyword).and_then(|s| s.as_array()) {
+            if !subschemas
+                .iter()
+                .any(|subschema| validate_value(value, subschema, definitions, path).is_ok())
+            {
+                return Err(format!(
+                    "{}: expected {}, got {}",
+                    path,
+                    schema_type_name(schema),
+                    value
+                ));
+            }
+        }
+    }
+
+    if let Some(enum_values) = schema_object.get("enum").and_then(|s| s.as_array()) {
+        if !enum_values.contains(value) {
+            return Err(format!(
+                "{}: expected one of {}, got {}",
+                path,
+                enum_values
+                    .iter()
+                    .map(|enum_value| enum_value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                value
+            ));
+        }
+    }
+    if let Some(const_value) = schema_object.get("const") {
+        if const_value != value {
+            return Err(format!("{}: expected {}, got {}", path, const_value, value));
+        }
+    }
+
+    if let Some(instance_types) = schema_object.get("type") {
+        let instance_types = match instance_types {
+            serde_json::Value::String(instance_type) => vec![instance_type.as_str()],
+            serde_json::Value::Array(instance_types) => instance_types
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .collect(),
+            _ => vec![],
+        };
+        if !instance_types
+            .iter()
+            .any(|instance_type| value_has_type(value, instance_type))
+        {
+            return Err(format!(
+                "{}: expected {}, got {}",
+                path,
+                schema_type_name(schema),
+                value
+            ));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema_object.get("minimum").and_then(|s| s.as_f64()) {
+            if number < minimum {
+                return Err(format!("{}: {} is less than {}", path, value, minimum));
+            }
+        }
+        if let Some(maximum) = schema_object.get("maximum").and_then(|s| s.as_f64()) {
+            if number > maximum {
+                return Err(format!("{}: {} is greater than {}", path, value, maximum));
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema_object.get("properties").and_then(|s| s.as_object());
+        if let Some(required) = schema_object.get("required").and_then(|s| s.as_array()) {
+            for name in required.iter().filter_map(serde_json::Value::as_str) {
+                if !object.contains_key(name) {
+                    return Err(format!("{}.{}: the field is missing", path, name));
+                }
+            }
+        }
+        for (name, field_value) in object {
+            let field_path = format!("{}.{}", path, name);
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => {
+                    validate_value(field_value, field_schema, definitions, &field_path)?
+                }
+                None => match schema_object.get("additionalProperties") {
+                    Some(serde_json::Value::Bool(false)) => {
+                        return Err(format!("{}: unexpected field", field_path))
+                    }
+                    Some(additional_schema) => {
+                        validate_value(field_value, additional_schema, definitions, &field_path)?
+                    }
+                    None => {}
+                },
+            }
+        }
+    }
+
+    if let Some(array) = value.as_array() {
+        match schema_object.get("items") {
+            Some(serde_json::Value::Array(item_schemas)) => {
+                for (index, (item, item_schema)) in array.iter().zip(item_schemas).enumerate() {
+                    validate_value(
+                        item,
+                        item_schema,
+                        definitions,
+                        &format!("{}[{}]", path, index),
+                    )?;
+                }
+            }
+            Some(item_schema) => {
+                for (index, item) in array.iter().enumerate() {
+                    validate_value(
+                        item,
+                        item_schema,
+                        definitions,
+                        &format!("{}[{}]", path, index),
+                    )?;
+                }
+            }
+            None => {}
+        }
+        if let Some(min_items) = schema_object.get("minItems").and_then(|s| s.as_u64()) {
+            if (array.len() as u64) < min_items {
+                return Err(format!("{}: expected at least {} items", path, min_items));
+            }
+        }
+        if let Some(max_items) = schema_object.get("maxItems").and_then(|s| s.as_u64()) {
+            if (array.len() as u64) > max_items {
+                return Err(format!("{}: expected at most {} items", path, max_items));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn value_has_type(value: &serde_json::Value, instance_type: &str) -> bool {
+    match instance_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().map_or(false, |number| number.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WASM_HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    fn wasm_with_sections(sections: &[(u8, &[u8])]) -> Vec<u8> {
+        let mut code = WASM_HEADER.to_vec();
+        for (section_id, content) in sections {
+            code.push(*section_id);
+            code.push(content.len() as u8);
+            code.extend_from_slice(content);
+        }
+        code
+    }
+
+    fn custom_section(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut content = vec![name.len() as u8];
+        content.extend_from_slice(name.as_bytes());
+        content.extend_from_slice(payload);
+        content
+    }
+
+    #[test]
+    fn read_leb128_u32_single_byte() {
+        let mut position = 0;
+        assert_eq!(read_leb128_u32(&[0x2a], &mut position), Some(42));
+        assert_eq!(position, 1);
+    }
+    #[test]
+    fn read_leb128_u32_multiple_bytes() {
+        let mut position = 1;
+        assert_eq!(
+            read_leb128_u32(&[0xff, 0xe5, 0x8e, 0x26, 0x00], &mut position),
+            Some(624485)
+        );
+        assert_eq!(position, 4);
+    }
+    #[test]
+    fn read_leb128_u32_max() {
+        let mut position = 0;
+        assert_eq!(
+            read_leb128_u32(&[0xff, 0xff, 0xff, 0xff, 0x0f], &mut position),
+            Some(u32::MAX)
+        );
+    }
+    #[test]
+    fn read_leb128_u32_truncated() {
+        let mut position = 0;
+        assert_eq!(read_leb128_u32(&[0xe5, 0x8e], &mut position), None);
+        assert_eq!(read_leb128_u32(&[], &mut 0), None);
+    }
+    #[test]
+    fn read_leb128_u32_too_long() {
+        assert_eq!(
+            read_leb128_u32(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00], &mut 0),
+            None
+        );
+    }
+    #[test]
+    fn read_leb128_u32_overflow() {
+        assert_eq!(
+            read_leb128_u32(&[0xff, 0xff, 0xff, 0xff, 0x1f], &mut 0),
+            None
+        );
+    }
+    #[test]
+    fn find_wasm_custom_section_found() {
+        let code = wasm_with_sections(&[
+            (1, &[0x01, 0x60, 0x00, 0x00]),
+            (0, &custom_section("name", b"ignored")),
+            (0, &custom_section("near-abi", b"{\"abi\":1}")),
+        ]);
+        assert_eq!(
+            find_wasm_custom_section(&code, "near-abi"),
+            Some(&b"{\"abi\":1}"[..])
+        );
+    }
+    #[test]
+    fn find_wasm_custom_section_missing() {
+        let code = wasm_with_sections(&[
+            (1, &[0x01, 0x60, 0x00, 0x00]),
+            (0, &custom_section("name", b"near-abi")),
+        ]);
+        assert_eq!(find_wasm_custom_section(&code, "near-abi"), None);
+    }
+    #[test]
+    fn find_wasm_custom_section_not_wasm() {
+        assert_eq!(find_wasm_custom_section(b"\0asm", "near-abi"), None);
+        let mut code = wasm_with_sections(&[(0, &custom_section("near-abi", b"{}"))]);
+        code[4] = 0x02;
+        assert_eq!(find_wasm_custom_section(&code, "near-abi"), None);
+    }
+    #[test]
+    fn find_wasm_custom_section_truncated() {
+        let mut code = wasm_with_sections(&[(0, &custom_section("near-abi", b"{}"))]);
+        code.pop();
+        assert_eq!(find_wasm_custom_section(&code, "near-abi"), None);
+    }
+    #[test]
+    fn find_wasm_custom_section_malformed_size() {
+        let mut code = WASM_HEADER.to_vec();
+        code.extend_from_slice(&[0x00, 0xff, 0xff]);
+        assert_eq!(find_wasm_custom_section(&code, "near-abi"), None);
+    }
+    #[test]
+    fn find_wasm_custom_section_name_out_of_section() {
+        let code = wasm_with_sections(&[(0, &[0x10, b'n', b'e', b'a', b'r'])]);
+        assert_eq!(find_wasm_custom_section(&code, "near"), None);
+    }
+    #[test]
+    fn validate_value_accepts_matching_types() {
+        let definitions = serde_json::json!({
+            "U128": {"type": "string"},
+            "Token": {
+                "type": "object",
+                "required": ["token_id"],
+                "properties": {
+                    "token_id": {"type": "string"},
+                    "amount": {"$ref": "#/definitions/U128"},
+                    "memo": {"type": ["string", "null"]}
+                },
+                "additionalProperties": false
+            }
+        });
+        let schema = serde_json::json!({"$ref": "#/definitions/Token"});
+        assert_eq!(
+            validate_value(
+                &serde_json::json!({"token_id": "42", "amount": "1000", "memo": null}),
+                &schema,
+                &definitions,
+                "token"
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!([1, 2]),
+                &serde_json::json!({"type": "array", "items": {"type": "integer", "minimum": 0}, "maxItems": 2}),
+                &definitions,
+                "ids"
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!("b"),
+                &serde_json::json!({"anyOf": [{"enum": ["a", "b"]}, {"type": "integer"}]}),
+                &definitions,
+                "kind"
+            ),
+            Ok(())
+        );
+    }
+    #[test]
+    fn validate_value_rejects_wrong_type() {
+        assert_eq!(
+            validate_value(
+                &serde_json::json!(42),
+                &serde_json::json!({"type": "string"}),
+                &serde_json::json!({}),
+                "token_id"
+            ),
+            Err("token_id: expected string, got 42".to_string())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!(1.5),
+                &serde_json::json!({"type": "integer"}),
+                &serde_json::json!({}),
+                "count"
+            ),
+            Err("count: expected integer, got 1.5".to_string())
+        );
+    }
+    #[test]
+    fn validate_value_rejects_missing_and_unexpected_fields() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["token_id"],
+            "properties": {"token_id": {"type": "string"}},
+            "additionalProperties": false
+        });
+        assert_eq!(
+            validate_value(
+                &serde_json::json!({}),
+                &schema,
+                &serde_json::json!({}),
+                "args"
+            ),
+            Err("args.token_id: the field is missing".to_string())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!({"token_id": "1", "extra": true}),
+                &schema,
+                &serde_json::json!({}),
+                "args"
+            ),
+            Err("args.extra: unexpected field".to_string())
+        );
+    }
+    #[test]
+    fn validate_value_rejects_out_of_range() {
+        assert_eq!(
+            validate_value(
+                &serde_json::json!(-1),
+                &serde_json::json!({"type": "integer", "minimum": 0}),
+                &serde_json::json!({}),
+                "limit"
+            ),
+            Err("limit: -1 is less than 0".to_string())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!([1, 2, 3]),
+                &serde_json::json!({"type": "array", "maxItems": 2}),
+                &serde_json::json!({}),
+                "ids"
+            ),
+            Err("ids: expected at most 2 items".to_string())
+        );
+        assert_eq!(
+            validate_value(
+                &serde_json::json!("c"),
+                &serde_json::json!({"enum": ["a", "b"]}),
+                &serde_json::json!({}),
+                "kind"
+            ),
+            Err("kind: expected one of \"a\", \"b\", got \"c\"".to_string())
+        );
+    }
+    #[test]
+    fn abi_source_from_str() {
+        assert!(matches!(
+            "contract".parse::<AbiSource>(),
+            Ok(AbiSource::Contract)
+        ));
+        assert!(
+            matches!("file:abi.json".parse::<AbiSource>(), Ok(AbiSource::File(path)) if path == std::path::Path::new("abi.json"))
+        );
+        assert!("abi".parse::<AbiSource>().is_err());
+    }
+}
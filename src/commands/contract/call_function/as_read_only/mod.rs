@@ -1,9 +1,12 @@
 use crate::common::CallResultExt;
 use crate::common::JsonRpcClientExt;
 
+mod result_format;
+
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = CallFunctionViewContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct CallFunctionView {
     /// What is the account ID?
     account_id: crate::types::account_id::AccountId,
@@ -12,9 +15,37 @@ pub struct CallFunctionView {
     #[interactive_clap(value_enum)]
     #[interactive_clap(skip_default_input_arg)]
     /// How do you want to pass the function call arguments?
-    function_args_type: super::call_function_args_type::FunctionArgsType,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
     /// Enter the arguments to this function or the path to the arguments file
     function_args: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Borsh schema of the arguments for borsh-args: abi, file:<path> or an inline schema
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Validate the arguments and decode the result with the ABI: contract or file:<path>
+    abi: Option<super::abi::AbiSource>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// How to print the returned value: json, raw, base64, hex, utf8 or borsh:<schema>
+    result_format: Option<self::result_format::ResultFormat>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Select a part of the JSON result (e.g. .owner_id or .items[0].name)
+    select: Option<self::result_format::JsonSelector>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Write the returned value to the file instead of printing it
+    output_file: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Re-run the call on new final blocks at this interval (e.g. 30s) and print only the changes
+    watch: Option<crate::types::duration::Duration>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// With --watch, exit with an error once the (selected) result is below:<N>, above:<N> or equal:<N>
+    exit_if: Option<crate::types::threshold::Threshold>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
@@ -32,39 +63,117 @@ impl CallFunctionViewContext {
         let function_args_type = scope.function_args_type.clone();
         let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
         let function_name = scope.function_name.clone();
+        let borsh_schema = scope.borsh_schema.clone();
+        let abi = scope.abi.clone();
+        let result_format = scope.result_format.clone();
+        let borsh_schema_uses_abi = super::call_function_args_type::borsh_args_use_abi(
+            &function_args_type,
+            borsh_schema.as_ref(),
+        ) || matches!(
+            result_format,
+            Some(self::result_format::ResultFormat::Borsh(
+                super::borsh_schema::BorshSchemaSource::Abi
+            ))
+        );
+        let selector = scope.select.clone();
+        let output_format = previous_context.1.output_format.clone();
+        let output_file: Option<std::path::PathBuf> = scope
+            .output_file
+            .clone()
+            .map(|output_file| output_file.into());
+        crate::common::check_exit_if_needs_watch(scope.watch.as_ref(), scope.exit_if.as_ref())?;
+        let watch = scope.watch;
+        let exit_if = scope.exit_if.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
-                let args = super::call_function_args_type::function_args(
+                let abi_root = super::abi::requested_abi(
+                    abi.as_ref(),
+                    borsh_schema_uses_abi,
+                    network_config,
+                    &account_id,
+                    block_reference.clone(),
+                )?;
+                let args = super::call_function_args_type::function_args_with_borsh_schema(
                     function_args.clone(),
                     function_args_type.clone(),
+                    borsh_schema.as_ref(),
+                    abi_root.as_ref(),
+                    &function_name,
                 )?;
+                if let Some(abi_root) = &abi_root {
+                    if let super::call_function_args_type::CallFunctionArgsType::JsonArgs =
+                        function_args_type
+                    {
+                        super::abi::validate_function_args(abi_root, &function_name, &args)?;
+                    }
+                }
+
+                if let Some(watch) = watch {
+                    return crate::common::watch_final_blocks(network_config, block_reference, watch.into(), &output_format, |block_reference| {
+                        let call_result = network_config
+                            .json_rpc_client()?
+                            .blocking_call_view_function(
+                                &account_id,
+                                &function_name,
+                                args.clone(),
+                                block_reference.clone(),
+                            )?;
+                        self::result_format::watched_result(
+                            result_format.as_ref(),
+                            selector.as_ref(),
+                            abi_root.as_ref(),
+                            &function_name,
+                            &call_result,
+                            exit_if.as_ref(),
+                        )
+                    });
+                }
 
                 let call_result = network_config
-                .json_rpc_client()
+                .json_rpc_client()?
                 .blocking_call_view_function(
                     &account_id,
                     &function_name,
                     args,
                     block_reference.clone(),
                 )?;
-                call_result.print_logs();
-                eprintln!("Result:");
-                if call_result.result.is_empty() {
-                    eprintln!("Empty result");
-                } else if let Ok(json_result) = call_result.parse_result_from_json::<serde_json::Value>() {
-                    println!("{}", serde_json::to_string_pretty(&json_result)?);
-                } else if let Ok(string_result) = String::from_utf8(call_result.result) {
-                    println!("{string_result}");
-                } else {
-                    eprintln!("The returned value is not printable (binary data)");
+                if let crate::common::OutputFormat::Json = output_format {
+                    // The file gets the result as usual, and stdout gets the JSON document
+                    if output_file.is_some() {
+                        self::result_format::print_result(
+                            result_format.as_ref(),
+                            selector.as_ref(),
+                            output_file.as_deref(),
+                            abi_root.as_ref(),
+                            &function_name,
+                            &call_result,
+                        )?;
+                    }
+                    return self::result_format::print_json_result(
+                        result_format.as_ref(),
+                        selector.as_ref(),
+                        abi_root.as_ref(),
+                        &account_id,
+                        &function_name,
+                        &call_result,
+                    );
                 }
+                call_result.print_logs();
+                self::result_format::print_result(
+                    result_format.as_ref(),
+                    selector.as_ref(),
+                    output_file.as_deref(),
+                    abi_root.as_ref(),
+                    &function_name,
+                    &call_result,
+                )?;
                 eprintln!("--------------");
                 Ok(())
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -76,10 +185,198 @@ impl From<CallFunctionViewContext> for crate::network_view_at_block::ArgsForView
     }
 }
 
+impl interactive_clap::FromCli for CallFunctionView {
+    type FromCliContext = crate::GlobalContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<Self as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+
+        if clap_variant.account_id.is_none() {
+            clap_variant.account_id = match Self::input_account_id(&context) {
+                Ok(Some(account_id)) => Some(account_id),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let account_id = clap_variant.account_id.clone().expect("Unexpected error");
+
+        let abi_root = if clap_variant.function_name.is_none()
+            || clap_variant.function_args.is_none()
+        {
+            let network_name = clap_variant.network_config.as_ref().and_then(
+                |ClapNamedArgNetworkViewAtBlockArgsForCallFunctionView::NetworkConfig(cli_arg)| {
+                    cli_arg.network_name.clone()
+                },
+            );
+            match super::abi::find_contract_abi(
+                &context.0,
+                network_name.as_deref(),
+                &account_id.clone().into(),
+            ) {
+                Ok(abi_root) => abi_root,
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            }
+        } else {
+            None
+        };
+        // The prompts below are driven by the ABI, so the call is checked against it as well
+        if abi_root.is_some() && clap_variant.abi.is_none() {
+            clap_variant.abi = Some(super::abi::AbiSource::Contract);
+        }
+
+        if clap_variant.function_name.is_none() {
+            clap_variant.function_name =
+                match Self::input_function_name_from_abi(&context, abi_root.as_ref()) {
+                    Ok(Some(function_name)) => Some(function_name),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+        }
+        let function_name = clap_variant
+            .function_name
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args_type.is_none() && clap_variant.function_args.is_none() {
+            if let Some((abi_root, function)) = abi_root.as_ref().and_then(|abi_root| {
+                super::abi::get_function(abi_root, &function_name)
+                    .map(|function| (abi_root, function))
+            }) {
+                match super::abi::input_function_args(abi_root, function) {
+                    Ok(Some(function_args)) => {
+                        clap_variant.function_args_type =
+                            Some(super::call_function_args_type::CallFunctionArgsType::JsonArgs);
+                        clap_variant.function_args = Some(function_args.to_string());
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                }
+            }
+        }
+
+        if clap_variant.function_args_type.is_none() {
+            clap_variant.function_args_type = match Self::input_function_args_type(&context) {
+                Ok(Some(function_args_type)) => Some(function_args_type),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args_type = clap_variant
+            .function_args_type
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args.is_none() {
+            clap_variant.function_args = match Self::input_function_args(&context) {
+                Ok(Some(function_args)) => Some(function_args),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args = clap_variant
+            .function_args
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.borsh_schema.is_none() {
+            if let super::call_function_args_type::CallFunctionArgsType::BorshArgs =
+                function_args_type
+            {
+                clap_variant.borsh_schema = match Self::input_borsh_schema(&context) {
+                    Ok(optional_borsh_schema) => optional_borsh_schema,
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+            }
+        }
+        let borsh_schema = clap_variant.borsh_schema.clone();
+        let abi = clap_variant.abi.clone();
+        let result_format = clap_variant.result_format.clone();
+        let select = clap_variant.select.clone();
+        let output_file = clap_variant.output_file.clone();
+        let watch = clap_variant.watch;
+        let exit_if = clap_variant.exit_if.clone();
+
+        let new_context_scope = InteractiveClapContextScopeForCallFunctionView {
+            account_id,
+            function_name,
+            function_args_type,
+            function_args,
+            borsh_schema,
+            abi,
+            result_format,
+            select,
+            output_file,
+            watch,
+            exit_if,
+        };
+        let new_context =
+            match CallFunctionViewContext::from_previous_context(context, &new_context_scope) {
+                Ok(new_context) => new_context,
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+
+        match <crate::network_view_at_block::NetworkViewAtBlockArgs as interactive_clap::FromCli>::from_cli(
+            clap_variant.network_config.take().map(
+                |ClapNamedArgNetworkViewAtBlockArgsForCallFunctionView::NetworkConfig(cli_arg)| cli_arg,
+            ),
+            new_context.into(),
+        ) {
+            interactive_clap::ResultFromCli::Ok(cli_arg) | interactive_clap::ResultFromCli::Cancel(Some(cli_arg)) => {
+                clap_variant.network_config = Some(
+                    ClapNamedArgNetworkViewAtBlockArgsForCallFunctionView::NetworkConfig(cli_arg),
+                );
+                interactive_clap::ResultFromCli::Ok(clap_variant)
+            }
+            interactive_clap::ResultFromCli::Cancel(None) => interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_arg, err) => {
+                clap_variant.network_config = optional_cli_arg
+                    .map(ClapNamedArgNetworkViewAtBlockArgsForCallFunctionView::NetworkConfig);
+                interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+            }
+        }
+    }
+}
+
 impl CallFunctionView {
+    fn input_function_name_from_abi(
+        context: &crate::GlobalContext,
+        abi_root: Option<&near_abi::AbiRoot>,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        if let Some(abi_root) = abi_root {
+            if let Some(function_name) = super::abi::input_function_name(abi_root, true)? {
+                return Ok(Some(function_name));
+            }
+        }
+        Self::input_function_name(context)
+    }
+
     fn input_function_args_type(
         _context: &crate::GlobalContext,
-    ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::FunctionArgsType>> {
-        super::call_function_args_type::input_function_args_type()
+    ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::CallFunctionArgsType>>
+    {
+        super::call_function_args_type::input_call_function_args_type()
+    }
+
+    fn input_borsh_schema(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<super::borsh_schema::BorshSchemaSource>> {
+        super::borsh_schema::input_borsh_schema(&context.1)
     }
 }
@@ -0,0 +1,561 @@
+use std::io::Write;
+
+use color_eyre::eyre::Context;
+
+use crate::common::CallResultExt;
+
+/// How to interpret the value returned by a view-function call.
+#[derive(Debug, Clone)]
+pub enum ResultFormat {
+    /// JSON value (pretty-printed)
+    Json,
+    /// Bytes as they were returned by the contract
+    Raw,
+    /// Base64-encoded bytes
+    Base64,
+    /// Hex-encoded bytes
+    Hex,
+    /// UTF-8 text
+    Utf8,
+    /// Borsh-serialized value decoded with the schema (`borsh:<schema>`)
+    Borsh(super::super::borsh_schema::BorshSchemaSource),
+}
+
+impl interactive_clap::ToCli for ResultFormat {
+    type CliVariant = ResultFormat;
+}
+
+impl std::str::FromStr for ResultFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "raw" => Ok(Self::Raw),
+            "base64" => Ok(Self::Base64),
+            "hex" => Ok(Self::Hex),
+            "utf8" => Ok(Self::Utf8),
+            _ => match s.split_once(':') {
+                Some(("borsh", schema)) => Ok(Self::Borsh(schema.parse()?)),
+                _ => Err(format!(
+                    "ResultFormat: incorrect value entered <{}> (expected json, raw, base64, hex, utf8 or borsh:<schema>)",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ResultFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Raw => write!(f, "raw"),
+            Self::Base64 => write!(f, "base64"),
+            Self::Hex => write!(f, "hex"),
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Borsh(schema) => write!(f, "borsh:{}", schema),
+        }
+    }
+}
+
+enum ResultValue {
+    Empty,
+    Json(serde_json::Value),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl ResultValue {
+    /// The value as JSON, with binary data base64 encoded
+    fn into_json(self) -> serde_json::Value {
+        match self {
+            Self::Empty => serde_json::Value::Null,
+            Self::Json(json_value) => json_value,
+            Self::Text(text) => serde_json::Value::String(text),
+            Self::Bytes(bytes) => serde_json::Value::String(base64::encode(bytes)),
+        }
+    }
+}
+
+impl ResultFormat {
+    fn decode(
+        &self,
+        abi_root: Option<&near_abi::AbiRoot>,
+        function_name: &str,
+        call_result: &near_primitives::views::CallResult,
+    ) -> color_eyre::eyre::Result<ResultValue> {
+        let result = &call_result.result;
+        Ok(match self {
+            Self::Json => ResultValue::Json(call_result.parse_result_from_json()?),
+            Self::Raw => ResultValue::Bytes(result.clone()),
+            Self::Base64 => ResultValue::Text(base64::encode(result)),
+            Self::Hex => ResultValue::Text(hex::encode(result)),
+            Self::Utf8 => ResultValue::Text(
+                String::from_utf8(result.clone())
+                    .wrap_err("The returned value is not a valid UTF-8 text")?,
+            ),
+            Self::Borsh(schema) => ResultValue::Json(
+                schema
+                    .result_type(abi_root, function_name)?
+                    .deserialize(result)?,
+            ),
+        })
+    }
+}
+
+/// Decodes the result the same way as it was done before `--result-format` was introduced:
+/// according to the contract ABI, as JSON or as UTF-8 text.
+fn decode_auto(
+    abi_root: Option<&near_abi::AbiRoot>,
+    function_name: &str,
+    call_result: &near_primitives::views::CallResult,
+) -> color_eyre::eyre::Result<(String, ResultValue)> {
+    if let Some((abi_root, function)) = abi_root.and_then(|abi_root| {
+        super::super::abi::get_function(abi_root, function_name)
+            .map(|function| (abi_root, function))
+    }) {
+        if let Some((type_name, json_result)) =
+            super::super::abi::decode_function_result(abi_root, function, call_result)?
+        {
+            return Ok((
+                format!("Result ({}):", type_name),
+                ResultValue::Json(json_result),
+            ));
+        }
+    }
+    let value = if call_result.result.is_empty() {
+        ResultValue::Empty
+    } else if let Ok(json_result) = call_result.parse_result_from_json::<serde_json::Value>() {
+        ResultValue::Json(json_result)
+    } else if let Ok(string_result) = String::from_utf8(call_result.result.clone()) {
+        ResultValue::Text(string_result)
+    } else {
+        ResultValue::Bytes(call_result.result.clone())
+    };
+    Ok(("Result:".to_string(), value))
+}
+
+/// Prints the result of the view-function call to stdout (or writes it to the output file).
+pub fn print_result(
+    result_format: Option<&ResultFormat>,
+    selector: Option<&JsonSelector>,
+    output_file: Option<&std::path::Path>,
+    abi_root: Option<&near_abi::AbiRoot>,
+    function_name: &str,
+    call_result: &near_primitives::views::CallResult,
+) -> crate::CliResult {
+    let (title, value) = decode_selected(
+        result_format,
+        selector,
+        abi_root,
+        function_name,
+        call_result,
+    )?;
+    let output = match value {
+        ResultValue::Empty => {
+            if output_file.is_none() {
+                eprintln!("Result:");
+                eprintln!("Empty result");
+                return Ok(());
+            }
+            vec![]
+        }
+        // Scripts usually need a selected string as is, without JSON quotes
+        ResultValue::Json(serde_json::Value::String(string)) if selector.is_some() => {
+            format!("{}\n", string).into_bytes()
+        }
+        ResultValue::Json(json_value) => {
+            format!("{}\n", serde_json::to_string_pretty(&json_value)?).into_bytes()
+        }
+        ResultValue::Text(text) => format!("{}\n", text).into_bytes(),
+        ResultValue::Bytes(bytes) => {
+            if result_format.is_none() && output_file.is_none() {
+                eprintln!("Result:");
+                eprintln!("The returned value is not printable (binary data)");
+                return Ok(());
+            }
+            bytes
+        }
+    };
+    match output_file {
+        Some(output_file) => {
+            std::fs::File::create(output_file)
+                .wrap_err_with(|| format!("Failed to create file: {:?}", output_file))?
+                .write_all(&output)
+                .wrap_err_with(|| format!("Failed to write to file: {:?}", output_file))?;
+            eprintln!("The result was written to the file {:?}", output_file);
+        }
+        None => {
+            eprintln!("{}", title);
+            std::io::stdout()
+                .write_all(&output)
+                .wrap_err("Failed to write the result to stdout")?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the result of the view-function call with `--output-format json`: the decoded (and
+/// selected) value together with the logs, binary data base64 encoded.
+pub fn print_json_result(
+    result_format: Option<&ResultFormat>,
+    selector: Option<&JsonSelector>,
+    abi_root: Option<&near_abi::AbiRoot>,
+    account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    call_result: &near_primitives::views::CallResult,
+) -> crate::CliResult {
+    let (_, value) = decode_selected(
+        result_format,
+        selector,
+        abi_root,
+        function_name,
+        call_result,
+    )?;
+    crate::common::print_json_output(&serde_json::json!({
+        "account_id": account_id,
+        "function_name": function_name,
+        "logs": call_result.logs,
+        "result": value.into_json(),
+    }))
+}
+
+/// Decodes the result with the format (or automatically) and applies the `--select` expression.
+fn decode_selected(
+    result_format: Option<&ResultFormat>,
+    selector: Option<&JsonSelector>,
+    abi_root: Option<&near_abi::AbiRoot>,
+    function_name: &str,
+    call_result: &near_primitives::views::CallResult,
+) -> color_eyre::eyre::Result<(String, ResultValue)> {
+    let (title, value) = match result_format {
+        Some(result_format) => (
+            "Result:".to_string(),
+            result_format.decode(abi_root, function_name, call_result)?,
+        ),
+        None => decode_auto(abi_root, function_name, call_result)?,
+    };
+    let value = match selector {
+        Some(selector) => match value {
+            ResultValue::Json(json_value) => ResultValue::Json(
+                selector
+                    .select(&json_value)
+                    .map_err(color_eyre::Report::msg)?,
+            ),
+            _ => color_eyre::eyre::bail!(
+                "The --select expression can only be applied to a JSON value (see --result-format)"
+            ),
+        },
+        None => value,
+    };
+    Ok((title, value))
+}
+
+/// The result as it is followed by `--watch`: binary data is base64 encoded, and only a number
+/// (or a string holding an integer, as u128 values are returned) can meet the `--exit-if`
+/// condition.
+pub fn watched_result(
+    result_format: Option<&ResultFormat>,
+    selector: Option<&JsonSelector>,
+    abi_root: Option<&near_abi::AbiRoot>,
+    function_name: &str,
+    call_result: &near_primitives::views::CallResult,
+    exit_if: Option<&crate::types::threshold::Threshold>,
+) -> color_eyre::eyre::Result<crate::common::WatchedValue> {
+    let (_, value) = decode_selected(
+        result_format,
+        selector,
+        abi_root,
+        function_name,
+        call_result,
+    )?;
+    let json = value.into_json();
+    let threshold_met = match exit_if {
+        Some(exit_if) => {
+            let number = match &json {
+                serde_json::Value::Number(number) => number.as_u64().map(u128::from),
+                serde_json::Value::String(string) => string.parse::<u128>().ok(),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                color_eyre::eyre::eyre!(
+                    "--exit-if needs a non-negative integer result (see --select), but got {}",
+                    json
+                )
+            })?;
+            exit_if.is_met(number, 0)?
+        }
+        None => false,
+    };
+    Ok(crate::common::WatchedValue {
+        text: json.to_string(),
+        json,
+        threshold_met,
+    })
+}
+
+/// JSONPath/jq-style expression to select a part of the JSON result, e.g. `.owner`,
+/// `.items[0].name`, `$.items[*].name` or `.balances["alice.near"]`.
+#[derive(Debug, Clone)]
+pub struct JsonSelector {
+    expression: String,
+    segments: Vec<SelectorSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SelectorSegment {
+    Field(String),
+    Index(i64),
+    Iterate,
+}
+
+impl interactive_clap::ToCli for JsonSelector {
+    type CliVariant = JsonSelector;
+}
+
+impl std::fmt::Display for JsonSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl std::str::FromStr for JsonSelector {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| format!("Invalid --select expression <{}>: {}", s, reason);
+        let mut rest = s.trim();
+        rest = rest.strip_prefix('$').unwrap_or(rest);
+        let mut segments = vec![];
+        while !rest.is_empty() {
+            if let Some(after_dot) = rest.strip_prefix('.') {
+                if after_dot.is_empty() || after_dot.starts_with('[') {
+                    rest = after_dot;
+                    continue;
+                }
+                let end = after_dot
+                    .find(|c| c == '.' || c == '[')
+                    .unwrap_or(after_dot.len());
+                let name = &after_dot[..end];
+                if name == "*" {
+                    segments.push(SelectorSegment::Iterate);
+                } else {
+                    segments.push(SelectorSegment::Field(name.to_string()));
+                }
+                rest = &after_dot[end..];
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let end = if let Some(quoted) = after_bracket.strip_prefix('"') {
+                    // Quoted field names may contain dots and brackets
+                    let closing_quote = quoted
+                        .find('"')
+                        .ok_or_else(|| invalid("unterminated quote"))?;
+                    closing_quote + 2
+                } else {
+                    after_bracket
+                        .find(']')
+                        .ok_or_else(|| invalid("missing closing bracket"))?
+                };
+                let inner = &after_bracket[..end];
+                rest = after_bracket[end..]
+                    .strip_prefix(']')
+                    .ok_or_else(|| invalid("missing closing bracket"))?;
+                if inner.is_empty() || inner == "*" {
+                    segments.push(SelectorSegment::Iterate);
+                } else if let Some(name) = inner
+                    .strip_prefix('"')
+                    .and_then(|inner| inner.strip_suffix('"'))
+                {
+                    segments.push(SelectorSegment::Field(name.to_string()));
+                } else {
+                    segments.push(SelectorSegment::Index(
+                        inner
+                            .parse()
+                            .map_err(|_| invalid(&format!("<{}> is not an index", inner)))?,
+                    ));
+                }
+            } else {
+                return Err(invalid("expected '.' or '['"));
+            }
+        }
+        Ok(Self {
+            expression: s.to_string(),
+            segments,
+        })
+    }
+}
+
+impl JsonSelector {
+    /// Selects the value; if the expression iterates over arrays, the selected values are
+    /// collected into an array.
+    pub fn select(&self, value: &serde_json::Value) -> Result<serde_json::Value, String> {
+        let mut values = vec![value.clone()];
+        for segment in &self.segments {
+            let mut next_values = vec![];
+            for value in values {
+                match (segment, value) {
+                    (SelectorSegment::Field(name), serde_json::Value::Object(mut object)) => {
+                        next_values.push(object.remove(name).unwrap_or(serde_json::Value::Null))
+                    }
+                    (SelectorSegment::Field(_), serde_json::Value::Null) => {
+                        next_values.push(serde_json::Value::Null)
+                    }
+                    (SelectorSegment::Index(index), serde_json::Value::Array(mut array)) => {
+                        let index = if *index < 0 {
+                            array.len() as i64 + index
+                        } else {
+                            *index
+                        };
+                        next_values.push(if index >= 0 && (index as usize) < array.len() {
+                            array.swap_remove(index as usize)
+                        } else {
+                            serde_json::Value::Null
+                        });
+                    }
+                    (SelectorSegment::Index(_), serde_json::Value::Null) => {
+                        next_values.push(serde_json::Value::Null)
+                    }
+                    (SelectorSegment::Iterate, serde_json::Value::Array(array)) => {
+                        next_values.extend(array)
+                    }
+                    (SelectorSegment::Iterate, serde_json::Value::Object(object)) => {
+                        next_values.extend(object.into_iter().map(|(_, value)| value))
+                    }
+                    (segment, value) => {
+                        return Err(format!(
+                            "Cannot apply {} to {}",
+                            match segment {
+                                SelectorSegment::Field(name) => format!("field <{}>", name),
+                                SelectorSegment::Index(index) => format!("index [{}]", index),
+                                SelectorSegment::Iterate => "iteration".to_string(),
+                            },
+                            value
+                        ))
+                    }
+                }
+            }
+            values = next_values;
+        }
+        if self.segments.contains(&SelectorSegment::Iterate) {
+            Ok(serde_json::Value::Array(values))
+        } else {
+            Ok(values.pop().unwrap_or(serde_json::Value::Null))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn select(expression: &str, value: serde_json::Value) -> Result<serde_json::Value, String> {
+        JsonSelector::from_str(expression)?.select(&value)
+    }
+
+    #[test]
+    fn json_selector_parse_segments() {
+        assert_eq!(
+            JsonSelector::from_str("$.items[0].name").unwrap().segments,
+            vec![
+                SelectorSegment::Field("items".to_string()),
+                SelectorSegment::Index(0),
+                SelectorSegment::Field("name".to_string()),
+            ]
+        );
+        assert_eq!(
+            JsonSelector::from_str(r#".balances["alice.near"][*]"#)
+                .unwrap()
+                .segments,
+            vec![
+                SelectorSegment::Field("balances".to_string()),
+                SelectorSegment::Field("alice.near".to_string()),
+                SelectorSegment::Iterate,
+            ]
+        );
+        assert_eq!(
+            JsonSelector::from_str(".items.*[-1]").unwrap().segments,
+            vec![
+                SelectorSegment::Field("items".to_string()),
+                SelectorSegment::Iterate,
+                SelectorSegment::Index(-1),
+            ]
+        );
+        assert!(JsonSelector::from_str(".").unwrap().segments.is_empty());
+        assert!(JsonSelector::from_str("$").unwrap().segments.is_empty());
+    }
+    #[test]
+    fn json_selector_parse_errors() {
+        assert!(JsonSelector::from_str(".items[0").is_err());
+        assert!(JsonSelector::from_str(r#".balances["alice.near]"#).is_err());
+        assert!(JsonSelector::from_str(".items[first]").is_err());
+        assert!(JsonSelector::from_str("items").is_err());
+    }
+    #[test]
+    fn json_selector_select_field_and_index() {
+        let value = serde_json::json!({
+            "owner_id": "alice.near",
+            "items": [{"name": "a"}, {"name": "b"}, {"name": "c"}]
+        });
+        assert_eq!(
+            select(".owner_id", value.clone()),
+            Ok(serde_json::json!("alice.near"))
+        );
+        assert_eq!(
+            select(".items[1].name", value.clone()),
+            Ok(serde_json::json!("b"))
+        );
+        assert_eq!(
+            select(".items[-1].name", value.clone()),
+            Ok(serde_json::json!("c"))
+        );
+        assert_eq!(
+            select(".items[5]", value.clone()),
+            Ok(serde_json::Value::Null)
+        );
+        assert_eq!(
+            select(".missing.field", value.clone()),
+            Ok(serde_json::Value::Null)
+        );
+        assert_eq!(select(".", value.clone()), Ok(value));
+    }
+    #[test]
+    fn json_selector_select_iteration() {
+        let value = serde_json::json!({
+            "items": [{"name": "a"}, {"name": "b"}],
+            "balances": {"alice.near": "10", "bob.near": "20"}
+        });
+        assert_eq!(
+            select("$.items[*].name", value.clone()),
+            Ok(serde_json::json!(["a", "b"]))
+        );
+        assert_eq!(
+            select(".balances[]", value.clone()),
+            Ok(serde_json::json!(["10", "20"]))
+        );
+        assert_eq!(
+            select(r#".balances["alice.near"]"#, value),
+            Ok(serde_json::json!("10"))
+        );
+    }
+    #[test]
+    fn result_values_as_json() {
+        assert_eq!(ResultValue::Empty.into_json(), serde_json::Value::Null);
+        assert_eq!(
+            ResultValue::Json(serde_json::json!({"a": 1})).into_json(),
+            serde_json::json!({"a": 1})
+        );
+        assert_eq!(
+            ResultValue::Text("text".to_string()).into_json(),
+            serde_json::json!("text")
+        );
+        assert_eq!(
+            ResultValue::Bytes(vec![0, 159]).into_json(),
+            serde_json::json!("AJ8=")
+        );
+    }
+    #[test]
+    fn json_selector_select_type_mismatch() {
+        assert!(select(".name", serde_json::json!([1, 2])).is_err());
+        assert!(select("[0]", serde_json::json!({"a": 1})).is_err());
+        assert!(select("[*]", serde_json::json!("text")).is_err());
+    }
+}
@@ -5,6 +5,7 @@ use inquire::Text;
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = CallFunctionPropertiesContext)]
+#[interactive_clap(skip_default_from_cli)]
 pub struct CallFunctionProperties {
     /// What is the contract account ID?
     contract_account_id: crate::types::account_id::AccountId,
@@ -13,9 +14,17 @@ pub struct CallFunctionProperties {
     #[interactive_clap(value_enum)]
     #[interactive_clap(skip_default_input_arg)]
     /// How do you want to pass the function call arguments?
-    function_args_type: super::call_function_args_type::FunctionArgsType,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
     /// Enter the arguments to this function or the path to the arguments file
     function_args: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Borsh schema of the arguments for borsh-args: abi, file:<path> or an inline schema
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Validate the arguments with the ABI before signing: contract or file:<path>
+    abi: Option<super::abi::AbiSource>,
     #[interactive_clap(named_arg)]
     /// Enter gas for function call
     prepaid_gas: PrepaidGas,
@@ -23,10 +32,13 @@ pub struct CallFunctionProperties {
 
 #[derive(Debug, Clone)]
 pub struct CallFunctionPropertiesContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     function_name: String,
-    function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
+    function_args: String,
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    abi: Option<super::abi::AbiSource>,
 }
 
 impl CallFunctionPropertiesContext {
@@ -34,24 +46,217 @@ impl CallFunctionPropertiesContext {
         previous_context: crate::GlobalContext,
         scope: &<CallFunctionProperties as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let function_args = super::call_function_args_type::function_args(
-            scope.function_args.clone(),
-            scope.function_args_type.clone(),
-        )?;
+        // Borsh arguments described by the contract ABI can only be serialized once the network
+        // is selected, the rest are checked right away.
+        if !super::call_function_args_type::borsh_args_use_abi(
+            &scope.function_args_type,
+            scope.borsh_schema.as_ref(),
+        ) {
+            super::call_function_args_type::function_args_with_borsh_schema(
+                scope.function_args.clone(),
+                scope.function_args_type.clone(),
+                scope.borsh_schema.as_ref(),
+                None,
+                &scope.function_name,
+            )?;
+        }
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             receiver_account_id: scope.contract_account_id.clone().into(),
             function_name: scope.function_name.clone(),
-            function_args,
+            function_args_type: scope.function_args_type.clone(),
+            function_args: scope.function_args.clone(),
+            borsh_schema: scope.borsh_schema.clone(),
+            abi: scope.abi.clone(),
         })
     }
 }
 
+impl interactive_clap::FromCli for CallFunctionProperties {
+    type FromCliContext = crate::GlobalContext;
+    type FromCliError = color_eyre::eyre::Error;
+
+    fn from_cli(
+        optional_clap_variant: Option<<Self as interactive_clap::ToCli>::CliVariant>,
+        context: Self::FromCliContext,
+    ) -> interactive_clap::ResultFromCli<
+        <Self as interactive_clap::ToCli>::CliVariant,
+        Self::FromCliError,
+    >
+    where
+        Self: Sized + interactive_clap::ToCli,
+    {
+        let mut clap_variant = optional_clap_variant.unwrap_or_default();
+
+        if clap_variant.contract_account_id.is_none() {
+            clap_variant.contract_account_id = match Self::input_contract_account_id(&context) {
+                Ok(Some(contract_account_id)) => Some(contract_account_id),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let contract_account_id = clap_variant
+            .contract_account_id
+            .clone()
+            .expect("Unexpected error");
+
+        let abi_root = if clap_variant.function_name.is_none()
+            || clap_variant.function_args.is_none()
+        {
+            match super::abi::find_contract_abi(
+                &context.0,
+                None,
+                &contract_account_id.clone().into(),
+            ) {
+                Ok(abi_root) => abi_root,
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            }
+        } else {
+            None
+        };
+        // The prompts below are driven by the ABI, so the arguments are checked against it as well
+        if abi_root.is_some() && clap_variant.abi.is_none() {
+            clap_variant.abi = Some(super::abi::AbiSource::Contract);
+        }
+
+        if clap_variant.function_name.is_none() {
+            clap_variant.function_name =
+                match Self::input_function_name_from_abi(&context, abi_root.as_ref()) {
+                    Ok(Some(function_name)) => Some(function_name),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+        }
+        let function_name = clap_variant
+            .function_name
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args_type.is_none() && clap_variant.function_args.is_none() {
+            if let Some((abi_root, function)) = abi_root.as_ref().and_then(|abi_root| {
+                super::abi::get_function(abi_root, &function_name)
+                    .map(|function| (abi_root, function))
+            }) {
+                match super::abi::input_function_args(abi_root, function) {
+                    Ok(Some(function_args)) => {
+                        clap_variant.function_args_type =
+                            Some(super::call_function_args_type::CallFunctionArgsType::JsonArgs);
+                        clap_variant.function_args = Some(function_args.to_string());
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                }
+            }
+        }
+
+        if clap_variant.function_args_type.is_none() {
+            clap_variant.function_args_type = match Self::input_function_args_type(&context) {
+                Ok(Some(function_args_type)) => Some(function_args_type),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args_type = clap_variant
+            .function_args_type
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.function_args.is_none() {
+            clap_variant.function_args = match Self::input_function_args(&context) {
+                Ok(Some(function_args)) => Some(function_args),
+                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+        }
+        let function_args = clap_variant
+            .function_args
+            .clone()
+            .expect("Unexpected error");
+
+        if clap_variant.borsh_schema.is_none() {
+            if let super::call_function_args_type::CallFunctionArgsType::BorshArgs =
+                function_args_type
+            {
+                clap_variant.borsh_schema = match Self::input_borsh_schema(&context) {
+                    Ok(optional_borsh_schema) => optional_borsh_schema,
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
+            }
+        }
+        let borsh_schema = clap_variant.borsh_schema.clone();
+        let abi = clap_variant.abi.clone();
+
+        let new_context_scope = InteractiveClapContextScopeForCallFunctionProperties {
+            contract_account_id,
+            function_name,
+            function_args_type,
+            function_args,
+            borsh_schema,
+            abi,
+        };
+        let new_context =
+            match CallFunctionPropertiesContext::from_previous_context(context, &new_context_scope)
+            {
+                Ok(new_context) => new_context,
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            };
+
+        match <PrepaidGas as interactive_clap::FromCli>::from_cli(
+            clap_variant.prepaid_gas.take().map(
+                |ClapNamedArgPrepaidGasForCallFunctionProperties::PrepaidGas(cli_arg)| cli_arg,
+            ),
+            new_context,
+        ) {
+            interactive_clap::ResultFromCli::Ok(cli_arg)
+            | interactive_clap::ResultFromCli::Cancel(Some(cli_arg)) => {
+                clap_variant.prepaid_gas = Some(
+                    ClapNamedArgPrepaidGasForCallFunctionProperties::PrepaidGas(cli_arg),
+                );
+                interactive_clap::ResultFromCli::Ok(clap_variant)
+            }
+            interactive_clap::ResultFromCli::Cancel(None) => {
+                interactive_clap::ResultFromCli::Cancel(Some(clap_variant))
+            }
+            interactive_clap::ResultFromCli::Back => interactive_clap::ResultFromCli::Back,
+            interactive_clap::ResultFromCli::Err(optional_cli_arg, err) => {
+                clap_variant.prepaid_gas = optional_cli_arg
+                    .map(ClapNamedArgPrepaidGasForCallFunctionProperties::PrepaidGas);
+                interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+            }
+        }
+    }
+}
+
 impl CallFunctionProperties {
+    fn input_function_name_from_abi(
+        context: &crate::GlobalContext,
+        abi_root: Option<&near_abi::AbiRoot>,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        if let Some(abi_root) = abi_root {
+            if let Some(function_name) = super::abi::input_function_name(abi_root, false)? {
+                return Ok(Some(function_name));
+            }
+        }
+        Self::input_function_name(context)
+    }
+
     fn input_function_args_type(
         _context: &crate::GlobalContext,
-    ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::FunctionArgsType>> {
-        super::call_function_args_type::input_function_args_type()
+    ) -> color_eyre::eyre::Result<Option<super::call_function_args_type::CallFunctionArgsType>>
+    {
+        super::call_function_args_type::input_call_function_args_type()
+    }
+
+    fn input_borsh_schema(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<super::borsh_schema::BorshSchemaSource>> {
+        super::borsh_schema::input_borsh_schema(&context.1)
     }
 }
 
@@ -69,10 +274,13 @@ pub struct PrepaidGas {
 
 #[derive(Debug, Clone)]
 pub struct PrepaidGasContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     function_name: String,
-    function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
+    function_args: String,
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    abi: Option<super::abi::AbiSource>,
     gas: crate::common::NearGas,
 }
 
@@ -82,10 +290,13 @@ impl PrepaidGasContext {
         scope: &<PrepaidGas as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             function_name: previous_context.function_name,
+            function_args_type: previous_context.function_args_type,
             function_args: previous_context.function_args,
+            borsh_schema: previous_context.borsh_schema,
+            abi: previous_context.abi,
             gas: scope.gas.clone(),
         })
     }
@@ -93,13 +304,13 @@ impl PrepaidGasContext {
 
 impl PrepaidGas {
     fn input_gas(
-        _context: &CallFunctionPropertiesContext,
+        context: &CallFunctionPropertiesContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
         eprintln!();
         let gas: u64 = loop {
             match crate::common::NearGas::from_str(
                 &Text::new("Enter gas for function call")
-                    .with_initial_value("100 TeraGas")
+                    .with_initial_value(context.global_context.0.cli.function_call_gas())
                     .prompt()?,
             ) {
                 Ok(input_gas) => {
@@ -132,10 +343,13 @@ pub struct Deposit {
 
 #[derive(Debug, Clone)]
 pub struct DepositContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     function_name: String,
-    function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
+    function_args: String,
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    abi: Option<super::abi::AbiSource>,
     gas: crate::common::NearGas,
     deposit: crate::common::NearBalance,
 }
@@ -146,10 +360,13 @@ impl DepositContext {
         scope: &<Deposit as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             function_name: previous_context.function_name,
+            function_args_type: previous_context.function_args_type,
             function_args: previous_context.function_args,
+            borsh_schema: previous_context.borsh_schema,
+            abi: previous_context.abi,
             gas: previous_context.gas,
             deposit: scope.deposit.clone(),
         })
@@ -158,14 +375,14 @@ impl DepositContext {
 
 impl Deposit {
     fn input_deposit(
-        _context: &PrepaidGasContext,
+        context: &PrepaidGasContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
         eprintln!();
         match crate::common::NearBalance::from_str(
             &Text::new(
                 "Enter deposit for a function call (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
-            .with_initial_value("0 NEAR")
+            .with_initial_value(context.global_context.0.cli.function_call_deposit())
             .prompt()?,
         ) {
             Ok(deposit) => Ok(Some(deposit)),
@@ -178,6 +395,7 @@ impl Deposit {
 #[interactive_clap(input_context = DepositContext)]
 #[interactive_clap(output_context = SignerAccountIdContext)]
 pub struct SignerAccountId {
+    #[interactive_clap(skip_default_input_arg)]
     /// What is the signer account ID?
     signer_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(named_arg)]
@@ -185,12 +403,26 @@ pub struct SignerAccountId {
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
 }
 
+impl SignerAccountId {
+    fn input_signer_account_id(
+        context: &DepositContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(
+            &context.global_context.0,
+            "What is the signer account ID?",
+        )
+    }
+}
+
 #[derive(Clone)]
 pub struct SignerAccountIdContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     function_name: String,
-    function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::CallFunctionArgsType,
+    function_args: String,
+    borsh_schema: Option<super::borsh_schema::BorshSchemaSource>,
+    abi: Option<super::abi::AbiSource>,
     gas: crate::common::NearGas,
     deposit: crate::common::NearBalance,
     signer_account_id: near_primitives::types::AccountId,
@@ -202,10 +434,13 @@ impl SignerAccountIdContext {
         scope: &<SignerAccountId as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             function_name: previous_context.function_name,
+            function_args_type: previous_context.function_args_type,
             function_args: previous_context.function_args,
+            borsh_schema: previous_context.borsh_schema,
+            abi: previous_context.abi,
             gas: previous_context.gas,
             deposit: previous_context.deposit,
             signer_account_id: scope.signer_account_id.clone().into(),
@@ -216,14 +451,43 @@ impl SignerAccountIdContext {
 impl From<SignerAccountIdContext> for crate::commands::ActionContext {
     fn from(item: SignerAccountIdContext) -> Self {
         let on_after_getting_network_callback: crate::commands::OnAfterGettingNetworkCallback =
-            std::sync::Arc::new(move |_network_config| {
+            std::sync::Arc::new(move |network_config| {
+                let abi_root = super::abi::requested_abi(
+                    item.abi.as_ref(),
+                    super::call_function_args_type::borsh_args_use_abi(
+                        &item.function_args_type,
+                        item.borsh_schema.as_ref(),
+                    ),
+                    network_config,
+                    &item.receiver_account_id,
+                    near_primitives::types::Finality::Final.into(),
+                )?;
+                let function_args =
+                    super::call_function_args_type::function_args_with_borsh_schema(
+                        item.function_args.clone(),
+                        item.function_args_type.clone(),
+                        item.borsh_schema.as_ref(),
+                        abi_root.as_ref(),
+                        &item.function_name,
+                    )?;
+                if let Some(abi_root) = &abi_root {
+                    if let super::call_function_args_type::CallFunctionArgsType::JsonArgs =
+                        item.function_args_type
+                    {
+                        super::abi::validate_function_args(
+                            abi_root,
+                            &item.function_name,
+                            &function_args,
+                        )?;
+                    }
+                }
                 Ok(crate::commands::PrepopulatedTransaction {
                     signer_id: item.signer_account_id.clone(),
                     receiver_id: item.receiver_account_id.clone(),
                     actions: vec![near_primitives::transaction::Action::FunctionCall(
                         near_primitives::transaction::FunctionCallAction {
                             method_name: item.function_name.clone(),
-                            args: item.function_args.clone(),
+                            args: function_args,
                             gas: item.gas.inner,
                             deposit: item.deposit.to_yoctonear(),
                         },
@@ -231,7 +495,7 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
                 })
             });
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
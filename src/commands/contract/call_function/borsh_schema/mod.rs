@@ -0,0 +1,853 @@
+use color_eyre::eyre::Context;
+use inquire::{CustomType, Select, Text};
+use near_primitives::borsh::schema::{BorshSchemaContainer, Definition, Fields};
+
+/// Schemas nested deeper than this are considered recursive and are rejected.
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+/// Where to take a Borsh schema from:
+/// - `abi` - from the contract ABI;
+/// - `file:<path>` - from a file with a schema;
+/// - inline schema, e.g. `{"struct": [["receiver_id", "string"], ["amount", "u128"]]}`.
+///
+/// Schema syntax:
+/// - primitive types: `u8`..`u128`, `i8`..`i128`, `f32`, `f64`, `bool`, `string`, `unit`;
+/// - `["T"]` - a dynamically-sized sequence (`Vec<T>`), `["T", N]` - a fixed-size array (`[T; N]`);
+/// - `{"struct": [["field", T], ...]}`, `{"tuple": [T, ...]}`, `{"option": T}`,
+///   `{"enum": [["Variant", T], "UnitVariant", ...]}`.
+#[derive(Debug, Clone)]
+pub enum BorshSchemaSource {
+    Abi,
+    File(std::path::PathBuf),
+    Inline(String),
+}
+
+impl interactive_clap::ToCli for BorshSchemaSource {
+    type CliVariant = BorshSchemaSource;
+}
+
+impl std::str::FromStr for BorshSchemaSource {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "abi" {
+            Ok(Self::Abi)
+        } else if let Some(path) = s.strip_prefix("file:") {
+            Ok(Self::File(shellexpand::tilde(path).as_ref().into()))
+        } else {
+            BorshType::from_schema_str(s).map_err(|err| format!("Borsh schema: {}", err))?;
+            Ok(Self::Inline(s.to_string()))
+        }
+    }
+}
+
+impl std::fmt::Display for BorshSchemaSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Abi => write!(f, "abi"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Inline(schema) => write!(f, "{}", schema),
+        }
+    }
+}
+
+impl BorshSchemaSource {
+    /// Borsh schema of the function arguments.
+    pub fn args_type(
+        &self,
+        abi_root: Option<&near_abi::AbiRoot>,
+        function_name: &str,
+    ) -> color_eyre::eyre::Result<BorshType> {
+        match self {
+            Self::Abi => {
+                let function = abi_function(abi_root, function_name)?;
+                match &function.params {
+                    near_abi::AbiParameters::Borsh { args } => Ok(BorshType::Struct(
+                        args.iter()
+                            .map(|arg| {
+                                Ok((
+                                    arg.name.clone(),
+                                    BorshType::from_container(&arg.type_schema)?,
+                                ))
+                            })
+                            .collect::<color_eyre::eyre::Result<_>>()?,
+                    )),
+                    near_abi::AbiParameters::Json { .. } => color_eyre::eyre::bail!(
+                        "The function <{}> expects JSON arguments according to the contract ABI",
+                        function_name
+                    ),
+                }
+            }
+            _ => self.inline_or_file_type(),
+        }
+    }
+
+    /// Borsh schema of the function return value.
+    pub fn result_type(
+        &self,
+        abi_root: Option<&near_abi::AbiRoot>,
+        function_name: &str,
+    ) -> color_eyre::eyre::Result<BorshType> {
+        match self {
+            Self::Abi => match &abi_function(abi_root, function_name)?.result {
+                Some(near_abi::AbiType::Borsh { type_schema }) => {
+                    BorshType::from_container(type_schema)
+                }
+                Some(near_abi::AbiType::Json { .. }) => color_eyre::eyre::bail!(
+                    "The function <{}> returns a JSON value according to the contract ABI",
+                    function_name
+                ),
+                None => color_eyre::eyre::bail!(
+                    "The function <{}> does not return a value according to the contract ABI",
+                    function_name
+                ),
+            },
+            _ => self.inline_or_file_type(),
+        }
+    }
+
+    fn inline_or_file_type(&self) -> color_eyre::eyre::Result<BorshType> {
+        match self {
+            Self::Abi => unreachable!("ABI schema is resolved separately"),
+            Self::File(path) => {
+                let schema = std::fs::read_to_string(path).wrap_err_with(|| {
+                    format!("Access to Borsh schema file <{:?}> not found!", path)
+                })?;
+                BorshType::from_schema_str(&schema)
+                    .map_err(|err| color_eyre::eyre::eyre!("Borsh schema {:?}: {}", path, err))
+            }
+            Self::Inline(schema) => BorshType::from_schema_str(schema)
+                .map_err(|err| color_eyre::eyre::eyre!("Borsh schema: {}", err)),
+        }
+    }
+}
+
+pub fn input_borsh_schema(
+    global_options: &crate::common::GlobalOptions,
+) -> color_eyre::eyre::Result<Option<BorshSchemaSource>> {
+    global_options.ensure_interactive_mode("--borsh-schema")?;
+    #[derive(strum_macros::Display)]
+    enum SchemaSource {
+        #[strum(to_string = "Use the Borsh schema from the contract ABI")]
+        Abi,
+        #[strum(to_string = "Enter the Borsh schema")]
+        Inline,
+        #[strum(to_string = "Read the Borsh schema from a file")]
+        File,
+    }
+    eprintln!();
+    let select_schema_source = Select::new(
+        "Where to take the Borsh schema of the arguments from?",
+        vec![SchemaSource::Abi, SchemaSource::Inline, SchemaSource::File],
+    )
+    .prompt()?;
+    match select_schema_source {
+        SchemaSource::Abi => Ok(Some(BorshSchemaSource::Abi)),
+        SchemaSource::Inline => loop {
+            let schema =
+                Text::new("Enter the Borsh schema (e.g. {\"struct\": [[\"amount\", \"u128\"]]}):")
+                    .prompt()?;
+            match BorshType::from_schema_str(&schema) {
+                Ok(_) => return Ok(Some(BorshSchemaSource::Inline(schema))),
+                Err(err) => eprintln!("Borsh schema: {}", err),
+            }
+        },
+        SchemaSource::File => {
+            let path: crate::types::path_buf::PathBuf =
+                CustomType::new("What is the path to the Borsh schema file?").prompt()?;
+            Ok(Some(BorshSchemaSource::File(path.into())))
+        }
+    }
+}
+
+fn abi_function<'a>(
+    abi_root: Option<&'a near_abi::AbiRoot>,
+    function_name: &str,
+) -> color_eyre::eyre::Result<&'a near_abi::AbiFunction> {
+    let abi_root = abi_root.ok_or_else(|| {
+        color_eyre::eyre::eyre!("The contract does not provide an ABI, so the Borsh schema has to be specified explicitly")
+    })?;
+    super::abi::get_function(abi_root, function_name).ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The contract ABI does not declare the function <{}>",
+            function_name
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorshType {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    F32,
+    F64,
+    Bool,
+    String,
+    Unit,
+    Sequence(Box<BorshType>),
+    Array(Box<BorshType>, u32),
+    Tuple(Vec<BorshType>),
+    Struct(Vec<(String, BorshType)>),
+    Enum(Vec<(String, BorshType)>),
+    Option(Box<BorshType>),
+}
+
+impl BorshType {
+    pub fn from_schema_str(schema: &str) -> Result<Self, String> {
+        let schema: serde_json::Value = match serde_json::from_str(schema) {
+            Ok(schema) => schema,
+            // Allow primitive types without quotes, e.g. `u64`
+            Err(_) => serde_json::Value::String(schema.trim().to_string()),
+        };
+        Self::from_schema_value(&schema, 0)
+    }
+
+    fn from_schema_value(schema: &serde_json::Value, depth: usize) -> Result<Self, String> {
+        if depth > MAX_SCHEMA_DEPTH {
+            return Err("the schema is nested too deeply".to_string());
+        }
+        match schema {
+            serde_json::Value::String(name) => {
+                Self::from_primitive_name(name).ok_or_else(|| format!("unknown type <{}>", name))
+            }
+            serde_json::Value::Array(items) => match items.as_slice() {
+                [elements] => Ok(Self::Sequence(Box::new(Self::from_schema_value(
+                    elements,
+                    depth + 1,
+                )?))),
+                [elements, serde_json::Value::Number(length)] => {
+                    let length = length
+                        .as_u64()
+                        .and_then(|length| u32::try_from(length).ok())
+                        .ok_or_else(|| format!("invalid array length {}", length))?;
+                    Ok(Self::Array(
+                        Box::new(Self::from_schema_value(elements, depth + 1)?),
+                        length,
+                    ))
+                }
+                _ => Err(format!(
+                    "expected [T] (sequence) or [T, N] (fixed-size array), got {}",
+                    schema
+                )),
+            },
+            serde_json::Value::Object(object) if object.len() == 1 => {
+                let (kind, value) = object.iter().next().expect("Unexpected error");
+                match kind.as_str() {
+                    "struct" => Ok(Self::Struct(Self::named_types(value, depth)?)),
+                    "enum" => Ok(Self::Enum(Self::named_types(value, depth)?)),
+                    "tuple" => Ok(Self::Tuple(
+                        value
+                            .as_array()
+                            .ok_or_else(|| {
+                                format!("expected a list of tuple types, got {}", value)
+                            })?
+                            .iter()
+                            .map(|item| Self::from_schema_value(item, depth + 1))
+                            .collect::<Result<_, _>>()?,
+                    )),
+                    "option" => Ok(Self::Option(Box::new(Self::from_schema_value(
+                        value,
+                        depth + 1,
+                    )?))),
+                    _ => Err(format!(
+                        "unknown schema kind <{}> (expected struct, enum, tuple or option)",
+                        kind
+                    )),
+                }
+            }
+            _ => Err(format!("invalid schema {}", schema)),
+        }
+    }
+
+    /// Parses `[["name", T], ...]`, where unit entries may be specified by a name only.
+    fn named_types(
+        value: &serde_json::Value,
+        depth: usize,
+    ) -> Result<Vec<(String, BorshType)>, String> {
+        value
+            .as_array()
+            .ok_or_else(|| format!("expected a list of [\"name\", type] pairs, got {}", value))?
+            .iter()
+            .map(|item| match item {
+                serde_json::Value::String(name) => Ok((name.clone(), Self::Unit)),
+                serde_json::Value::Array(pair) if pair.len() == 2 && pair[0].is_string() => Ok((
+                    pair[0].as_str().expect("Unexpected error").to_string(),
+                    Self::from_schema_value(&pair[1], depth + 1)?,
+                )),
+                _ => Err(format!("expected a [\"name\", type] pair, got {}", item)),
+            })
+            .collect()
+    }
+
+    fn from_primitive_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "u128" => Self::U128,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "i128" => Self::I128,
+            "f32" => Self::F32,
+            "f64" => Self::F64,
+            "bool" => Self::Bool,
+            "string" | "String" => Self::String,
+            "unit" | "nil" | "()" => Self::Unit,
+            _ => return None,
+        })
+    }
+
+    /// Converts the Borsh schema from the contract ABI.
+    pub fn from_container(container: &BorshSchemaContainer) -> color_eyre::eyre::Result<Self> {
+        Self::from_declaration(&container.declaration, container, 0)
+    }
+
+    fn from_declaration(
+        declaration: &str,
+        container: &BorshSchemaContainer,
+        depth: usize,
+    ) -> color_eyre::eyre::Result<Self> {
+        if depth > MAX_SCHEMA_DEPTH {
+            color_eyre::eyre::bail!(
+                "The Borsh schema of <{}> is recursive, which is not supported",
+                container.declaration
+            );
+        }
+        let definition = match container.definitions.get(declaration) {
+            Some(definition) => definition,
+            None => {
+                return Self::from_primitive_name(declaration).ok_or_else(|| {
+                    color_eyre::eyre::eyre!(
+                        "The Borsh schema does not define the type <{}>",
+                        declaration
+                    )
+                })
+            }
+        };
+        let convert = |declaration: &str| Self::from_declaration(declaration, container, depth + 1);
+        Ok(match definition {
+            Definition::Array { length, elements } => {
+                Self::Array(Box::new(convert(elements)?), *length)
+            }
+            Definition::Sequence { elements } => Self::Sequence(Box::new(convert(elements)?)),
+            Definition::Tuple { elements } => Self::Tuple(
+                elements
+                    .iter()
+                    .map(|element| convert(element))
+                    .collect::<color_eyre::eyre::Result<_>>()?,
+            ),
+            Definition::Enum { variants } => match variants.as_slice() {
+                [(none, unit), (some, value)]
+                    if none == "None" && unit == "nil" && some == "Some" =>
+                {
+                    Self::Option(Box::new(convert(value)?))
+                }
+                _ => Self::Enum(
+                    variants
+                        .iter()
+                        .map(|(name, declaration)| Ok((name.clone(), convert(declaration)?)))
+                        .collect::<color_eyre::eyre::Result<_>>()?,
+                ),
+            },
+            Definition::Struct { fields } => match fields {
+                Fields::NamedFields(fields) => Self::Struct(
+                    fields
+                        .iter()
+                        .map(|(name, declaration)| Ok((name.clone(), convert(declaration)?)))
+                        .collect::<color_eyre::eyre::Result<_>>()?,
+                ),
+                Fields::UnnamedFields(fields) => Self::Tuple(
+                    fields
+                        .iter()
+                        .map(|declaration| convert(declaration))
+                        .collect::<color_eyre::eyre::Result<_>>()?,
+                ),
+                Fields::Empty => Self::Unit,
+            },
+        })
+    }
+
+    /// Serializes a JSON value to Borsh according to the schema.
+    ///
+    /// Integers may be specified as JSON numbers or as strings (which is the only way to pass
+    /// 128-bit integers without losing precision).
+    pub fn serialize(&self, value: &serde_json::Value) -> color_eyre::eyre::Result<Vec<u8>> {
+        let mut buffer = vec![];
+        self.serialize_into(value, "value", &mut buffer)
+            .map_err(|err| color_eyre::eyre::eyre!("Failed to serialize to Borsh: {}", err))?;
+        Ok(buffer)
+    }
+
+    fn serialize_into(
+        &self,
+        value: &serde_json::Value,
+        path: &str,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        macro_rules! integer {
+            ($type:ty) => {{
+                let number = match value {
+                    serde_json::Value::Number(number) => number.to_string().parse::<$type>().ok(),
+                    serde_json::Value::String(number) => number.parse::<$type>().ok(),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    format!("{}: expected {}, got {}", path, stringify!($type), value)
+                })?;
+                buffer.extend_from_slice(&number.to_le_bytes());
+            }};
+        }
+        match self {
+            Self::U8 => integer!(u8),
+            Self::U16 => integer!(u16),
+            Self::U32 => integer!(u32),
+            Self::U64 => integer!(u64),
+            Self::U128 => integer!(u128),
+            Self::I8 => integer!(i8),
+            Self::I16 => integer!(i16),
+            Self::I32 => integer!(i32),
+            Self::I64 => integer!(i64),
+            Self::I128 => integer!(i128),
+            Self::F32 => {
+                let number = value
+                    .as_f64()
+                    .ok_or_else(|| format!("{}: expected f32, got {}", path, value))?;
+                buffer.extend_from_slice(&(number as f32).to_le_bytes());
+            }
+            Self::F64 => {
+                let number = value
+                    .as_f64()
+                    .ok_or_else(|| format!("{}: expected f64, got {}", path, value))?;
+                buffer.extend_from_slice(&number.to_le_bytes());
+            }
+            Self::Bool => {
+                let boolean = value
+                    .as_bool()
+                    .ok_or_else(|| format!("{}: expected bool, got {}", path, value))?;
+                buffer.push(u8::from(boolean));
+            }
+            Self::String => {
+                let string = value
+                    .as_str()
+                    .ok_or_else(|| format!("{}: expected string, got {}", path, value))?;
+                write_length(string.len(), path, buffer)?;
+                buffer.extend_from_slice(string.as_bytes());
+            }
+            Self::Unit => {
+                if !value.is_null() && value != &serde_json::json!([]) {
+                    return Err(format!("{}: expected null, got {}", path, value));
+                }
+            }
+            Self::Sequence(elements) => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| format!("{}: expected an array, got {}", path, value))?;
+                write_length(items.len(), path, buffer)?;
+                for (index, item) in items.iter().enumerate() {
+                    elements.serialize_into(item, &format!("{}[{}]", path, index), buffer)?;
+                }
+            }
+            Self::Array(elements, length) => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| format!("{}: expected an array, got {}", path, value))?;
+                if items.len() != *length as usize {
+                    return Err(format!(
+                        "{}: expected an array of {} items, got {} items",
+                        path,
+                        length,
+                        items.len()
+                    ));
+                }
+                for (index, item) in items.iter().enumerate() {
+                    elements.serialize_into(item, &format!("{}[{}]", path, index), buffer)?;
+                }
+            }
+            Self::Tuple(elements) => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| format!("{}: expected an array, got {}", path, value))?;
+                if items.len() != elements.len() {
+                    return Err(format!(
+                        "{}: expected a tuple of {} items, got {} items",
+                        path,
+                        elements.len(),
+                        items.len()
+                    ));
+                }
+                for (index, (element, item)) in elements.iter().zip(items).enumerate() {
+                    element.serialize_into(item, &format!("{}[{}]", path, index), buffer)?;
+                }
+            }
+            Self::Struct(fields) => {
+                let object = value
+                    .as_object()
+                    .ok_or_else(|| format!("{}: expected an object, got {}", path, value))?;
+                if let Some(name) = object
+                    .keys()
+                    .find(|name| !fields.iter().any(|(field_name, _)| field_name == *name))
+                {
+                    return Err(format!("{}.{}: unexpected field", path, name));
+                }
+                for (name, field) in fields {
+                    let field_path = format!("{}.{}", path, name);
+                    match object.get(name) {
+                        Some(field_value) => {
+                            field.serialize_into(field_value, &field_path, buffer)?
+                        }
+                        None if matches!(field, Self::Option(_) | Self::Unit) => {
+                            field.serialize_into(&serde_json::Value::Null, &field_path, buffer)?
+                        }
+                        None => return Err(format!("{}: the field is missing", field_path)),
+                    }
+                }
+            }
+            Self::Enum(variants) => {
+                let null = serde_json::Value::Null;
+                let (name, variant_value) = match value {
+                    serde_json::Value::String(name) => (name.as_str(), &null),
+                    serde_json::Value::Object(object) if object.len() == 1 => {
+                        let (name, variant_value) = object.iter().next().expect("Unexpected error");
+                        (name.as_str(), variant_value)
+                    }
+                    _ => {
+                        return Err(format!(
+                            "{}: expected \"Variant\" or {{\"Variant\": value}}, got {}",
+                            path, value
+                        ))
+                    }
+                };
+                let index = variants
+                    .iter()
+                    .position(|(variant_name, _)| variant_name == name)
+                    .ok_or_else(|| {
+                        format!(
+                            "{}: unknown variant <{}> (expected one of: {})",
+                            path,
+                            name,
+                            variants
+                                .iter()
+                                .map(|(variant_name, _)| variant_name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })?;
+                buffer.push(
+                    u8::try_from(index).map_err(|_| format!("{}: too many enum variants", path))?,
+                );
+                variants[index].1.serialize_into(
+                    variant_value,
+                    &format!("{}.{}", path, name),
+                    buffer,
+                )?;
+            }
+            Self::Option(inner) => {
+                if value.is_null() {
+                    buffer.push(0);
+                } else {
+                    buffer.push(1);
+                    inner.serialize_into(value, path, buffer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes Borsh-serialized data into a JSON value according to the schema.
+    ///
+    /// 128-bit integers are represented as strings to avoid losing precision.
+    pub fn deserialize(&self, data: &[u8]) -> color_eyre::eyre::Result<serde_json::Value> {
+        let mut reader = data;
+        let value = self
+            .deserialize_from(&mut reader, "value")
+            .map_err(|err| color_eyre::eyre::eyre!("Failed to deserialize from Borsh: {}", err))?;
+        if !reader.is_empty() {
+            color_eyre::eyre::bail!(
+                "Failed to deserialize from Borsh: {} unexpected trailing bytes",
+                reader.len()
+            );
+        }
+        Ok(value)
+    }
+
+    fn deserialize_from(
+        &self,
+        reader: &mut &[u8],
+        path: &str,
+    ) -> Result<serde_json::Value, String> {
+        macro_rules! integer {
+            ($type:ty) => {{
+                let bytes = read_bytes(reader, std::mem::size_of::<$type>(), path)?;
+                <$type>::from_le_bytes(bytes.try_into().expect("Unexpected error"))
+            }};
+        }
+        Ok(match self {
+            Self::U8 => integer!(u8).into(),
+            Self::U16 => integer!(u16).into(),
+            Self::U32 => integer!(u32).into(),
+            Self::U64 => integer!(u64).into(),
+            Self::U128 => integer!(u128).to_string().into(),
+            Self::I8 => integer!(i8).into(),
+            Self::I16 => integer!(i16).into(),
+            Self::I32 => integer!(i32).into(),
+            Self::I64 => integer!(i64).into(),
+            Self::I128 => integer!(i128).to_string().into(),
+            Self::F32 => f64::from(integer!(f32)).into(),
+            Self::F64 => integer!(f64).into(),
+            Self::Bool => match integer!(u8) {
+                0 => false.into(),
+                1 => true.into(),
+                byte => return Err(format!("{}: invalid bool value {}", path, byte)),
+            },
+            Self::String => {
+                let length = integer!(u32) as usize;
+                let bytes = read_bytes(reader, length, path)?;
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|_| format!("{}: invalid UTF-8 string", path))?
+                    .into()
+            }
+            Self::Unit => serde_json::Value::Null,
+            Self::Sequence(elements) => {
+                let length = integer!(u32) as usize;
+                let mut items = Vec::with_capacity(length.min(reader.len()));
+                for index in 0..length {
+                    items.push(elements.deserialize_from(reader, &format!("{}[{}]", path, index))?);
+                }
+                items.into()
+            }
+            Self::Array(elements, length) => (0..*length)
+                .map(|index| elements.deserialize_from(reader, &format!("{}[{}]", path, index)))
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            Self::Tuple(elements) => elements
+                .iter()
+                .enumerate()
+                .map(|(index, element)| {
+                    element.deserialize_from(reader, &format!("{}[{}]", path, index))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            Self::Struct(fields) => {
+                let mut object = serde_json::Map::new();
+                for (name, field) in fields {
+                    object.insert(
+                        name.clone(),
+                        field.deserialize_from(reader, &format!("{}.{}", path, name))?,
+                    );
+                }
+                object.into()
+            }
+            Self::Enum(variants) => {
+                let index = integer!(u8) as usize;
+                let (name, variant) = variants
+                    .get(index)
+                    .ok_or_else(|| format!("{}: invalid enum variant index {}", path, index))?;
+                if let Self::Unit = variant {
+                    name.clone().into()
+                } else {
+                    let mut object = serde_json::Map::new();
+                    object.insert(
+                        name.clone(),
+                        variant.deserialize_from(reader, &format!("{}.{}", path, name))?,
+                    );
+                    object.into()
+                }
+            }
+            Self::Option(inner) => match integer!(u8) {
+                0 => serde_json::Value::Null,
+                1 => inner.deserialize_from(reader, path)?,
+                byte => return Err(format!("{}: invalid option tag {}", path, byte)),
+            },
+        })
+    }
+}
+
+fn write_length(length: usize, path: &str, buffer: &mut Vec<u8>) -> Result<(), String> {
+    let length = u32::try_from(length).map_err(|_| format!("{}: the value is too long", path))?;
+    buffer.extend_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
+fn read_bytes<'a>(reader: &mut &'a [u8], length: usize, path: &str) -> Result<&'a [u8], String> {
+    if reader.len() < length {
+        return Err(format!("{}: unexpected end of data", path));
+    }
+    let (bytes, rest) = reader.split_at(length);
+    *reader = rest;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_primitives::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+    #[test]
+    fn serialize_struct_as_borsh() {
+        let borsh_type = BorshType::from_schema_str(
+            r#"{"struct": [["a", "u8"], ["b", "string"], ["c", ["u32"]], ["d", "u128"], ["e", {"option": "u64"}], ["f", ["u16", 2]], ["g", "bool"]]}"#,
+        )
+        .unwrap();
+        let value = serde_json::json!({
+            "a": 7,
+            "b": "hi",
+            "c": [1, 2],
+            "d": u128::MAX.to_string(),
+            "f": [3, 4],
+            "g": true,
+        });
+        assert_eq!(
+            borsh_type.serialize(&value).unwrap(),
+            (
+                7u8,
+                "hi".to_string(),
+                vec![1u32, 2],
+                u128::MAX,
+                None::<u64>,
+                [3u16, 4],
+                true
+            )
+                .try_to_vec()
+                .unwrap()
+        );
+    }
+    #[test]
+    fn serialize_tuple_readable_by_borsh() {
+        let borsh_type =
+            BorshType::from_schema_str(r#"{"tuple": ["i32", {"option": "u64"}, ["string"]]}"#)
+                .unwrap();
+        let data = borsh_type
+            .serialize(&serde_json::json!([-5, "42", ["x", "y"]]))
+            .unwrap();
+        assert_eq!(
+            <(i32, Option<u64>, Vec<String>)>::try_from_slice(&data).unwrap(),
+            (-5, Some(42), vec!["x".to_string(), "y".to_string()])
+        );
+    }
+    #[test]
+    fn deserialize_borsh_data() {
+        let data = (-5i64, Some(u128::MAX), vec!["x".to_string()], false)
+            .try_to_vec()
+            .unwrap();
+        let borsh_type = BorshType::from_schema_str(
+            r#"{"struct": [["a", "i64"], ["b", {"option": "u128"}], ["c", ["string"]], ["d", "bool"]]}"#,
+        )
+        .unwrap();
+        let value = borsh_type.deserialize(&data).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"a": -5, "b": u128::MAX.to_string(), "c": ["x"], "d": false})
+        );
+        assert_eq!(borsh_type.serialize(&value).unwrap(), data);
+    }
+    #[test]
+    fn enum_round_trip() {
+        let borsh_type = BorshType::from_schema_str(r#"{"enum": [["A", "u8"], "B"]}"#).unwrap();
+        assert_eq!(
+            borsh_type.serialize(&serde_json::json!({"A": 5})).unwrap(),
+            vec![0, 5]
+        );
+        assert_eq!(
+            borsh_type.serialize(&serde_json::json!("B")).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            borsh_type.deserialize(&[0, 5]).unwrap(),
+            serde_json::json!({"A": 5})
+        );
+        assert_eq!(
+            borsh_type.deserialize(&[1]).unwrap(),
+            serde_json::json!("B")
+        );
+        assert!(borsh_type.deserialize(&[2]).is_err());
+        assert!(borsh_type.serialize(&serde_json::json!("C")).is_err());
+    }
+    #[test]
+    fn deserialize_rejects_malformed_data() {
+        let borsh_type = BorshType::from_schema_str("u32").unwrap();
+        assert!(borsh_type.deserialize(&[1, 0, 0]).is_err());
+        assert!(borsh_type.deserialize(&[1, 0, 0, 0, 0]).is_err());
+        assert!(BorshType::Bool.deserialize(&[2]).is_err());
+        assert!(BorshType::String.deserialize(&[5, 0, 0, 0, b'a']).is_err());
+    }
+    #[test]
+    fn serialize_rejects_mismatched_values() {
+        let borsh_type = BorshType::from_schema_str(r#"{"struct": [["amount", "u8"]]}"#).unwrap();
+        assert!(borsh_type
+            .serialize(&serde_json::json!({"amount": 256}))
+            .is_err());
+        assert!(borsh_type.serialize(&serde_json::json!({})).is_err());
+        assert!(borsh_type
+            .serialize(&serde_json::json!({"amount": 1, "extra": 2}))
+            .is_err());
+        assert!(BorshType::Array(Box::new(BorshType::U8), 2)
+            .serialize(&serde_json::json!([1]))
+            .is_err());
+    }
+    #[test]
+    fn from_container_of_borsh_schema() {
+        assert_eq!(
+            BorshType::from_container(
+                &<(u8, Vec<String>, Option<u64>, [u16; 2])>::schema_container()
+            )
+            .unwrap(),
+            BorshType::Tuple(vec![
+                BorshType::U8,
+                BorshType::Sequence(Box::new(BorshType::String)),
+                BorshType::Option(Box::new(BorshType::U64)),
+                BorshType::Array(Box::new(BorshType::U16), 2),
+            ])
+        );
+        assert_eq!(
+            BorshType::from_container(&<u128>::schema_container()).unwrap(),
+            BorshType::U128
+        );
+    }
+    #[test]
+    fn from_schema_str_primitives() {
+        assert_eq!(BorshType::from_schema_str("u64"), Ok(BorshType::U64));
+        assert_eq!(
+            BorshType::from_schema_str("\"string\""),
+            Ok(BorshType::String)
+        );
+        assert_eq!(
+            BorshType::from_schema_str(r#"["u8", 32]"#),
+            Ok(BorshType::Array(Box::new(BorshType::U8), 32))
+        );
+        assert_eq!(
+            BorshType::from_schema_str(
+                r#"{"struct": [["receiver_id", "string"], ["memo", {"option": "string"}]]}"#
+            ),
+            Ok(BorshType::Struct(vec![
+                ("receiver_id".to_string(), BorshType::String),
+                (
+                    "memo".to_string(),
+                    BorshType::Option(Box::new(BorshType::String))
+                ),
+            ]))
+        );
+    }
+    #[test]
+    fn from_schema_str_errors() {
+        assert!(BorshType::from_schema_str("u256").is_err());
+        assert!(BorshType::from_schema_str(r#"["u8", -1]"#).is_err());
+        assert!(BorshType::from_schema_str(r#"{"struct": 5}"#).is_err());
+        assert!(BorshType::from_schema_str(r#"{"union": []}"#).is_err());
+        assert!(BorshType::from_schema_str(&format!(
+            "{}\"u8\"{}",
+            "[".repeat(MAX_SCHEMA_DEPTH + 2),
+            "]".repeat(MAX_SCHEMA_DEPTH + 2)
+        ))
+        .is_err());
+    }
+}
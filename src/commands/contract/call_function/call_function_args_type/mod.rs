@@ -98,3 +98,134 @@ pub fn function_args(
         }
     }
 }
+
+#[derive(Debug, EnumDiscriminants, Clone, clap::ValueEnum)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// How do you want to pass the function call arguments?
+///
+/// Same as [FunctionArgsType], plus `borsh-args`, which needs a Borsh schema and so is only
+/// offered by the "contract call-function" commands.
+pub enum CallFunctionArgsType {
+    #[strum_discriminants(strum(
+        message = "json-args    - Valid JSON arguments (e.g. {\"token_id\": \"42\"})"
+    ))]
+    /// Valid JSON arguments (e.g. {"token_id": "42"})
+    JsonArgs,
+    #[strum_discriminants(strum(message = "text-args    - Arbitrary text arguments"))]
+    /// Arbitrary text arguments
+    TextArgs,
+    #[strum_discriminants(strum(message = "base64-args  - Base64-encoded string (e.g. e30=)"))]
+    /// Base64-encoded string (e.g. e30=)
+    Base64Args,
+    #[strum_discriminants(strum(
+        message = "file-args    - Read from file (e.g. reusable JSON or binary data)"
+    ))]
+    /// Read from file (e.g. reusable JSON or binary data)
+    FileArgs,
+    #[strum_discriminants(strum(
+        message = "borsh-args   - JSON value serialized to Borsh using a schema (e.g. {\"amount\": \"42\"})"
+    ))]
+    /// JSON value serialized to Borsh using a schema (e.g. {"amount": "42"})
+    BorshArgs,
+}
+
+impl interactive_clap::ToCli for CallFunctionArgsType {
+    type CliVariant = CallFunctionArgsType;
+}
+
+impl std::str::FromStr for CallFunctionArgsType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "borsh-args" => Ok(Self::BorshArgs),
+            _ => FunctionArgsType::from_str(s).map(Self::from),
+        }
+    }
+}
+
+impl std::fmt::Display for CallFunctionArgsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::JsonArgs => write!(f, "json-args"),
+            Self::TextArgs => write!(f, "text-args"),
+            Self::Base64Args => write!(f, "base64-args"),
+            Self::FileArgs => write!(f, "file-args"),
+            Self::BorshArgs => write!(f, "borsh-args"),
+        }
+    }
+}
+
+impl std::fmt::Display for CallFunctionArgsTypeDiscriminants {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::JsonArgs => write!(f, "json-args"),
+            Self::TextArgs => write!(f, "text-args"),
+            Self::Base64Args => write!(f, "base64-args"),
+            Self::FileArgs => write!(f, "file-args"),
+            Self::BorshArgs => write!(f, "borsh-args"),
+        }
+    }
+}
+
+impl From<FunctionArgsType> for CallFunctionArgsType {
+    fn from(function_args_type: FunctionArgsType) -> Self {
+        match function_args_type {
+            FunctionArgsType::JsonArgs => Self::JsonArgs,
+            FunctionArgsType::TextArgs => Self::TextArgs,
+            FunctionArgsType::Base64Args => Self::Base64Args,
+            FunctionArgsType::FileArgs => Self::FileArgs,
+        }
+    }
+}
+
+pub fn input_call_function_args_type() -> color_eyre::eyre::Result<Option<CallFunctionArgsType>> {
+    let variants = CallFunctionArgsTypeDiscriminants::iter().collect::<Vec<_>>();
+    let selected = Select::new(" How would you like to proceed", variants).prompt()?;
+    match selected {
+        CallFunctionArgsTypeDiscriminants::JsonArgs => Ok(Some(CallFunctionArgsType::JsonArgs)),
+        CallFunctionArgsTypeDiscriminants::TextArgs => Ok(Some(CallFunctionArgsType::TextArgs)),
+        CallFunctionArgsTypeDiscriminants::Base64Args => Ok(Some(CallFunctionArgsType::Base64Args)),
+        CallFunctionArgsTypeDiscriminants::FileArgs => Ok(Some(CallFunctionArgsType::FileArgs)),
+        CallFunctionArgsTypeDiscriminants::BorshArgs => Ok(Some(CallFunctionArgsType::BorshArgs)),
+    }
+}
+
+/// Whether the `borsh-args` are serialized with the Borsh schema from the contract ABI.
+pub fn borsh_args_use_abi(
+    function_args_type: &CallFunctionArgsType,
+    borsh_schema: Option<&super::borsh_schema::BorshSchemaSource>,
+) -> bool {
+    matches!(
+        (function_args_type, borsh_schema),
+        (
+            CallFunctionArgsType::BorshArgs,
+            None | Some(super::borsh_schema::BorshSchemaSource::Abi)
+        )
+    )
+}
+
+/// Same as [function_args], but also serializes `borsh-args` using the Borsh schema (the contract
+/// ABI is used when the schema is not specified).
+pub fn function_args_with_borsh_schema(
+    args: String,
+    function_args_type: CallFunctionArgsType,
+    borsh_schema: Option<&super::borsh_schema::BorshSchemaSource>,
+    abi_root: Option<&near_abi::AbiRoot>,
+    function_name: &str,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let function_args_type = match function_args_type {
+        CallFunctionArgsType::JsonArgs => FunctionArgsType::JsonArgs,
+        CallFunctionArgsType::TextArgs => FunctionArgsType::TextArgs,
+        CallFunctionArgsType::Base64Args => FunctionArgsType::Base64Args,
+        CallFunctionArgsType::FileArgs => FunctionArgsType::FileArgs,
+        CallFunctionArgsType::BorshArgs => {
+            let borsh_type = borsh_schema
+                .unwrap_or(&super::borsh_schema::BorshSchemaSource::Abi)
+                .args_type(abi_root, function_name)?;
+            let data_json =
+                serde_json::Value::from_str(&args).wrap_err("Data not in JSON format!")?;
+            return borsh_type.serialize(&data_json);
+        }
+    };
+    function_args(args, function_args_type)
+}
@@ -1,7 +1,9 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+mod abi;
 mod as_read_only;
 mod as_transaction;
+mod borsh_schema;
 pub mod call_function_args_type;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -22,7 +22,7 @@ pub struct CallFunctionAction {
 
 #[derive(Debug, Clone)]
 pub struct CallFunctionActionContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     code: Vec<u8>,
@@ -41,7 +41,7 @@ impl CallFunctionActionContext {
                 scope.function_args_type.clone(),
             )?;
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code: previous_context.code,
@@ -75,7 +75,7 @@ pub struct PrepaidGas {
 
 #[derive(Debug, Clone)]
 pub struct PrepaidGasContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     code: Vec<u8>,
@@ -90,7 +90,7 @@ impl PrepaidGasContext {
         scope: &<PrepaidGas as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code: previous_context.code,
@@ -103,13 +103,13 @@ impl PrepaidGasContext {
 
 impl PrepaidGas {
     fn input_gas(
-        _context: &CallFunctionActionContext,
+        context: &CallFunctionActionContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
         eprintln!();
         let gas: u64 = loop {
             match crate::common::NearGas::from_str(
                 &Text::new("Enter gas for function call")
-                    .with_initial_value("100 TeraGas")
+                    .with_initial_value(context.global_context.0.cli.function_call_gas())
                     .prompt()?,
             ) {
                 Ok(input_gas) => {
@@ -173,7 +173,7 @@ impl DepositContext {
                 })
             });
         Ok(Self(crate::commands::ActionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -196,14 +196,14 @@ impl From<DepositContext> for crate::commands::ActionContext {
 
 impl Deposit {
     fn input_deposit(
-        _context: &PrepaidGasContext,
+        context: &PrepaidGasContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
         eprintln!();
         match crate::common::NearBalance::from_str(
             &Text::new(
                 "Enter deposit for a function call (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
-            .with_initial_value("0 NEAR")
+            .with_initial_value(context.global_context.0.cli.function_call_deposit())
             .prompt()?,
         ) {
             Ok(deposit) => Ok(Some(deposit)),
@@ -6,6 +6,7 @@ mod initialize_mode;
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = ContractContext)]
 pub struct Contract {
+    #[interactive_clap(skip_default_input_arg)]
     /// What is the contract account ID?
     account_id: crate::types::account_id::AccountId,
     #[interactive_clap(named_arg)]
@@ -13,9 +14,17 @@ pub struct Contract {
     use_file: ContractFile,
 }
 
+impl Contract {
+    fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(&context.0, "What is the contract account ID?")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ContractContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
 }
@@ -26,7 +35,7 @@ impl ContractContext {
         scope: &<Contract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             receiver_account_id: scope.account_id.clone().into(),
             signer_account_id: scope.account_id.clone().into(),
         })
@@ -45,7 +54,7 @@ pub struct ContractFile {
 
 #[derive(Debug, Clone)]
 pub struct ContractFileContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     code: Vec<u8>,
@@ -60,7 +69,7 @@ impl ContractFileContext {
             format!("Failed to open or read the file: {:?}.", &scope.file_path.0,)
         })?;
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             code,
@@ -18,7 +18,7 @@ pub struct ContractAccount {
 
 #[derive(Debug, Clone)]
 pub struct ContractAccountContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     account_id: near_primitives::types::AccountId,
 }
 
@@ -28,7 +28,7 @@ impl ContractAccountContext {
         scope: &<ContractAccount as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             account_id: scope.account_id.clone().into(),
         })
     }
@@ -60,7 +60,7 @@ impl DownloadContractContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
                 let query_view_method_response = network_config
-                    .json_rpc_client()
+                    .json_rpc_client()?
                     .blocking_call(near_jsonrpc_client::methods::query::RpcQueryRequest {
                         block_reference: block_reference.clone(),
                         request: near_primitives::views::QueryRequest::ViewCode {
@@ -91,7 +91,7 @@ impl DownloadContractContext {
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_block_reference_callback,
         }))
     }
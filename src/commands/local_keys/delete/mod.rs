@@ -0,0 +1,75 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = DeleteLocalKeyContext)]
+pub struct DeleteLocalKey {
+    /// What is the account ID of the access key?
+    account_id: crate::types::account_id::AccountId,
+    /// What is the public key of the access key?
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(long)]
+    /// Confirm the deletion without a prompt
+    yes: bool,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct DeleteLocalKeyContext(crate::network::NetworkContext);
+
+impl DeleteLocalKeyContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<DeleteLocalKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let scripting_mode = previous_context.1.scripting_mode;
+        let yes = scope.yes;
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let local_keys = super::storage::find_local_keys(
+                    &credentials_home_dir,
+                    network_config,
+                    &account_id,
+                    &public_key,
+                )?;
+                eprintln!("The access key is stored in:");
+                for local_key in &local_keys {
+                    for location in &local_key.locations {
+                        eprintln!("  {}: {}", local_key.storage, location);
+                    }
+                }
+                // The key stays on-chain, so the account may become inaccessible from near CLI
+                if !crate::common::confirm(
+                    scripting_mode,
+                    yes,
+                    &format!(
+                    "Do you want to delete the access key {} of <{}> from all the local storages? It is not removed from the account",
+                    public_key, account_id
+                ))? {
+                    return Ok(());
+                }
+                for local_key in &local_keys {
+                    super::storage::delete_key_pair(local_key)?;
+                }
+                eprintln!(
+                    "The access key {} of <{}> was deleted from the local storages",
+                    public_key, account_id
+                );
+                Ok(())
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<DeleteLocalKeyContext> for crate::network::NetworkContext {
+    fn from(item: DeleteLocalKeyContext) -> Self {
+        item.0
+    }
+}
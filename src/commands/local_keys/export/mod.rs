@@ -0,0 +1,65 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ExportLocalKeyContext)]
+pub struct ExportLocalKey {
+    /// What is the account ID of the access key?
+    account_id: crate::types::account_id::AccountId,
+    /// What is the public key of the access key?
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(long)]
+    /// Confirm the export without a prompt
+    yes: bool,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct ExportLocalKeyContext(crate::network::NetworkContext);
+
+impl ExportLocalKeyContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ExportLocalKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let scripting_mode = previous_context.1.scripting_mode;
+        let yes = scope.yes;
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let local_keys = super::storage::find_local_keys(
+                    &credentials_home_dir,
+                    network_config,
+                    &account_id,
+                    &public_key,
+                )?;
+                if !crate::common::confirm(
+                    scripting_mode,
+                    yes,
+                    &format!(
+                        "Do you want to print the private key {} of <{}> to the terminal?",
+                        public_key, account_id
+                    ),
+                )? {
+                    return Ok(());
+                }
+                let key_pair: serde_json::Value =
+                    serde_json::from_str(&super::storage::read_key_pair(&local_keys[0])?)?;
+                println!("{}", serde_json::to_string_pretty(&key_pair)?);
+                Ok(())
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<ExportLocalKeyContext> for crate::network::NetworkContext {
+    fn from(item: ExportLocalKeyContext) -> Self {
+        item.0
+    }
+}
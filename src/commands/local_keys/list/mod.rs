@@ -0,0 +1,177 @@
+use color_eyre::eyre::WrapErr;
+
+use crate::common::{JsonRpcClientExt, RpcQueryResponseExt};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListLocalKeysContext)]
+pub struct ListLocalKeys {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// List only the access keys of this account
+    account_id: Option<crate::types::account_id::AccountId>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct ListLocalKeysContext(crate::network::NetworkContext);
+
+impl ListLocalKeysContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ListLocalKeys as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let output_format = previous_context.1.output_format.clone();
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let local_keys =
+                    super::storage::list_local_keys(&credentials_home_dir, network_config)?
+                        .into_iter()
+                        .filter(|local_key| {
+                            account_id
+                                .as_ref()
+                                .map_or(true, |account_id| &local_key.account_id == account_id)
+                        })
+                        .collect::<Vec<_>>();
+
+                let mut access_key_lists = std::collections::HashMap::new();
+                for local_key in &local_keys {
+                    if access_key_lists.contains_key(&local_key.account_id) {
+                        continue;
+                    }
+                    let access_key_list = match network_config
+                        .json_rpc_client()?
+                        .blocking_call_view_access_key_list(
+                            &local_key.account_id,
+                            near_primitives::types::Finality::Final.into(),
+                        ) {
+                        Ok(rpc_query_response) => Some(rpc_query_response.access_key_list_view()?),
+                        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount {
+                                    ..
+                                },
+                            ),
+                        )) => None,
+                        Err(err) => {
+                            return Err(err).wrap_err_with(|| {
+                                format!(
+                                    "Failed to fetch query AccessKeyList for {}",
+                                    &local_key.account_id
+                                )
+                            })
+                        }
+                    };
+                    access_key_lists.insert(local_key.account_id.clone(), access_key_list);
+                }
+                let on_chain_permission = |local_key: &super::storage::LocalKey| {
+                    access_key_lists
+                        .get(&local_key.account_id)
+                        .and_then(Option::as_ref)
+                        .and_then(|access_key_list| {
+                            access_key_list
+                                .keys
+                                .iter()
+                                .find(|access_key| access_key.public_key == local_key.public_key)
+                        })
+                        .map(|access_key| access_key.access_key.permission.clone())
+                };
+
+                if let crate::common::OutputFormat::Json = output_format {
+                    return crate::common::print_json_output(&serde_json::json!({
+                        "network_name": network_config.network_name,
+                        "keys": local_keys
+                            .iter()
+                            .map(|local_key| serde_json::json!({
+                                "account_id": local_key.account_id,
+                                "public_key": local_key.public_key,
+                                "storage": local_key.storage.to_string(),
+                                "locations": local_key.locations,
+                                "account_exists": matches!(access_key_lists.get(&local_key.account_id), Some(Some(_))),
+                                "on_chain_permission": on_chain_permission(local_key),
+                            }))
+                            .collect::<Vec<_>>(),
+                    }));
+                }
+
+                if local_keys.is_empty() {
+                    eprintln!(
+                        "\nThere are no access keys stored locally for the <{}> network.",
+                        network_config.network_name
+                    );
+                    return Ok(());
+                }
+                let mut table = prettytable::Table::new();
+                table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+                table.set_titles(prettytable::row![
+                    "Account ID",
+                    "Public key",
+                    "Storage",
+                    "On-chain permission"
+                ]);
+                for local_key in &local_keys {
+                    let status = match (
+                        access_key_lists.get(&local_key.account_id),
+                        on_chain_permission(local_key),
+                    ) {
+                        (Some(None), _) => "account does not exist".to_string(),
+                        (_, None) => "not registered".to_string(),
+                        (_, Some(near_primitives::views::AccessKeyPermissionView::FullAccess)) => {
+                            "full access".to_string()
+                        }
+                        (
+                            _,
+                            Some(near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                                receiver_id,
+                                method_names,
+                                ..
+                            }),
+                        ) => {
+                            if method_names.is_empty() {
+                                format!("function call to {} (any method)", receiver_id)
+                            } else {
+                                format!(
+                                    "function call to {} ({})",
+                                    receiver_id,
+                                    method_names.join(", ")
+                                )
+                            }
+                        }
+                    };
+                    table.add_row(prettytable::row![
+                        Fg->local_key.account_id,
+                        local_key.public_key,
+                        local_key.storage,
+                        status
+                    ]);
+                }
+                eprintln!();
+                table.printstd();
+                Ok(())
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl ListLocalKeys {
+    fn input_account_id(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        Ok(None)
+    }
+}
+
+impl From<ListLocalKeysContext> for crate::network::NetworkContext {
+    fn from(item: ListLocalKeysContext) -> Self {
+        item.0
+    }
+}
@@ -0,0 +1,38 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod delete;
+mod export;
+mod list;
+mod move_key;
+pub mod storage;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct LocalKeysCommands {
+    #[interactive_clap(subcommand)]
+    local_keys_actions: LocalKeysActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[non_exhaustive]
+/// What do you want to do with the access keys stored locally?
+pub enum LocalKeysActions {
+    #[strum_discriminants(strum(
+        message = "list   - List the access keys stored for a network and their on-chain status"
+    ))]
+    /// List the access keys stored for a network and their on-chain status
+    List(self::list::ListLocalKeys),
+    #[strum_discriminants(strum(message = "export - Print the key pair of a stored access key"))]
+    /// Print the key pair of a stored access key
+    Export(self::export::ExportLocalKey),
+    #[strum_discriminants(strum(message = "delete - Delete a stored access key"))]
+    /// Delete a stored access key
+    Delete(self::delete::DeleteLocalKey),
+    #[strum_discriminants(strum(
+        message = "move   - Move a stored access key to another keychain"
+    ))]
+    /// Move a stored access key to another keychain
+    Move(self::move_key::MoveLocalKey),
+}
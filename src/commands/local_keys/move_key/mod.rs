@@ -0,0 +1,127 @@
+use inquire::Select;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = MoveLocalKeyContext)]
+pub struct MoveLocalKey {
+    /// What is the account ID of the access key?
+    account_id: crate::types::account_id::AccountId,
+    /// What is the public key of the access key?
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(value_enum)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// To which keychain do you want to move the access key?
+    to_storage: super::storage::KeyStorage,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct MoveLocalKeyContext(crate::network::NetworkContext);
+
+impl MoveLocalKeyContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<MoveLocalKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let credentials_home_dir = previous_context.0.credentials_home_dir.clone();
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+        let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+        let to_storage = scope.to_storage;
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new(move |network_config| {
+                let local_keys = super::storage::find_local_keys(
+                    &credentials_home_dir,
+                    network_config,
+                    &account_id,
+                    &public_key,
+                )?;
+                let Some(source_key) = local_keys
+                    .iter()
+                    .find(|local_key| local_key.storage != to_storage)
+                else {
+                    eprintln!(
+                        "The access key {} of <{}> is already stored in the {} only",
+                        public_key, account_id, to_storage
+                    );
+                    return Ok(());
+                };
+                let key_pair = super::storage::read_key_pair(source_key)?;
+                // The key files of Ledger keys hold the HD path only
+                if serde_json::from_str::<crate::transaction_signature_options::AccountKeyPair>(
+                    &key_pair,
+                )
+                .is_err()
+                {
+                    return Err(crate::common::CliError::new(
+                        crate::common::ErrorCategory::LocalKeyStorage,
+                        format!(
+                            "The {} holds no private key of the access key {} of <{}> (e.g. it is a Ledger key), so it can't be moved",
+                            source_key.storage, public_key, account_id
+                        ),
+                    )
+                    .into());
+                }
+                // The key is only deleted from the source after it is saved to the target
+                if !local_keys
+                    .iter()
+                    .any(|local_key| local_key.storage == to_storage)
+                {
+                    eprintln!(
+                        "{}",
+                        super::storage::save_key_pair(
+                            to_storage,
+                            &credentials_home_dir,
+                            network_config,
+                            &account_id,
+                            &public_key,
+                            &key_pair,
+                        )?
+                    );
+                }
+                for local_key in local_keys
+                    .iter()
+                    .filter(|local_key| local_key.storage != to_storage)
+                {
+                    super::storage::delete_key_pair(local_key)?;
+                }
+                eprintln!(
+                    "The access key {} of <{}> was moved from the {} to the {}",
+                    public_key, account_id, source_key.storage, to_storage
+                );
+                Ok(())
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            global_context: previous_context,
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<MoveLocalKeyContext> for crate::network::NetworkContext {
+    fn from(item: MoveLocalKeyContext) -> Self {
+        item.0
+    }
+}
+
+impl MoveLocalKey {
+    fn input_to_storage(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<super::storage::KeyStorage>> {
+        #[allow(unused_mut)]
+        let mut variants = vec![
+            super::storage::KeyStorage::LegacyKeychain,
+            super::storage::KeyStorage::EncryptedKeychain,
+        ];
+        #[cfg(target_os = "macos")]
+        variants.push(super::storage::KeyStorage::MacosKeychain);
+        let selected = Select::new(
+            "To which keychain do you want to move the access key?",
+            variants,
+        )
+        .prompt()?;
+        Ok(Some(selected))
+    }
+}
@@ -0,0 +1,645 @@
+use color_eyre::eyre::WrapErr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+/// The storages of the access keys of near CLI
+pub enum KeyStorage {
+    /// Files in `credentials_home_dir` (compatible with the old near CLI)
+    LegacyKeychain,
+    /// Files in `credentials_home_dir` encrypted with a password
+    EncryptedKeychain,
+    /// macOS Keychain
+    MacosKeychain,
+}
+
+impl interactive_clap::ToCli for KeyStorage {
+    type CliVariant = KeyStorage;
+}
+
+impl std::str::FromStr for KeyStorage {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "legacy-keychain" => Ok(Self::LegacyKeychain),
+            "encrypted-keychain" => Ok(Self::EncryptedKeychain),
+            "macos-keychain" => Ok(Self::MacosKeychain),
+            _ => Err("KeyStorage: incorrect value entered".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for KeyStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LegacyKeychain => write!(f, "legacy-keychain"),
+            Self::EncryptedKeychain => write!(f, "encrypted-keychain"),
+            Self::MacosKeychain => write!(f, "macos-keychain"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalKey {
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub storage: KeyStorage,
+    /// The files of the legacy or encrypted keychain, or the service name of the macOS keychain item
+    pub locations: Vec<String>,
+}
+
+/// Lists the access keys which are stored for the network in all the available storages.
+pub fn list_local_keys(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<Vec<LocalKey>> {
+    let mut local_keys =
+        list_legacy_keychain_keys(credentials_home_dir, &network_config.network_name);
+    local_keys.extend(list_encrypted_keychain_keys(
+        credentials_home_dir,
+        &network_config.network_name,
+    ));
+    #[cfg(target_os = "macos")]
+    local_keys.extend(list_macos_keychain_keys(&network_config.network_name)?);
+    Ok(local_keys)
+}
+
+/// Finds the access key in the storages, so it fails when the key is not stored locally.
+pub fn find_local_keys(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<Vec<LocalKey>> {
+    let local_keys = list_local_keys(credentials_home_dir, network_config)?
+        .into_iter()
+        .filter(|local_key| {
+            &local_key.account_id == account_id && &local_key.public_key == public_key
+        })
+        .collect::<Vec<_>>();
+    if local_keys.is_empty() {
+        return Err(crate::common::CliError::new(
+            crate::common::ErrorCategory::LocalKeyStorage,
+            format!(
+                "The access key {} of <{}> is not stored locally for the <{}> network",
+                public_key, account_id, network_config.network_name
+            ),
+        )
+        .into());
+    }
+    Ok(local_keys)
+}
+
+/// Descriptions of where the private keys of an account are available on this machine, by public key
+pub type LocalKeyAvailability = std::collections::HashMap<near_crypto::PublicKey, Vec<String>>;
+
+/// Finds the keys of the account in the keychains, and the key files in `credentials_home_dir/implicit`.
+/// A key file without the private key but with the HD path (as saved for a Ledger device) means
+/// that the key can be used with the Ledger device.
+pub fn local_key_availability(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<LocalKeyAvailability> {
+    let mut availability = LocalKeyAvailability::new();
+    for local_key in list_local_keys(credentials_home_dir, network_config)? {
+        if &local_key.account_id != account_id {
+            continue;
+        }
+        let description = match local_key.storage {
+            KeyStorage::MacosKeychain => Some("macOS keychain".to_string()),
+            KeyStorage::EncryptedKeychain => Some("encrypted keychain".to_string()),
+            KeyStorage::LegacyKeychain => describe_key_file(
+                std::path::Path::new(&local_key.locations[0]),
+                "legacy keychain",
+            ),
+        };
+        if let Some(description) = description {
+            availability
+                .entry(local_key.public_key)
+                .or_default()
+                .push(description);
+        }
+    }
+    if let Ok(entries) = credentials_home_dir.join("implicit").read_dir() {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let (Some(public_key), Some(description)) = (
+                read_public_key(&path),
+                describe_key_file(&path, "implicit account file"),
+            ) else {
+                continue;
+            };
+            availability
+                .entry(public_key)
+                .or_default()
+                .push(description);
+        }
+    }
+    Ok(availability)
+}
+
+fn describe_key_file(path: &std::path::Path, private_key_description: &str) -> Option<String> {
+    let key_pair: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+    if key_pair.get("private_key").is_some() {
+        return Some(private_key_description.to_string());
+    }
+    key_pair
+        .get("seed_phrase_hd_path")?
+        .as_str()
+        .map(|seed_phrase_hd_path| format!("ledger HD path known ({})", seed_phrase_hd_path))
+}
+
+/// Both `<network>/<account_id>.json` and `<network>/<account_id>/<key_type>_<public_key>.json`
+/// files may hold the same key, so the files are grouped by the key.
+fn list_legacy_keychain_keys(
+    credentials_home_dir: &std::path::Path,
+    network_name: &str,
+) -> Vec<LocalKey> {
+    let mut key_files = vec![];
+    if let Ok(entries) = credentials_home_dir.join(network_name).read_dir() {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                let Some(account_id) = path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                if let Ok(key_entries) = path.read_dir() {
+                    key_files.extend(
+                        key_entries
+                            .flatten()
+                            .map(|entry| (account_id.to_string(), entry.path())),
+                    );
+                }
+            } else if let Some(account_id) = path.file_stem().and_then(|name| name.to_str()) {
+                key_files.push((account_id.to_string(), path.clone()));
+            }
+        }
+    }
+
+    let mut local_keys: Vec<LocalKey> = vec![];
+    for (account_id, path) in key_files {
+        if path
+            .extension()
+            .map_or(true, |extension| extension != "json")
+        {
+            continue;
+        }
+        let (Ok(account_id), Some(public_key)) = (
+            account_id.parse::<near_primitives::types::AccountId>(),
+            read_public_key(&path),
+        ) else {
+            continue;
+        };
+        let location = path.display().to_string();
+        match local_keys.iter_mut().find(|local_key| {
+            local_key.account_id == account_id && local_key.public_key == public_key
+        }) {
+            Some(local_key) => local_key.locations.push(location),
+            None => local_keys.push(LocalKey {
+                account_id,
+                public_key,
+                storage: KeyStorage::LegacyKeychain,
+                locations: vec![location],
+            }),
+        }
+    }
+    local_keys
+}
+
+/// The encrypted key files are saved next to the files of the legacy keychain as
+/// `<network>/<account_id>/<key_type>_<public_key>.encrypted`; the account and the public key are
+/// stored in plain text, so the keys are listed without the password.
+fn list_encrypted_keychain_keys(
+    credentials_home_dir: &std::path::Path,
+    network_name: &str,
+) -> Vec<LocalKey> {
+    let mut local_keys = vec![];
+    let Ok(entries) = credentials_home_dir.join(network_name).read_dir() else {
+        return local_keys;
+    };
+    for account_dir in entries.flatten().map(|entry| entry.path()) {
+        let Ok(key_entries) = account_dir.read_dir() else {
+            continue;
+        };
+        for path in key_entries.flatten().map(|entry| entry.path()) {
+            let Some(encrypted_key_file) = read_encrypted_key_file(&path) else {
+                continue;
+            };
+            local_keys.push(LocalKey {
+                account_id: encrypted_key_file.account_id,
+                public_key: encrypted_key_file.public_key,
+                storage: KeyStorage::EncryptedKeychain,
+                locations: vec![path.display().to_string()],
+            });
+        }
+    }
+    local_keys
+}
+
+const ENCRYPTED_KEY_FILE_EXTENSION: &str = "encrypted";
+
+/// Whether the key file of the keychain holds a key pair of the encrypted keychain.
+pub fn is_encrypted_key_file(path: &std::path::Path) -> bool {
+    path.extension()
+        .map_or(false, |extension| extension == ENCRYPTED_KEY_FILE_EXTENSION)
+}
+
+fn read_encrypted_key_file(path: &std::path::Path) -> Option<EncryptedKeyFile> {
+    if !is_encrypted_key_file(path) {
+        return None;
+    }
+    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+/// A key pair (in the JSON format of the legacy keychain) encrypted with XChaCha20-Poly1305 by a
+/// key which is derived from the password with scrypt.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct EncryptedKeyFile {
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    kdf: ScryptParams,
+    /// Base64
+    nonce: String,
+    /// Base64
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    /// Base64
+    salt: String,
+}
+
+impl EncryptedKeyFile {
+    fn encrypt(
+        password: &str,
+        kdf: ScryptParams,
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+        key_pair: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        use chacha20poly1305::aead::{Aead, NewAead};
+
+        let nonce = rand::random::<[u8; 24]>();
+        let ciphertext = chacha20poly1305::XChaCha20Poly1305::new(&kdf.derive_key(password)?)
+            .encrypt(
+                chacha20poly1305::XNonce::from_slice(&nonce),
+                chacha20poly1305::aead::Payload {
+                    msg: key_pair.as_bytes(),
+                    aad: Self::associated_data(account_id, public_key).as_bytes(),
+                },
+            )
+            .map_err(|_| color_eyre::eyre::eyre!("Failed to encrypt the access key"))?;
+        Ok(Self {
+            account_id: account_id.clone(),
+            public_key: public_key.clone(),
+            kdf,
+            nonce: base64::encode(nonce),
+            ciphertext: base64::encode(ciphertext),
+        })
+    }
+
+    fn decrypt(&self, password: &str) -> color_eyre::eyre::Result<String> {
+        use chacha20poly1305::aead::{Aead, NewAead};
+
+        let nonce = base64::decode(&self.nonce).wrap_err("Invalid nonce of the encrypted key")?;
+        if nonce.len() != 24 {
+            color_eyre::eyre::bail!("Invalid nonce of the encrypted key");
+        }
+        let ciphertext =
+            base64::decode(&self.ciphertext).wrap_err("Invalid ciphertext of the encrypted key")?;
+        let key_pair = chacha20poly1305::XChaCha20Poly1305::new(&self.kdf.derive_key(password)?)
+            .decrypt(
+                chacha20poly1305::XNonce::from_slice(&nonce),
+                chacha20poly1305::aead::Payload {
+                    msg: &ciphertext,
+                    aad: Self::associated_data(&self.account_id, &self.public_key).as_bytes(),
+                },
+            )
+            .map_err(|_| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::LocalKeyStorage,
+                    "Failed to decrypt the access key: the password is wrong or the file is damaged",
+                )
+            })?;
+        String::from_utf8(key_pair).wrap_err("The decrypted access key is not valid UTF-8")
+    }
+
+    /// The account and the public key are authenticated along with the key pair, so they can't be
+    /// swapped with the ones of another file.
+    fn associated_data(
+        account_id: &near_primitives::types::AccountId,
+        public_key: &near_crypto::PublicKey,
+    ) -> String {
+        format!("{}:{}", account_id, public_key)
+    }
+}
+
+impl ScryptParams {
+    /// The recommended parameters (32 MiB of memory) with a new salt
+    fn new() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+            salt: base64::encode(rand::random::<[u8; 16]>()),
+        }
+    }
+
+    fn derive_key(&self, password: &str) -> color_eyre::eyre::Result<chacha20poly1305::Key> {
+        let salt = base64::decode(&self.salt).wrap_err("Invalid salt of the encrypted key")?;
+        let params = scrypt::Params::new(self.log_n, self.r, self.p).map_err(|_| {
+            color_eyre::eyre::eyre!("Invalid scrypt parameters of the encrypted key")
+        })?;
+        let mut key = chacha20poly1305::Key::default();
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key)
+            .map_err(|_| color_eyre::eyre::eyre!("Failed to derive the encryption key"))?;
+        Ok(key)
+    }
+}
+
+/// The password of the encrypted keychain is taken from `NEAR_CLI_KEYCHAIN_PASSWORD`, so scripts
+/// can use the keychain, or else prompted for.
+fn encrypted_keychain_password(is_new_key: bool) -> color_eyre::eyre::Result<String> {
+    if let Ok(password) = std::env::var("NEAR_CLI_KEYCHAIN_PASSWORD") {
+        return Ok(password);
+    }
+    let password = inquire::Password::new("Enter the password of the encrypted keychain:");
+    let password = if is_new_key {
+        password.with_custom_confirmation_message("Confirm the password:")
+    } else {
+        password.without_confirmation()
+    };
+    Ok(password.prompt()?)
+}
+
+/// Reads a key file of the legacy keychain, or decrypts a key file of the encrypted keychain.
+pub fn read_key_file(path: &std::path::Path) -> color_eyre::eyre::Result<String> {
+    let data = std::fs::read_to_string(path).wrap_err_with(|| {
+        crate::common::CliError::new(
+            crate::common::ErrorCategory::LocalKeyStorage,
+            format!("Error reading data from file: {}", path.display()),
+        )
+    })?;
+    if !is_encrypted_key_file(path) {
+        return Ok(data);
+    }
+    let encrypted_key_file: EncryptedKeyFile = serde_json::from_str(&data).wrap_err_with(|| {
+        crate::common::CliError::new(
+            crate::common::ErrorCategory::LocalKeyStorage,
+            format!("Invalid encrypted key file: {}", path.display()),
+        )
+    })?;
+    encrypted_key_file.decrypt(&encrypted_keychain_password(false)?)
+}
+
+fn read_public_key(path: &std::path::Path) -> Option<near_crypto::PublicKey> {
+    let key_pair: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+    key_pair.get("public_key")?.as_str()?.parse().ok()
+}
+
+/// The items are saved with the `near-<network>-<account_id>` service and the
+/// `<account_id>:<public_key>` account names.
+#[cfg(target_os = "macos")]
+fn list_macos_keychain_keys(network_name: &str) -> color_eyre::eyre::Result<Vec<LocalKey>> {
+    let service_prefix = format!("near-{}-", network_name);
+    // The search fails when there are no generic passwords at all
+    let search_results = security_framework::item::ItemSearchOptions::new()
+        .class(security_framework::item::ItemClass::generic_password())
+        .load_attributes(true)
+        .limit(security_framework::item::Limit::All)
+        .search()
+        .unwrap_or_default();
+    Ok(search_results
+        .iter()
+        .filter_map(|search_result| {
+            let attributes = search_result.simplify_dict()?;
+            let service_name = attributes.get("svce")?;
+            let account_id = service_name.strip_prefix(&service_prefix)?;
+            let (item_account_id, public_key) = attributes.get("acct")?.split_once(':')?;
+            if item_account_id != account_id {
+                return None;
+            }
+            Some(LocalKey {
+                account_id: account_id.parse().ok()?,
+                public_key: public_key.parse().ok()?,
+                storage: KeyStorage::MacosKeychain,
+                locations: vec![service_name.clone()],
+            })
+        })
+        .collect())
+}
+
+/// Reads the key pair in the JSON format of the storages.
+pub fn read_key_pair(local_key: &LocalKey) -> color_eyre::eyre::Result<String> {
+    match local_key.storage {
+        KeyStorage::LegacyKeychain | KeyStorage::EncryptedKeychain => {
+            read_key_file(std::path::Path::new(&local_key.locations[0]))
+        }
+        #[cfg(target_os = "macos")]
+        KeyStorage::MacosKeychain => {
+            let (password, _) = open_macos_keychain()?
+                .find_generic_password(
+                    &local_key.locations[0],
+                    &format!("{}:{}", local_key.account_id, local_key.public_key),
+                )
+                .wrap_err_with(|| {
+                    crate::common::CliError::new(
+                        crate::common::ErrorCategory::LocalKeyStorage,
+                        "Failed to read the access key from the macOS keychain",
+                    )
+                })?;
+            Ok(String::from_utf8_lossy(password.as_ref()).into_owned())
+        }
+        #[cfg(not(target_os = "macos"))]
+        KeyStorage::MacosKeychain => Err(macos_keychain_unavailable_error()),
+    }
+}
+
+pub fn save_key_pair(
+    storage: KeyStorage,
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    key_pair: &str,
+) -> color_eyre::eyre::Result<String> {
+    match storage {
+        KeyStorage::LegacyKeychain => crate::common::save_access_key_to_keychain(
+            network_config.clone(),
+            credentials_home_dir.to_path_buf(),
+            key_pair,
+            &public_key.to_string(),
+            account_id.as_str(),
+        ),
+        KeyStorage::EncryptedKeychain => save_encrypted_key_pair(
+            credentials_home_dir,
+            network_config,
+            account_id,
+            public_key,
+            key_pair,
+        ),
+        #[cfg(target_os = "macos")]
+        KeyStorage::MacosKeychain => crate::common::save_access_key_to_macos_keychain(
+            network_config.clone(),
+            key_pair,
+            &public_key.to_string(),
+            account_id.as_str(),
+        ),
+        #[cfg(not(target_os = "macos"))]
+        KeyStorage::MacosKeychain => Err(macos_keychain_unavailable_error()),
+    }
+}
+
+fn save_encrypted_key_pair(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    key_pair: &str,
+) -> color_eyre::eyre::Result<String> {
+    let mut path = credentials_home_dir
+        .join(&network_config.network_name)
+        .join(account_id.as_str());
+    std::fs::create_dir_all(&path)
+        .wrap_err_with(|| format!("Failed to create directory: {:?}", path))?;
+    path.push(format!(
+        "{}.{}",
+        public_key.to_string().replace(':', "_"),
+        ENCRYPTED_KEY_FILE_EXTENSION
+    ));
+    if path.exists() {
+        return Ok(format!(
+            "The file: {} already exists! Therefore it was not overwritten.",
+            path.display()
+        ));
+    }
+    let encrypted_key_file = EncryptedKeyFile::encrypt(
+        &encrypted_keychain_password(true)?,
+        ScryptParams::new(),
+        account_id,
+        public_key,
+        key_pair,
+    )?;
+    std::fs::write(&path, serde_json::to_string_pretty(&encrypted_key_file)?)
+        .wrap_err_with(|| format!("Failed to write to file: {:?}", path))?;
+    Ok(format!(
+        "The access key is encrypted and saved in a file {}",
+        path.display()
+    ))
+}
+
+/// Saves `<network>/<account_id>/<public_key>.json` without a private key, so the Ledger key is
+/// listed as "ledger HD path known" and is never picked for signing with the keychain.
+pub fn save_ledger_hd_path(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    seed_phrase_hd_path: &crate::types::slip10::BIP32Path,
+) -> color_eyre::eyre::Result<String> {
+    let mut path = credentials_home_dir
+        .join(&network_config.network_name)
+        .join(account_id.as_str());
+    std::fs::create_dir_all(&path)
+        .wrap_err_with(|| format!("Failed to create directory: {:?}", path))?;
+    path.push(format!("{}.json", public_key.to_string().replace(':', "_")));
+    let buf = serde_json::json!({
+        "public_key": public_key.to_string(),
+        "seed_phrase_hd_path": seed_phrase_hd_path.to_string(),
+    })
+    .to_string();
+    std::fs::write(&path, buf).wrap_err_with(|| format!("Failed to write to file: {:?}", path))?;
+    Ok(format!(
+        "The HD path of the Ledger key is saved in a file {}",
+        path.display()
+    ))
+}
+
+pub fn delete_key_pair(local_key: &LocalKey) -> crate::CliResult {
+    match local_key.storage {
+        KeyStorage::LegacyKeychain | KeyStorage::EncryptedKeychain => {
+            for path in &local_key.locations {
+                std::fs::remove_file(path).wrap_err_with(|| {
+                    crate::common::CliError::new(
+                        crate::common::ErrorCategory::LocalKeyStorage,
+                        format!("Failed to delete file: {}", path),
+                    )
+                })?;
+            }
+            Ok(())
+        }
+        #[cfg(target_os = "macos")]
+        KeyStorage::MacosKeychain => {
+            let (_, item) = open_macos_keychain()?
+                .find_generic_password(
+                    &local_key.locations[0],
+                    &format!("{}:{}", local_key.account_id, local_key.public_key),
+                )
+                .wrap_err_with(|| {
+                    crate::common::CliError::new(
+                        crate::common::ErrorCategory::LocalKeyStorage,
+                        "Failed to find the access key in the macOS keychain",
+                    )
+                })?;
+            item.delete();
+            Ok(())
+        }
+        #[cfg(not(target_os = "macos"))]
+        KeyStorage::MacosKeychain => Err(macos_keychain_unavailable_error()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn open_macos_keychain(
+) -> color_eyre::eyre::Result<security_framework::os::macos::keychain::SecKeychain> {
+    security_framework::os::macos::keychain::SecKeychain::default().wrap_err_with(|| {
+        crate::common::CliError::new(
+            crate::common::ErrorCategory::LocalKeyStorage,
+            "Failed to open keychain",
+        )
+    })
+}
+
+#[cfg(not(target_os = "macos"))]
+fn macos_keychain_unavailable_error() -> color_eyre::eyre::Report {
+    crate::common::CliError::new(
+        crate::common::ErrorCategory::LocalKeyStorage,
+        "The macOS keychain is only available on macOS",
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_key_file_round_trip() {
+        let account_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+        let public_key: near_crypto::PublicKey =
+            "ed25519:6aXRS6VCxJ7D9oiYM8rj3vCyAzByTSCuaVFRrsP5fhiX"
+                .parse()
+                .unwrap();
+        let key_pair = r#"{"public_key":"ed25519:6aXRS6VCxJ7D9oiYM8rj3vCyAzByTSCuaVFRrsP5fhiX"}"#;
+        // Cheaper parameters, so the test is fast in debug builds
+        let kdf = ScryptParams {
+            log_n: 10,
+            ..ScryptParams::new()
+        };
+        let encrypted_key_file =
+            EncryptedKeyFile::encrypt("password", kdf, &account_id, &public_key, key_pair).unwrap();
+        assert!(!encrypted_key_file.ciphertext.contains("public_key"));
+        let encrypted_key_file: EncryptedKeyFile =
+            serde_json::from_str(&serde_json::to_string(&encrypted_key_file).unwrap()).unwrap();
+        assert_eq!(encrypted_key_file.decrypt("password").unwrap(), key_pair);
+        assert!(encrypted_key_file.decrypt("wrong password").is_err());
+
+        // The key pair can't be passed off as the key of another account
+        let mut swapped_key_file = encrypted_key_file;
+        swapped_key_file.account_id = "bob.near".parse().unwrap();
+        assert!(swapped_key_file.decrypt("password").is_err());
+    }
+}
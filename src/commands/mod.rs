@@ -2,14 +2,47 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod account;
+mod completions;
 mod config;
 mod contract;
+pub mod local_keys;
 mod tokens;
 mod transaction;
 
 #[cfg(feature = "self-update")]
 pub mod extensions;
 
+/// The root command of near CLI; it lives in the library, so the completion script is generated
+/// from it with the global arguments
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct Cmd {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Output format of view commands and transaction results: plaintext or json
+    pub output_format: Option<crate::common::OutputFormat>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Path to the configuration file (overrides NEAR_CLI_CONFIG and the default location)
+    pub config: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(subcommand)]
+    pub top_level: TopLevelCommand,
+}
+
+impl Cmd {
+    fn input_output_format(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::common::OutputFormat>> {
+        Ok(None)
+    }
+
+    fn input_config(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        Ok(None)
+    }
+}
+
 #[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
@@ -33,11 +66,21 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "transaction - Operate transactions"))]
     /// Use this to construct transactions or view a transaction status.
     Transaction(self::transaction::TransactionCommands),
+    #[strum_discriminants(strum(
+        message = "local-keys  - Manage the access keys stored locally (legacy keychain, macOS keychain)"
+    ))]
+    /// Use this to list, export, delete or move the access keys stored by near CLI
+    LocalKeys(self::local_keys::LocalKeysCommands),
     #[strum_discriminants(strum(
         message = "config      - Manage connections in a configuration file (config.toml)"
     ))]
     /// Use this to manage connections in a configuration file (config.toml).
     Config(self::config::ConfigCommands),
+    #[strum_discriminants(strum(
+        message = "completions - Generate a shell completion script (bash, zsh, fish, powershell)"
+    ))]
+    /// Use this to generate a shell completion script, including the names of your connections, accounts and keys as they are when the script is generated (generate it again to pick up new ones)
+    Completions(self::completions::Completions),
     #[cfg(feature = "self-update")]
     #[strum_discriminants(strum(message = "extension   - Manage near CLI and extensions"))]
     /// Use this to manage near CLI and extensions
@@ -64,7 +107,7 @@ pub struct PrepopulatedTransaction {
 
 #[derive(Clone)]
 pub struct ActionContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub on_after_getting_network_callback: OnAfterGettingNetworkCallback,
     pub on_before_signing_callback: OnBeforeSigningCallback,
     pub on_before_sending_transaction_callback:
@@ -75,7 +118,7 @@ pub struct ActionContext {
 
 #[derive(Clone)]
 pub struct TransactionContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub network_config: crate::config::NetworkConfig,
     pub prepopulated_transaction: PrepopulatedTransaction,
     pub on_before_signing_callback: OnBeforeSigningCallback,
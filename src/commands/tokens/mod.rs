@@ -14,15 +14,24 @@ mod view_nft_assets;
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = TokensCommandsContext)]
 pub struct TokensCommands {
+    #[interactive_clap(skip_default_input_arg)]
     /// What is your account ID?
     owner_account_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
     tokens_actions: TokensActions,
 }
 
+impl TokensCommands {
+    fn input_owner_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(&context.0, "What is your account ID?")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TokensCommandsContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     owner_account_id: near_primitives::types::AccountId,
 }
 
@@ -32,7 +41,7 @@ impl TokensCommandsContext {
         scope: &<TokensCommands as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             owner_account_id: scope.owner_account_id.clone().into(),
         })
     }
@@ -82,7 +91,7 @@ pub fn params_ft_metadata(
     block_reference: near_primitives::types::BlockReference,
 ) -> color_eyre::eyre::Result<FtMetadata> {
     let ft_metadata: FtMetadata = network_config
-        .json_rpc_client()
+        .json_rpc_client()?
         .blocking_call_view_function(
             &ft_contract_account_id,
             "ft_metadata",
@@ -92,3 +101,38 @@ pub fn params_ft_metadata(
         .parse_result_from_json()?;
     Ok(ft_metadata)
 }
+
+/// The amount in tokens, e.g. `12.5 USDC` for `12500000` with 6 decimals.
+pub fn format_ft_amount(amount: u128, decimals: u64, symbol: &str) -> String {
+    let one_token = 10u128.pow(decimals as u32);
+    if amount == 0 {
+        format!("0 {}", symbol)
+    } else if (amount % one_token) == 0 {
+        format!("{} {}", amount / one_token, symbol,)
+    } else {
+        format!(
+            "{}.{} {}",
+            amount / one_token,
+            format!("{:0>width$}", amount % one_token, width = decimals as usize)
+                .trim_end_matches('0'),
+            symbol
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_ft_amounts_in_tokens() {
+        assert_eq!(format_ft_amount(0, 6, "USDC"), "0 USDC");
+        assert_eq!(format_ft_amount(12_000_000, 6, "USDC"), "12 USDC");
+        assert_eq!(format_ft_amount(12_500_000, 6, "USDC"), "12.5 USDC");
+        assert_eq!(format_ft_amount(1, 6, "USDC"), "0.000001 USDC");
+        assert_eq!(
+            format_ft_amount(10u128.pow(24) + 1, 24, "wNEAR"),
+            "1.000000000000000000000001 wNEAR"
+        );
+    }
+}
@@ -11,8 +11,8 @@ pub struct SendFtCommand {
     ft_contract_account_id: crate::types::account_id::AccountId,
     /// What is the receiver account ID?
     receiver_account_id: crate::types::account_id::AccountId,
-    /// Enter an amount FT to transfer
-    amount: u128,
+    /// Enter an amount FT to transfer (e.g. 12.5 USDC, 12.5 or 12500000 raw)
+    amount: crate::types::ft_amount::FtAmount,
     #[interactive_clap(long = "prepaid-gas")]
     #[interactive_clap(skip_default_input_arg)]
     /// Enter gas for function call
@@ -21,6 +21,9 @@ pub struct SendFtCommand {
     #[interactive_clap(skip_default_input_arg)]
     /// Enter deposit for a function call
     deposit: crate::common::NearBalance,
+    #[interactive_clap(long)]
+    /// Transfer the amount without a confirmation
+    yes: bool,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_for_transaction::NetworkForTransactionArgs,
@@ -28,13 +31,14 @@ pub struct SendFtCommand {
 
 #[derive(Debug, Clone)]
 pub struct SendFtCommandContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     ft_contract_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
-    amount: u128,
+    amount: crate::types::ft_amount::FtAmount,
     gas: crate::common::NearGas,
     deposit: crate::common::NearBalance,
+    yes: bool,
 }
 
 impl SendFtCommandContext {
@@ -43,13 +47,14 @@ impl SendFtCommandContext {
         scope: &<SendFtCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.owner_account_id,
             ft_contract_account_id: scope.ft_contract_account_id.clone().into(),
             receiver_account_id: scope.receiver_account_id.clone().into(),
-            amount: scope.amount,
+            amount: scope.amount.clone(),
             gas: scope.gas.clone(),
             deposit: scope.deposit.clone(),
+            yes: scope.yes,
         })
     }
 }
@@ -57,36 +62,73 @@ impl SendFtCommandContext {
 impl From<SendFtCommandContext> for crate::commands::ActionContext {
     fn from(item: SendFtCommandContext) -> Self {
         let signer_account_id = item.signer_account_id.clone();
-        let amount = item.amount;
         let ft_contract_account_id = item.ft_contract_account_id.clone();
         let receiver_account_id = item.receiver_account_id.clone();
+        let scripting_mode = item.global_context.1.scripting_mode;
+        // The amount in tokens is found with the FT metadata before signing, and is reused in the
+        // message after sending, so the metadata is requested only once
+        let transferred_amount_fmt = std::sync::Arc::new(std::sync::Mutex::new(None::<String>));
 
         let on_after_getting_network_callback: crate::commands::OnAfterGettingNetworkCallback =
-            std::sync::Arc::new(move |_network_config| {
-                Ok(crate::commands::PrepopulatedTransaction {
-                    signer_id: item.signer_account_id.clone(),
-                    receiver_id: item.ft_contract_account_id.clone(),
-                    actions: vec![near_primitives::transaction::Action::FunctionCall(
-                        near_primitives::transaction::FunctionCallAction {
-                            method_name: "ft_transfer".to_string(),
-                            args: json!({
-                                "receiver_id": item.receiver_account_id.to_string(),
-                                "amount": item.amount.to_string()
-                            })
-                            .to_string()
-                            .into_bytes(),
-                            gas: item.gas.inner,
-                            deposit: item.deposit.to_yoctonear(),
-                        },
-                    )],
-                })
+            std::sync::Arc::new({
+                let transferred_amount_fmt = transferred_amount_fmt.clone();
+                move |network_config| {
+                    let (raw_amount, amount_fmt) = ft_transfer_amount(
+                        &item.amount,
+                        &item.ft_contract_account_id,
+                        network_config,
+                    )?;
+                    eprintln!(
+                        "\nTransferring {} ({} in the smallest units of the token) from <{}> to <{}>.",
+                        amount_fmt, raw_amount, item.signer_account_id, item.receiver_account_id
+                    );
+                    if !crate::common::confirm(
+                        scripting_mode,
+                        item.yes,
+                        &format!(
+                            "Do you want to transfer {} ({} raw)?",
+                            amount_fmt, raw_amount
+                        ),
+                    )? {
+                        return Err(crate::common::CliError::new(
+                            crate::common::ErrorCategory::UserInput,
+                            "The FT transfer was cancelled",
+                        )
+                        .into());
+                    }
+                    *transferred_amount_fmt
+                        .lock()
+                        .expect("The FT amount lock is poisoned") = Some(amount_fmt);
+                    Ok(crate::commands::PrepopulatedTransaction {
+                        signer_id: item.signer_account_id.clone(),
+                        receiver_id: item.ft_contract_account_id.clone(),
+                        actions: vec![near_primitives::transaction::Action::FunctionCall(
+                            near_primitives::transaction::FunctionCallAction {
+                                method_name: "ft_transfer".to_string(),
+                                args: json!({
+                                    "receiver_id": item.receiver_account_id.to_string(),
+                                    "amount": raw_amount.to_string()
+                                })
+                                .to_string()
+                                .into_bytes(),
+                                gas: item.gas.inner,
+                                deposit: item.deposit.to_yoctonear(),
+                            },
+                        )],
+                    })
+                }
             });
 
         let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new(
             move |outcome_view, _network_config| {
                 if let near_primitives::views::FinalExecutionStatus::SuccessValue(_) = outcome_view.status {
+                    let amount_fmt = transferred_amount_fmt
+                        .lock()
+                        .expect("The FT amount lock is poisoned")
+                        .clone()
+                        .unwrap_or_default();
                     eprintln!(
-                        "<{signer_account_id}> has successfully transferred {amount} FT ({ft_contract_account_id}) to <{receiver_account_id}>.",
+                        "<{signer_account_id}> has successfully transferred {amount_fmt} (FT-contract: {ft_contract_account_id}) to <{receiver_account_id}>.",
                     );
                 }
                 Ok(())
@@ -94,7 +136,7 @@ impl From<SendFtCommandContext> for crate::commands::ActionContext {
         );
 
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -107,15 +149,33 @@ impl From<SendFtCommandContext> for crate::commands::ActionContext {
     }
 }
 
+/// The amount to transfer in the smallest units of the token, and in tokens for the messages.
+fn ft_transfer_amount(
+    amount: &crate::types::ft_amount::FtAmount,
+    ft_contract_account_id: &near_primitives::types::AccountId,
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<(u128, String)> {
+    let super::FtMetadata { decimals, symbol } = super::params_ft_metadata(
+        ft_contract_account_id.clone(),
+        network_config,
+        near_primitives::types::Finality::Final.into(),
+    )?;
+    let raw_amount = amount.to_raw(&symbol, decimals as u32)?;
+    Ok((
+        raw_amount,
+        super::format_ft_amount(raw_amount, decimals, &symbol),
+    ))
+}
+
 impl SendFtCommand {
     fn input_gas(
-        _context: &super::TokensCommandsContext,
+        context: &super::TokensCommandsContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
         eprintln!();
         let gas: u64 = loop {
             match crate::common::NearGas::from_str(
                 &Text::new("Enter gas for function call")
-                    .with_initial_value("100 TeraGas")
+                    .with_initial_value(context.global_context.0.cli.function_call_gas())
                     .prompt()?,
             ) {
                 Ok(input_gas) => {
@@ -134,14 +194,14 @@ impl SendFtCommand {
     }
 
     fn input_deposit(
-        _context: &super::TokensCommandsContext,
+        context: &super::TokensCommandsContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
         eprintln!();
         match crate::common::NearBalance::from_str(
             &Text::new(
                 "Enter deposit for a function call (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
-            .with_initial_value("1 yoctoNEAR")
+            .with_initial_value(context.global_context.0.cli.token_transfer_deposit())
             .prompt()?,
         ) {
             Ok(deposit) => Ok(Some(deposit)),
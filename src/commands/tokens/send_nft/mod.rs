@@ -28,7 +28,7 @@ pub struct SendNftCommand {
 
 #[derive(Debug, Clone)]
 pub struct SendNftCommandContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     nft_contract_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
@@ -43,7 +43,7 @@ impl SendNftCommandContext {
         scope: &<SendNftCommand as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.owner_account_id,
             nft_contract_account_id: scope.nft_contract_account_id.clone().into(),
             receiver_account_id: scope.receiver_account_id.clone().into(),
@@ -98,7 +98,7 @@ impl From<SendNftCommandContext> for crate::commands::ActionContext {
         );
 
         Self {
-            config: item.config,
+            global_context: item.global_context,
             on_after_getting_network_callback,
             on_before_signing_callback: std::sync::Arc::new(
                 |_prepolulated_unsinged_transaction, _network_config| Ok(()),
@@ -113,13 +113,13 @@ impl From<SendNftCommandContext> for crate::commands::ActionContext {
 
 impl SendNftCommand {
     fn input_gas(
-        _context: &super::TokensCommandsContext,
+        context: &super::TokensCommandsContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
         eprintln!();
         let gas: u64 = loop {
             match crate::common::NearGas::from_str(
                 &Text::new("Enter gas for function call")
-                    .with_initial_value("100 TeraGas")
+                    .with_initial_value(context.global_context.0.cli.function_call_gas())
                     .prompt()?,
             ) {
                 Ok(input_gas) => {
@@ -138,14 +138,14 @@ impl SendNftCommand {
     }
 
     fn input_deposit(
-        _context: &super::TokensCommandsContext,
+        context: &super::TokensCommandsContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
         eprintln!();
         match crate::common::NearBalance::from_str(
             &Text::new(
                 "Enter deposit for a function call (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
-            .with_initial_value("1 yoctoNEAR")
+            .with_initial_value(context.global_context.0.cli.token_transfer_deposit())
             .prompt()?,
         ) {
             Ok(deposit) => Ok(Some(deposit)),
@@ -9,6 +9,14 @@ use crate::common::JsonRpcClientExt;
 pub struct ViewFtBalance {
     /// What is the ft-contract account ID?
     ft_contract_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Re-check the balance on new final blocks at this interval (e.g. 30s) and print only the changes
+    watch: Option<crate::types::duration::Duration>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// With --watch, exit with an error once the balance is below:<amount>, above:<amount> or equal:<amount> (in tokens)
+    exit_if: Option<crate::types::threshold::Threshold>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
@@ -22,9 +30,13 @@ impl ViewFtBalanceContext {
         previous_context: super::TokensCommandsContext,
         scope: &<ViewFtBalance as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        crate::common::check_exit_if_needs_watch(scope.watch.as_ref(), scope.exit_if.as_ref())?;
         let owner_account_id = previous_context.owner_account_id;
+        let global_options = previous_context.global_context.1.clone();
         let ft_contract_account_id: near_primitives::types::AccountId =
             scope.ft_contract_account_id.clone().into();
+        let watch = scope.watch;
+        let exit_if = scope.exit_if.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
@@ -33,46 +45,46 @@ impl ViewFtBalanceContext {
                     network_config,
                     block_reference.clone(),
                 )?;
-                let args = json!({
-                    "account_id": owner_account_id.to_string(),
-                    })
-                    .to_string()
-                    .into_bytes();
-                let call_result = network_config
-                    .json_rpc_client()
-                    .blocking_call_view_function(
-                        &ft_contract_account_id,
-                        "ft_balance_of",
-                        args,
-                        block_reference.clone(),
-                    )?;
-                call_result.print_logs();
-                let amount: String = call_result.parse_result_from_json()?;
-                let amount = amount.parse::<u128>().unwrap();
-                let amount_fmt = {
-                    if amount == 0 {
-                        format!("0 {}", symbol)
-                    } else if (amount % 10u128.pow(decimals as u32)) == 0 {
-                        format!("{} {}", amount / 10u128.pow(decimals as u32), symbol,)
-                    } else {
-                        format!(
-                            "{}.{} {}",
-                            amount / 10u128.pow(decimals as u32),
-                            format!("{:0>24}", amount % 10u128.pow(decimals as u32)).trim_end_matches('0'),
-                            symbol
-                        )
-                    }
-                };
-
+                if let Some(watch) = watch {
+                    return crate::common::watch_final_blocks(network_config, block_reference, watch.into(), &global_options.output_format, |block_reference| {
+                        let amount = get_ft_balance(network_config, &ft_contract_account_id, &owner_account_id, block_reference)?;
+                        Ok(crate::common::WatchedValue {
+                            text: super::format_ft_amount(amount, decimals, &symbol),
+                            json: json!({
+                                "account_id": owner_account_id,
+                                "ft_contract_account_id": ft_contract_account_id,
+                                "amount": amount.to_string(),
+                                "decimals": decimals,
+                                "symbol": symbol,
+                            }),
+                            threshold_met: match &exit_if {
+                                Some(exit_if) => exit_if.is_met(amount, decimals as u32)?,
+                                None => false,
+                            },
+                        })
+                    });
+                }
+                let amount = get_ft_balance(network_config, &ft_contract_account_id, &owner_account_id, block_reference)?;
+                if let crate::common::OutputFormat::Json = global_options.output_format {
+                    return crate::common::print_json_output(&json!({
+                        "account_id": owner_account_id,
+                        "ft_contract_account_id": ft_contract_account_id,
+                        "amount": amount.to_string(),
+                        "decimals": decimals,
+                        "symbol": symbol,
+                    }));
+                }
                 eprintln!(
                     "\n<{}> account has {}  (FT-contract: {})",
-                    owner_account_id, amount_fmt, ft_contract_account_id
+                    owner_account_id,
+                    super::format_ft_amount(amount, decimals, &symbol),
+                    ft_contract_account_id
                 );
                 Ok(())
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -83,3 +95,41 @@ impl From<ViewFtBalanceContext> for crate::network_view_at_block::ArgsForViewCon
         item.0
     }
 }
+
+impl ViewFtBalance {
+    fn input_watch(
+        _context: &super::TokensCommandsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::duration::Duration>> {
+        Ok(None)
+    }
+
+    fn input_exit_if(
+        _context: &super::TokensCommandsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::threshold::Threshold>> {
+        Ok(None)
+    }
+}
+
+fn get_ft_balance(
+    network_config: &crate::config::NetworkConfig,
+    ft_contract_account_id: &near_primitives::types::AccountId,
+    owner_account_id: &near_primitives::types::AccountId,
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<u128> {
+    let args = json!({
+        "account_id": owner_account_id.to_string(),
+    })
+    .to_string()
+    .into_bytes();
+    let call_result = network_config
+        .json_rpc_client()?
+        .blocking_call_view_function(
+            ft_contract_account_id,
+            "ft_balance_of",
+            args,
+            block_reference.clone(),
+        )?;
+    call_result.print_logs();
+    let amount: String = call_result.parse_result_from_json()?;
+    Ok(amount.parse::<u128>().unwrap())
+}
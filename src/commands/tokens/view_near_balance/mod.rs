@@ -2,6 +2,14 @@
 #[interactive_clap(input_context = super::TokensCommandsContext)]
 #[interactive_clap(output_context = ViewNearBalanceContext)]
 pub struct ViewNearBalance {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Re-check the balance on new final blocks at this interval (e.g. 30s) and print only the changes
+    watch: Option<crate::types::duration::Duration>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// With --watch, exit with an error once the balance is below:<NEAR>, above:<NEAR> or equal:<NEAR>
+    exit_if: Option<crate::types::threshold::Threshold>,
     #[interactive_clap(named_arg)]
     /// Select network
     network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
@@ -13,22 +21,55 @@ pub struct ViewNearBalanceContext(crate::network_view_at_block::ArgsForViewConte
 impl ViewNearBalanceContext {
     pub fn from_previous_context(
         previous_context: super::TokensCommandsContext,
-        _scope: &<ViewNearBalance as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+        scope: &<ViewNearBalance as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        crate::common::check_exit_if_needs_watch(scope.watch.as_ref(), scope.exit_if.as_ref())?;
         let owner_account_id = previous_context.owner_account_id;
+        let global_options = previous_context.global_context.1.clone();
+        let watch = scope.watch;
+        let exit_if = scope.exit_if.clone();
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             move |network_config, block_reference| {
+            if let Some(watch) = watch {
+                return crate::common::watch_final_blocks(network_config, block_reference, watch.into(), &global_options.output_format, |block_reference| {
+                    let account_transfer_allowance = crate::common::get_account_transfer_allowance(
+                        network_config.clone(),
+                        owner_account_id.clone(),
+                        block_reference.clone(),
+                        global_options.scripting_mode,
+                    )?;
+                    Ok(crate::common::WatchedValue {
+                        text: format!(
+                            "{} ({} available for transfer)",
+                            account_transfer_allowance.balance(),
+                            account_transfer_allowance.transfer_allowance()
+                        ),
+                        json: account_transfer_allowance.to_json(),
+                        threshold_met: match &exit_if {
+                            Some(exit_if) => exit_if.is_met(
+                                account_transfer_allowance.balance().to_yoctonear(),
+                                24,
+                            )?,
+                            None => false,
+                        },
+                    })
+                });
+            }
             let account_transfer_allowance = crate::common::get_account_transfer_allowance(
                 network_config.clone(),
                 owner_account_id.clone(),
                 block_reference.clone(),
+                global_options.scripting_mode,
             )?;
+        if let crate::common::OutputFormat::Json = global_options.output_format {
+            return crate::common::print_json_output(&account_transfer_allowance.to_json());
+        }
         eprintln! {"{}", &account_transfer_allowance};
         Ok(())
         }});
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_block_reference_callback,
         }))
     }
@@ -39,3 +80,17 @@ impl From<ViewNearBalanceContext> for crate::network_view_at_block::ArgsForViewC
         item.0
     }
 }
+
+impl ViewNearBalance {
+    fn input_watch(
+        _context: &super::TokensCommandsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::duration::Duration>> {
+        Ok(None)
+    }
+
+    fn input_exit_if(
+        _context: &super::TokensCommandsContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::threshold::Threshold>> {
+        Ok(None)
+    }
+}
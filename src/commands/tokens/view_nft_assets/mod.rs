@@ -23,6 +23,7 @@ impl ViewNftAssetsContext {
         scope: &<ViewNftAssets as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let owner_account_id = previous_context.owner_account_id;
+        let global_options = previous_context.global_context.1.clone();
         let nft_contract_account_id: near_primitives::types::AccountId =
             scope.nft_contract_account_id.clone().into();
 
@@ -34,7 +35,7 @@ impl ViewNftAssetsContext {
                     .to_string()
                     .into_bytes();
                 let call_result = network_config
-                    .json_rpc_client()
+                    .json_rpc_client()?
                     .blocking_call_view_function(
                         &nft_contract_account_id,
                         "nft_tokens_for_owner",
@@ -44,13 +45,20 @@ impl ViewNftAssetsContext {
                 call_result.print_logs();
                 let serde_call_result: serde_json::Value = call_result.parse_result_from_json()?;
 
+                if let crate::common::OutputFormat::Json = global_options.output_format {
+                    return crate::common::print_json_output(&json!({
+                        "account_id": owner_account_id,
+                        "nft_contract_account_id": nft_contract_account_id,
+                        "tokens": serde_call_result,
+                    }));
+                }
                 eprintln!("\n{} account has NFT tokens:", owner_account_id);
                 eprintln!("{}", serde_json::to_string_pretty(&serde_call_result)?);
                 Ok(())
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             on_after_getting_block_reference_callback,
         }))
     }
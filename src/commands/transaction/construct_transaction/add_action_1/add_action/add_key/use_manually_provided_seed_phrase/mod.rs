@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -6,6 +7,14 @@ use std::str::FromStr;
 pub struct AddAccessWithSeedPhraseAction {
     /// Enter the seed_phrase for this sub-account
     master_seed_phrase: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
     #[interactive_clap(subcommand)]
     next_action: super::super::super::super::add_action_2::NextAction,
 }
@@ -24,6 +33,8 @@ impl AddAccessWithSeedPhraseActionContext {
         let public_key = crate::common::get_public_key_from_seed_phrase(
             seed_phrase_hd_path_default,
             &scope.master_seed_phrase,
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
         let access_key = near_primitives::account::AccessKey {
             nonce: 0,
@@ -39,7 +50,7 @@ impl AddAccessWithSeedPhraseActionContext {
         actions.push(action);
         Ok(Self(
             super::super::super::super::ConstructTransactionContext {
-                config: previous_context.config,
+                global_context: previous_context.global_context,
                 signer_account_id: previous_context.signer_account_id,
                 receiver_account_id: previous_context.receiver_account_id,
                 actions,
@@ -55,3 +66,11 @@ impl From<AddAccessWithSeedPhraseActionContext>
         item.0
     }
 }
+
+impl SeedPhraseOptions for AddAccessWithSeedPhraseAction {
+    type InputContext = super::access_key_type::AccessKeyPermissionContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
@@ -3,6 +3,7 @@
 #[interactive_clap(output_context = DeleteAccountActionContext)]
 pub struct DeleteAccountAction {
     #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
     /// Enter the beneficiary ID to delete this account ID
     beneficiary_id: crate::types::account_id::AccountId,
     #[interactive_clap(subcommand)]
@@ -24,7 +25,7 @@ impl DeleteAccountActionContext {
         let mut actions = previous_context.actions;
         actions.push(action);
         Ok(Self(super::super::super::ConstructTransactionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions,
@@ -37,3 +38,24 @@ impl From<DeleteAccountActionContext> for super::super::super::ConstructTransact
         item.0
     }
 }
+
+impl DeleteAccountAction {
+    fn input_beneficiary_id(
+        context: &super::super::super::ConstructTransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--beneficiary-id")?;
+        match inquire::CustomType::new("Enter the beneficiary ID to delete this account ID")
+            .prompt()
+        {
+            Ok(beneficiary_id) => Ok(Some(beneficiary_id)),
+            Err(
+                inquire::error::InquireError::OperationCanceled
+                | inquire::error::InquireError::OperationInterrupted,
+            ) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use inquire::{CustomType, Select, Text};
 
 #[derive(Clone)]
 pub struct AccessKeyPermissionContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub signer_account_id: near_primitives::types::AccountId,
     pub receiver_account_id: near_primitives::types::AccountId,
     pub actions: Vec<near_primitives::transaction::Action>,
@@ -28,7 +28,7 @@ impl FullAccessTypeContext {
         _scope: &<FullAccessType as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self(AccessKeyPermissionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions: previous_context.actions,
@@ -51,7 +51,7 @@ pub struct FunctionCallType {
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_from_cli_arg)]
     #[interactive_clap(skip_default_input_arg)]
-    allowance: Option<crate::common::NearBalance>,
+    allowance: Option<crate::types::allowance::Allowance>,
     #[interactive_clap(long)]
     /// Enter a receiver to use by this access key to pay for function call gas and transaction fees.
     receiver_account_id: crate::types::account_id::AccountId,
@@ -75,14 +75,14 @@ impl FunctionCallTypeContext {
             near_primitives::account::FunctionCallPermission {
                 allowance: scope
                     .allowance
-                    .clone()
-                    .map(|allowance| allowance.to_yoctonear()),
+                    .as_ref()
+                    .and_then(|allowance| allowance.optional_yoctonear()),
                 receiver_id: scope.receiver_account_id.to_string(),
                 method_names: scope.method_names.clone().into(),
             },
         );
         Ok(Self(AccessKeyPermissionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions: previous_context.actions,
@@ -113,14 +113,19 @@ impl interactive_clap::FromCli for FunctionCallType {
         let mut clap_variant = optional_clap_variant.unwrap_or_default();
 
         if clap_variant.allowance.is_none() {
-            clap_variant.allowance = match Self::input_allowance() {
+            clap_variant.allowance = match Self::input_allowance(&context) {
                 Ok(optional_allowance) => optional_allowance,
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
             };
         }
         let allowance = clap_variant.allowance.clone();
         if clap_variant.receiver_account_id.is_none() {
-            clap_variant.receiver_account_id = match Self::input_receiver_account_id(&context) {
+            clap_variant.receiver_account_id = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--receiver-account-id")
+                .and_then(|_| Self::input_receiver_account_id(&context))
+            {
                 Ok(Some(first_receiver_account_id)) => Some(first_receiver_account_id),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -131,7 +136,12 @@ impl interactive_clap::FromCli for FunctionCallType {
             .clone()
             .expect("Unexpected error");
         if clap_variant.method_names.is_none() {
-            clap_variant.method_names = match Self::input_method_names() {
+            clap_variant.method_names = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--method-names")
+                .and_then(|_| Self::input_method_names())
+            {
                 Ok(Some(first_method_names)) => Some(first_method_names),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -208,7 +218,14 @@ impl FunctionCallType {
         }
     }
 
-    pub fn input_allowance() -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
+    pub fn input_allowance(
+        context: &super::super::super::super::ConstructTransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::allowance::Allowance>> {
+        // A missing allowance would silently grant an unlimited one, so it is never defaulted
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--allowance")?;
         eprintln!();
         #[derive(strum_macros::Display)]
         enum ConfirmOptions {
@@ -226,9 +243,11 @@ impl FunctionCallType {
             let allowance_near_balance: crate::common::NearBalance =
                     CustomType::new("Enter an allowance which is a balance limit to use by this access key to pay for function call gas and transaction fees. (example: 10NEAR or 0.5near or 10000yoctonear)")
                         .prompt()?;
-            Ok(Some(allowance_near_balance))
+            Ok(Some(crate::types::allowance::Allowance::Limited(
+                allowance_near_balance,
+            )))
         } else {
-            Ok(None)
+            Ok(Some(crate::types::allowance::Allowance::Unlimited))
         }
     }
 }
@@ -22,7 +22,7 @@ pub struct FunctionCallAction {
 
 #[derive(Debug, Clone)]
 pub struct FunctionCallActionContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
     actions: Vec<near_primitives::transaction::Action>,
@@ -41,7 +41,7 @@ impl FunctionCallActionContext {
                 scope.function_args_type.clone(),
             )?;
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions: previous_context.actions,
@@ -76,7 +76,7 @@ pub struct PrepaidGas {
 
 #[derive(Debug, Clone)]
 pub struct PrepaidGasContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     signer_account_id: near_primitives::types::AccountId,
     receiver_account_id: near_primitives::types::AccountId,
     actions: Vec<near_primitives::transaction::Action>,
@@ -91,7 +91,7 @@ impl PrepaidGasContext {
         scope: &<PrepaidGas as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions: previous_context.actions,
@@ -104,13 +104,13 @@ impl PrepaidGasContext {
 
 impl PrepaidGas {
     fn input_gas(
-        _context: &FunctionCallActionContext,
+        context: &FunctionCallActionContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
         eprintln!();
         let gas: u64 = loop {
             match crate::common::NearGas::from_str(
                 &Text::new("Enter gas for function call")
-                    .with_initial_value("100 TeraGas")
+                    .with_initial_value(context.global_context.0.cli.function_call_gas())
                     .prompt()?,
             ) {
                 Ok(input_gas) => {
@@ -159,7 +159,7 @@ impl DepositContext {
         let mut actions = previous_context.actions;
         actions.push(action);
         Ok(Self(super::super::super::ConstructTransactionContext {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             signer_account_id: previous_context.signer_account_id,
             receiver_account_id: previous_context.receiver_account_id,
             actions,
@@ -175,14 +175,14 @@ impl From<DepositContext> for super::super::super::ConstructTransactionContext {
 
 impl Deposit {
     fn input_deposit(
-        _context: &PrepaidGasContext,
+        context: &PrepaidGasContext,
     ) -> color_eyre::eyre::Result<Option<crate::common::NearBalance>> {
         eprintln!();
         match crate::common::NearBalance::from_str(
             &Text::new(
                 "Enter deposit for a function call (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
-            .with_initial_value("0 NEAR")
+            .with_initial_value(context.global_context.0.cli.function_call_deposit())
             .prompt()?,
         ) {
             Ok(deposit) => Ok(Some(deposit)),
@@ -8,6 +8,7 @@ mod skip_action;
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = ConstructTransactionContext)]
 pub struct ConstructTransaction {
+    #[interactive_clap(skip_default_input_arg)]
     /// What is the sender account ID?
     sender_account_id: crate::types::account_id::AccountId,
     /// What is the receiver account ID?
@@ -16,9 +17,17 @@ pub struct ConstructTransaction {
     next_actions: self::add_action_1::NextAction,
 }
 
+impl ConstructTransaction {
+    fn input_sender_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id(&context.0, "What is the sender account ID?")
+    }
+}
+
 #[derive(Clone)]
 pub struct ConstructTransactionContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub signer_account_id: near_primitives::types::AccountId,
     pub receiver_account_id: near_primitives::types::AccountId,
     pub actions: Vec<near_primitives::transaction::Action>,
@@ -30,7 +39,7 @@ impl ConstructTransactionContext {
         scope: &<ConstructTransaction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         Ok(Self {
-            config: previous_context.0,
+            global_context: previous_context,
             signer_account_id: scope.sender_account_id.clone().into(),
             receiver_account_id: scope.receiver_account_id.clone().into(),
             actions: vec![],
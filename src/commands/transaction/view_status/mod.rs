@@ -25,12 +25,13 @@ impl TransactionInfoContext {
     ) -> color_eyre::eyre::Result<Self> {
         let transaction_hash = scope.transaction_hash;
         let signer_account_id = scope.signer_account_id.clone();
+        let output_format = previous_context.1.output_format.clone();
 
         let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
             std::sync::Arc::new({
                 move |network_config| {
                     let query_view_transaction_status = network_config
-                        .json_rpc_client()
+                        .json_rpc_client()?
                         .blocking_call(near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::RpcTransactionStatusRequest {
                             transaction_info: near_jsonrpc_client::methods::EXPERIMENTAL_tx_status::TransactionInfo::TransactionId {
                                 hash: transaction_hash.into(),
@@ -38,13 +39,18 @@ impl TransactionInfoContext {
                             }
                         })
                         .wrap_err("Failed to fetch query for view transaction")?;
+                    if let crate::common::OutputFormat::Json = output_format {
+                        return crate::common::print_json_output(&serde_json::to_value(
+                            &query_view_transaction_status,
+                        )?);
+                    }
                     eprintln!("Transaction status: {:#?}", query_view_transaction_status);
                     Ok(())
                 }
             });
 
         Ok(Self(crate::network::NetworkContext {
-            config: previous_context.0,
+            global_context: previous_context,
             on_after_getting_network_callback,
         }))
     }
@@ -22,8 +22,11 @@ use strum::IntoEnumIterator;
     strum_macros::EnumString,
     strum_macros::EnumVariantNames,
     smart_default::SmartDefault,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 #[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum OutputFormat {
     #[default]
     Plaintext,
@@ -39,6 +42,471 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+impl interactive_clap::ToCli for OutputFormat {
+    type CliVariant = OutputFormat;
+}
+
+/// The top-level options which apply to every command, carried in [`crate::GlobalContext`]
+/// next to the config.
+#[derive(Debug, Clone, Default)]
+pub struct GlobalOptions {
+    /// The output format selected with `--output-format`: view commands and transaction results
+    /// print a JSON document to stdout when it is `json`
+    pub output_format: OutputFormat,
+    /// Scripting mode (`--scripting` or `--no-interactive`): near CLI never prompts, every missing
+    /// argument is an error, and connectivity failures are not retried
+    pub scripting_mode: bool,
+    /// The path of config.toml: `--config <path>`, `NEAR_CLI_CONFIG`, or the default location in
+    /// the config dir of the user
+    pub config_path: Option<std::path::PathBuf>,
+}
+
+impl GlobalOptions {
+    /// Returns an error naming the missing argument instead of prompting for it in scripting mode.
+    pub fn ensure_interactive_mode(&self, argument: &str) -> CliResult {
+        if self.scripting_mode {
+            return Err(MissingArgumentError {
+                argument: argument.to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+pub fn print_json_output(value: &serde_json::Value) -> CliResult {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+pub const SCRIPTING_MODE_FLAGS: [&str; 2] = ["--scripting", "--no-interactive"];
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "the required argument {argument} was not provided (prompts are disabled in scripting mode)"
+)]
+pub struct MissingArgumentError {
+    pub argument: String,
+}
+
+/// Splits the scripting mode flags off the command line arguments, so they can be passed anywhere
+/// in the command.
+pub fn extract_scripting_mode_flags(
+    args: impl Iterator<Item = std::ffi::OsString>,
+) -> (bool, Vec<std::ffi::OsString>) {
+    let mut scripting_mode = false;
+    let mut other_args = vec![];
+    for arg in args {
+        if SCRIPTING_MODE_FLAGS.iter().any(|flag| arg == *flag) {
+            scripting_mode = true;
+        } else {
+            other_args.push(arg);
+        }
+    }
+    (scripting_mode, other_args)
+}
+
+/// Makes every positional argument and every subcommand required, so in scripting mode clap
+/// reports the exact missing argument instead of near CLI prompting for it. The network name and
+/// the signing method stay optional: the selected connection provides the network, and whether the
+/// network in use has a default signing method is checked once the network is selected.
+pub fn scripting_mode_command(
+    mut command: clap::Command,
+    config: &crate::config::Config,
+) -> clap::Command {
+    // A missing connection selected in the environment is reported once the network is selected
+    let has_selected_connection = config.selected_network_config().is_some()
+        || config.missing_overridden_connection().is_some();
+    let positional_arg_ids = command
+        .get_positionals()
+        .map(|arg| arg.get_id().as_str().to_owned())
+        .filter(|arg_id| !(has_selected_connection && arg_id == "network_name"))
+        .collect::<Vec<_>>();
+    for arg_id in positional_arg_ids {
+        command = command.mut_arg(arg_id, |arg| arg.required(true));
+    }
+    let subcommand_names = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_owned())
+        .collect::<Vec<_>>();
+    // The signing method can be omitted when the network in use has a default one, which is only
+    // known once the network is selected, so it is checked there (see `NetworkForTransactionArgs`)
+    let is_signing_method = subcommand_names
+        .iter()
+        .any(|subcommand_name| subcommand_name == "sign-with-keychain");
+    if !subcommand_names.is_empty() && !is_signing_method {
+        command = command.subcommand_required(true);
+    }
+    for subcommand_name in subcommand_names {
+        command = command.mut_subcommand(subcommand_name, |subcommand| {
+            scripting_mode_command(subcommand, config)
+        });
+    }
+    command
+}
+
+/// Error categories reported to scripts with distinct exit codes (and as the `category` field of
+/// the JSON error object).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Missing or invalid arguments, or an interrupted prompt
+    UserInput,
+    /// The RPC server is unreachable or responded with an unexpected HTTP status
+    RpcTransport,
+    /// The RPC server processed the request and returned an error
+    RpcHandler,
+    /// The transaction was rejected before the execution
+    TransactionInvalid,
+    /// The transaction was executed, but one of its actions failed
+    ActionFailed,
+    /// The access key could not be found or read from the local key storage
+    LocalKeyStorage,
+    /// The watched value met the `--exit-if` condition
+    ThresholdMet,
+}
+
+impl ErrorCategory {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::UserInput => 2,
+            Self::RpcTransport => 3,
+            Self::RpcHandler => 4,
+            Self::TransactionInvalid => 5,
+            Self::ActionFailed => 6,
+            Self::LocalKeyStorage => 7,
+            Self::ThresholdMet => 8,
+        }
+    }
+}
+
+/// Exit code of the errors which do not belong to any [`ErrorCategory`].
+pub const UNCATEGORIZED_ERROR_EXIT_CODE: i32 = 1;
+
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{message}")]
+pub struct CliError {
+    pub category: ErrorCategory,
+    pub message: String,
+    /// The nearcore error variant, e.g. `NotEnoughBalance` or `AccountDoesNotExist`
+    pub variant: Option<String>,
+    /// The nearcore error serialized as it is returned by the RPC server
+    pub details: serde_json::Value,
+    pub transaction_hash: Option<String>,
+}
+
+impl CliError {
+    pub fn new(category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            message: message.into(),
+            variant: None,
+            details: serde_json::Value::Null,
+            transaction_hash: None,
+        }
+    }
+
+    pub fn with_nearcore_error(
+        mut self,
+        variant: &impl NearcoreErrorVariant,
+        details: &impl serde::Serialize,
+    ) -> Self {
+        self.variant = Some(variant.variant().to_string());
+        self.details = serde_json::to_value(details).unwrap_or(serde_json::Value::Null);
+        self
+    }
+
+    pub fn with_transaction_hash(mut self, transaction_hash: &CryptoHash) -> Self {
+        self.transaction_hash = Some(transaction_hash.to_string());
+        self
+    }
+}
+
+/// The name of the innermost variant of a nearcore error, e.g. `InvalidNonce` of
+/// `InvalidTxError::InvalidAccessKeyError(InvalidAccessKeyError::InvalidNonce { .. })`, reported
+/// as the `variant` of the JSON error object.
+pub trait NearcoreErrorVariant {
+    fn variant(&self) -> &'static str;
+}
+
+impl NearcoreErrorVariant for near_primitives::errors::InvalidTxError {
+    fn variant(&self) -> &'static str {
+        use near_primitives::errors::InvalidTxError;
+        match self {
+            InvalidTxError::InvalidAccessKeyError(error) => error.variant(),
+            InvalidTxError::ActionsValidation(error) => error.variant(),
+            InvalidTxError::InvalidSignerId { .. } => "InvalidSignerId",
+            InvalidTxError::SignerDoesNotExist { .. } => "SignerDoesNotExist",
+            InvalidTxError::InvalidNonce { .. } => "InvalidNonce",
+            InvalidTxError::NonceTooLarge { .. } => "NonceTooLarge",
+            InvalidTxError::InvalidReceiverId { .. } => "InvalidReceiverId",
+            InvalidTxError::InvalidSignature => "InvalidSignature",
+            InvalidTxError::NotEnoughBalance { .. } => "NotEnoughBalance",
+            InvalidTxError::LackBalanceForState { .. } => "LackBalanceForState",
+            InvalidTxError::CostOverflow => "CostOverflow",
+            InvalidTxError::InvalidChain => "InvalidChain",
+            InvalidTxError::Expired => "Expired",
+            InvalidTxError::TransactionSizeExceeded { .. } => "TransactionSizeExceeded",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_primitives::errors::InvalidAccessKeyError {
+    fn variant(&self) -> &'static str {
+        use near_primitives::errors::InvalidAccessKeyError;
+        match self {
+            InvalidAccessKeyError::AccessKeyNotFound { .. } => "AccessKeyNotFound",
+            InvalidAccessKeyError::ReceiverMismatch { .. } => "ReceiverMismatch",
+            InvalidAccessKeyError::MethodNameMismatch { .. } => "MethodNameMismatch",
+            InvalidAccessKeyError::RequiresFullAccess => "RequiresFullAccess",
+            InvalidAccessKeyError::NotEnoughAllowance { .. } => "NotEnoughAllowance",
+            InvalidAccessKeyError::DepositWithFunctionCall => "DepositWithFunctionCall",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_primitives::errors::ActionsValidationError {
+    fn variant(&self) -> &'static str {
+        use near_primitives::errors::ActionsValidationError;
+        match self {
+            ActionsValidationError::DeleteActionMustBeFinal => "DeleteActionMustBeFinal",
+            ActionsValidationError::TotalPrepaidGasExceeded { .. } => "TotalPrepaidGasExceeded",
+            ActionsValidationError::TotalNumberOfActionsExceeded { .. } => {
+                "TotalNumberOfActionsExceeded"
+            }
+            ActionsValidationError::AddKeyMethodNamesNumberOfBytesExceeded { .. } => {
+                "AddKeyMethodNamesNumberOfBytesExceeded"
+            }
+            ActionsValidationError::AddKeyMethodNameLengthExceeded { .. } => {
+                "AddKeyMethodNameLengthExceeded"
+            }
+            ActionsValidationError::IntegerOverflow => "IntegerOverflow",
+            ActionsValidationError::InvalidAccountId { .. } => "InvalidAccountId",
+            ActionsValidationError::ContractSizeExceeded { .. } => "ContractSizeExceeded",
+            ActionsValidationError::FunctionCallMethodNameLengthExceeded { .. } => {
+                "FunctionCallMethodNameLengthExceeded"
+            }
+            ActionsValidationError::FunctionCallArgumentsLengthExceeded { .. } => {
+                "FunctionCallArgumentsLengthExceeded"
+            }
+            ActionsValidationError::UnsuitableStakingKey { .. } => "UnsuitableStakingKey",
+            ActionsValidationError::FunctionCallZeroAttachedGas => "FunctionCallZeroAttachedGas",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_primitives::errors::ReceiptValidationError {
+    fn variant(&self) -> &'static str {
+        use near_primitives::errors::ReceiptValidationError;
+        match self {
+            ReceiptValidationError::ActionsValidation(error) => error.variant(),
+            ReceiptValidationError::InvalidPredecessorId { .. } => "InvalidPredecessorId",
+            ReceiptValidationError::InvalidReceiverId { .. } => "InvalidReceiverId",
+            ReceiptValidationError::InvalidSignerId { .. } => "InvalidSignerId",
+            ReceiptValidationError::InvalidDataReceiverId { .. } => "InvalidDataReceiverId",
+            ReceiptValidationError::ReturnedValueLengthExceeded { .. } => {
+                "ReturnedValueLengthExceeded"
+            }
+            ReceiptValidationError::NumberInputDataDependenciesExceeded { .. } => {
+                "NumberInputDataDependenciesExceeded"
+            }
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_primitives::errors::ActionErrorKind {
+    fn variant(&self) -> &'static str {
+        use near_primitives::errors::ActionErrorKind;
+        match self {
+            ActionErrorKind::FunctionCallError(error) => error.variant(),
+            ActionErrorKind::NewReceiptValidationError(error) => error.variant(),
+            ActionErrorKind::AccountAlreadyExists { .. } => "AccountAlreadyExists",
+            ActionErrorKind::AccountDoesNotExist { .. } => "AccountDoesNotExist",
+            ActionErrorKind::CreateAccountOnlyByRegistrar { .. } => "CreateAccountOnlyByRegistrar",
+            ActionErrorKind::CreateAccountNotAllowed { .. } => "CreateAccountNotAllowed",
+            ActionErrorKind::ActorNoPermission { .. } => "ActorNoPermission",
+            ActionErrorKind::DeleteKeyDoesNotExist { .. } => "DeleteKeyDoesNotExist",
+            ActionErrorKind::AddKeyAlreadyExists { .. } => "AddKeyAlreadyExists",
+            ActionErrorKind::DeleteAccountStaking { .. } => "DeleteAccountStaking",
+            ActionErrorKind::LackBalanceForState { .. } => "LackBalanceForState",
+            ActionErrorKind::TriesToUnstake { .. } => "TriesToUnstake",
+            ActionErrorKind::TriesToStake { .. } => "TriesToStake",
+            ActionErrorKind::InsufficientStake { .. } => "InsufficientStake",
+            ActionErrorKind::OnlyImplicitAccountCreationAllowed { .. } => {
+                "OnlyImplicitAccountCreationAllowed"
+            }
+            ActionErrorKind::DeleteAccountWithLargeState { .. } => "DeleteAccountWithLargeState",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_vm_errors::FunctionCallErrorSer {
+    fn variant(&self) -> &'static str {
+        use near_vm_errors::{CompilationError, FunctionCallErrorSer};
+        match self {
+            FunctionCallErrorSer::CompilationError(CompilationError::PrepareError(_)) => {
+                "PrepareError"
+            }
+            FunctionCallErrorSer::CompilationError(error) => error.into(),
+            FunctionCallErrorSer::MethodResolveError(error) => error.into(),
+            FunctionCallErrorSer::WasmTrap(error) => error.into(),
+            FunctionCallErrorSer::HostError(error) => error.into(),
+            FunctionCallErrorSer::LinkError { .. } => "LinkError",
+            FunctionCallErrorSer::WasmUnknownError => "WasmUnknownError",
+            FunctionCallErrorSer::_EVMError => "_EVMError",
+            FunctionCallErrorSer::ExecutionError(_) => "ExecutionError",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_jsonrpc_primitives::types::query::RpcQueryError {
+    fn variant(&self) -> &'static str {
+        use near_jsonrpc_primitives::types::query::RpcQueryError;
+        match self {
+            RpcQueryError::NoSyncedBlocks => "NoSyncedBlocks",
+            RpcQueryError::UnavailableShard { .. } => "UnavailableShard",
+            RpcQueryError::GarbageCollectedBlock { .. } => "GarbageCollectedBlock",
+            RpcQueryError::UnknownBlock { .. } => "UnknownBlock",
+            RpcQueryError::InvalidAccount { .. } => "InvalidAccount",
+            RpcQueryError::UnknownAccount { .. } => "UnknownAccount",
+            RpcQueryError::NoContractCode { .. } => "NoContractCode",
+            RpcQueryError::TooLargeContractState { .. } => "TooLargeContractState",
+            RpcQueryError::UnknownAccessKey { .. } => "UnknownAccessKey",
+            RpcQueryError::ContractExecutionError { .. } => "ContractExecutionError",
+            RpcQueryError::InternalError { .. } => "InternalError",
+        }
+    }
+}
+
+impl NearcoreErrorVariant
+    for near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError
+{
+    fn variant(&self) -> &'static str {
+        use near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError;
+        match self {
+            RpcTransactionError::InvalidTransaction { context } => context.variant(),
+            RpcTransactionError::DoesNotTrackShard => "DoesNotTrackShard",
+            RpcTransactionError::RequestRouted { .. } => "RequestRouted",
+            RpcTransactionError::UnknownTransaction { .. } => "UnknownTransaction",
+            RpcTransactionError::InternalError { .. } => "InternalError",
+            RpcTransactionError::TimeoutError => "TimeoutError",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_jsonrpc_primitives::types::blocks::RpcBlockError {
+    fn variant(&self) -> &'static str {
+        use near_jsonrpc_primitives::types::blocks::RpcBlockError;
+        match self {
+            RpcBlockError::UnknownBlock { .. } => "UnknownBlock",
+            RpcBlockError::NotSyncedYet => "NotSyncedYet",
+            RpcBlockError::InternalError { .. } => "InternalError",
+        }
+    }
+}
+
+impl NearcoreErrorVariant for near_jsonrpc_primitives::types::config::RpcProtocolConfigError {
+    fn variant(&self) -> &'static str {
+        use near_jsonrpc_primitives::types::config::RpcProtocolConfigError;
+        match self {
+            RpcProtocolConfigError::UnknownBlock { .. } => "UnknownBlock",
+            RpcProtocolConfigError::InternalError { .. } => "InternalError",
+        }
+    }
+}
+
+/// Connectivity problems are told apart from the errors returned by the RPC server.
+pub fn json_rpc_error_category<E>(
+    json_rpc_error: &near_jsonrpc_client::errors::JsonRpcError<E>,
+) -> ErrorCategory {
+    match json_rpc_error {
+        near_jsonrpc_client::errors::JsonRpcError::TransportError(_)
+        | near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(_),
+        ) => ErrorCategory::RpcTransport,
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(_) => ErrorCategory::RpcHandler,
+    }
+}
+
+fn json_rpc_cli_error<E>(err: &color_eyre::Report) -> Option<CliError>
+where
+    E: NearcoreErrorVariant
+        + std::fmt::Debug
+        + std::fmt::Display
+        + serde::Serialize
+        + Send
+        + Sync
+        + 'static,
+{
+    let json_rpc_error = err.downcast_ref::<near_jsonrpc_client::errors::JsonRpcError<E>>()?;
+    let cli_error = CliError::new(
+        json_rpc_error_category(json_rpc_error),
+        format!("{:#}", err),
+    );
+    Some(match json_rpc_error {
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(handler_error),
+        ) => cli_error.with_nearcore_error(handler_error, handler_error),
+        _ => cli_error,
+    })
+}
+
+/// Finds the category of the error, so that scripts can tell apart user mistakes, connectivity
+/// problems and on-chain failures.
+pub fn categorize_error(err: &color_eyre::Report) -> Option<CliError> {
+    if let Some(cli_error) = err.downcast_ref::<CliError>() {
+        return Some(cli_error.clone());
+    }
+    if err.downcast_ref::<MissingArgumentError>().is_some()
+        || err.downcast_ref::<inquire::error::InquireError>().is_some()
+    {
+        return Some(CliError::new(
+            ErrorCategory::UserInput,
+            format!("{:#}", err),
+        ));
+    }
+    json_rpc_cli_error::<near_jsonrpc_primitives::types::query::RpcQueryError>(err)
+        .or_else(|| {
+            json_rpc_cli_error::<
+                near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError,
+            >(err)
+        })
+        .or_else(|| {
+            json_rpc_cli_error::<near_jsonrpc_primitives::types::blocks::RpcBlockError>(err)
+        })
+        .or_else(|| {
+            json_rpc_cli_error::<near_jsonrpc_primitives::types::config::RpcProtocolConfigError>(
+                err,
+            )
+        })
+}
+
+/// Prints the error (as a JSON error object to stdout with `--output-format json`) and returns
+/// the exit code of its category.
+pub fn report_error(err: &color_eyre::Report, output_format: &OutputFormat) -> i32 {
+    let cli_error = categorize_error(err);
+    if let OutputFormat::Json = output_format {
+        let error_object = serde_json::json!({
+            "error": {
+                "category": cli_error.as_ref().map_or("other", |cli_error| cli_error.category.into()),
+                "exit_code": cli_error.as_ref().map_or(UNCATEGORIZED_ERROR_EXIT_CODE, |cli_error| cli_error.category.exit_code()),
+                "message": format!("{:#}", err),
+                "variant": cli_error.as_ref().and_then(|cli_error| cli_error.variant.clone()),
+                "details": cli_error.as_ref().map_or(serde_json::Value::Null, |cli_error| cli_error.details.clone()),
+                "transaction_hash": cli_error.as_ref().and_then(|cli_error| cli_error.transaction_hash.clone()),
+            }
+        });
+        if print_json_output(&error_object).is_err() {
+            eprintln!("Error: {:?}", err);
+        }
+    } else {
+        eprintln!("Error: {:?}", err);
+    }
+    cli_error.map_or(UNCATEGORIZED_ERROR_EXIT_CODE, |cli_error| {
+        cli_error.category.exit_code()
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct SignedTransactionAsBase64 {
     pub inner: near_primitives::transaction::SignedTransaction,
@@ -114,6 +582,46 @@ impl std::fmt::Display for BlockHashAsBase58 {
 
 const ONE_NEAR: u128 = 10u128.pow(24);
 
+/// Converts a decimal amount (e.g. `1.5`) to the smallest units of a token with `decimals`
+/// decimal places.
+pub fn parse_decimal_amount(amount: &str, decimals: u32) -> Result<u128, String> {
+    let amount = amount.trim();
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("<{}> is not a decimal number", amount));
+    }
+    if fraction.len() > decimals as usize {
+        return Err(format!(
+            "<{}> has more than {} decimal places",
+            amount, decimals
+        ));
+    }
+    let too_large = || format!("<{}> is too large", amount);
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<u128>().map_err(|_| too_large())?
+    };
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        10u128
+            .checked_pow(decimals - fraction.len() as u32)
+            .and_then(|scale| fraction.parse::<u128>().ok()?.checked_mul(scale))
+            .ok_or_else(too_large)?
+    };
+    10u128
+        .checked_pow(decimals)
+        .and_then(|one| integer.checked_mul(one))
+        .and_then(|amount| amount.checked_add(fraction))
+        .ok_or_else(too_large)
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd)]
 pub struct NearBalance {
     pub yoctonear_amount: u128,
@@ -362,6 +870,20 @@ impl std::fmt::Display for AccountTransferAllowance {
 }
 
 impl AccountTransferAllowance {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "account_id": self.account_id,
+            "transfer_allowance": self.transfer_allowance().to_yoctonear().to_string(),
+            "balance": self.account_liquid_balance.to_yoctonear().to_string(),
+            "locked_for_storage": self.liquid_storage_stake().to_yoctonear().to_string(),
+            "pessimistic_transaction_fee": self.pessimistic_transaction_fee.to_yoctonear().to_string(),
+        })
+    }
+
+    pub fn balance(&self) -> NearBalance {
+        self.account_liquid_balance.clone()
+    }
+
     pub fn liquid_storage_stake(&self) -> NearBalance {
         NearBalance::from_yoctonear(
             self.storage_stake
@@ -383,23 +905,44 @@ pub fn get_account_transfer_allowance(
     network_config: crate::config::NetworkConfig,
     account_id: near_primitives::types::AccountId,
     block_reference: BlockReference,
+    scripting_mode: bool,
 ) -> color_eyre::eyre::Result<AccountTransferAllowance> {
-    let account_view = if let Ok(account_view) =
-        get_account_state(network_config.clone(), account_id.clone(), block_reference)
-    {
-        account_view
-    } else {
-        return Ok(AccountTransferAllowance {
-            account_id,
-            account_liquid_balance: NearBalance::from_yoctonear(0),
-            account_locked_balance: NearBalance::from_yoctonear(0),
-            storage_stake: NearBalance::from_yoctonear(0),
-            pessimistic_transaction_fee: NearBalance::from_yoctonear(0),
-        });
+    let account_view = match get_account_state(
+        network_config.clone(),
+        account_id.clone(),
+        block_reference,
+        scripting_mode,
+    ) {
+        Ok(account_view) => account_view,
+        Err(err)
+            if scripting_mode
+                && !matches!(
+                    err,
+                    near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                        near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                            near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                        ),
+                    )
+                ) =>
+        {
+            return Err(color_eyre::Report::new(err).wrap_err(format!(
+                "Failed to fetch the balance of <{}> on <{}> network",
+                account_id, network_config.network_name
+            )))
+        }
+        Err(_) => {
+            return Ok(AccountTransferAllowance {
+                account_id,
+                account_liquid_balance: NearBalance::from_yoctonear(0),
+                account_locked_balance: NearBalance::from_yoctonear(0),
+                storage_stake: NearBalance::from_yoctonear(0),
+                pessimistic_transaction_fee: NearBalance::from_yoctonear(0),
+            })
+        }
     };
     let storage_amount_per_byte = tokio::runtime::Runtime::new()
         .unwrap()
-        .block_on(network_config.json_rpc_client().call(
+        .block_on(network_config.json_rpc_client()?.call(
             near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
             },
@@ -422,10 +965,25 @@ pub fn get_account_transfer_allowance(
     })
 }
 
+/// Reports the HTTP client of the connection which could not be set up (e.g. an unreadable root
+/// certificate) as a transport error, for the functions which return the JSON RPC errors.
+fn json_rpc_client_setup_error<E>(
+    err: color_eyre::Report,
+) -> near_jsonrpc_client::errors::JsonRpcError<E> {
+    near_jsonrpc_client::errors::JsonRpcError::TransportError(
+        near_jsonrpc_client::errors::RpcTransportError::SendError(
+            near_jsonrpc_client::errors::JsonRpcTransportSendError::PayloadSerializeError(
+                std::io::Error::new(std::io::ErrorKind::Other, format!("{:#}", err)),
+            ),
+        ),
+    )
+}
+
 pub fn verify_account_access_key(
     account_id: near_primitives::types::AccountId,
     public_key: near_crypto::PublicKey,
     network_config: crate::config::NetworkConfig,
+    scripting_mode: bool,
 ) -> color_eyre::eyre::Result<
     near_primitives::views::AccessKeyView,
     near_jsonrpc_client::errors::JsonRpcError<near_jsonrpc_primitives::types::query::RpcQueryError>,
@@ -433,6 +991,7 @@ pub fn verify_account_access_key(
     loop {
         match network_config
             .json_rpc_client()
+            .map_err(json_rpc_client_setup_error)?
             .blocking_call_view_access_key(
                 &account_id,
                 &public_key,
@@ -466,7 +1025,7 @@ pub fn verify_account_access_key(
                 eprintln!("\nAccount information ({}) cannot be fetched on <{}> network due to connectivity issue.",
                     account_id, network_config.network_name
                 );
-                if !need_check_account() {
+                if !need_check_account(scripting_mode) {
                     return Err(near_jsonrpc_client::errors::JsonRpcError::TransportError(
                         err,
                     ));
@@ -476,7 +1035,7 @@ pub fn verify_account_access_key(
                 eprintln!("\nAccount information ({}) cannot be fetched on <{}> network due to server error.",
                     account_id, network_config.network_name
                 );
-                if !need_check_account() {
+                if !need_check_account(scripting_mode) {
                     return Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(err));
                 }
             }
@@ -484,28 +1043,43 @@ pub fn verify_account_access_key(
     }
 }
 
+/// Whether the account exists on any of the networks. An account that could not be fetched counts
+/// as missing, except in scripting mode, where the RPC error is returned instead.
 pub fn is_account_exist(
     networks: &linked_hash_map::LinkedHashMap<String, crate::config::NetworkConfig>,
     account_id: near_primitives::types::AccountId,
-) -> bool {
+    scripting_mode: bool,
+) -> color_eyre::eyre::Result<bool> {
     for network in networks {
-        if get_account_state(
+        match get_account_state(
             network.1.clone(),
             account_id.clone(),
             near_primitives::types::Finality::Final.into(),
-        )
-        .is_ok()
-        {
-            return true;
+            scripting_mode,
+        ) {
+            Ok(_) => return Ok(true),
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                ),
+            )) => {}
+            Err(err) if scripting_mode => {
+                return Err(color_eyre::Report::new(err).wrap_err(format!(
+                    "Failed to check whether the account <{}> exists on <{}> network",
+                    account_id, network.1.network_name
+                )))
+            }
+            Err(_) => {}
         }
     }
-    false
+    Ok(false)
 }
 
 pub fn get_account_state(
     network_config: crate::config::NetworkConfig,
     account_id: near_primitives::types::AccountId,
     block_reference: BlockReference,
+    scripting_mode: bool,
 ) -> color_eyre::eyre::Result<
     near_primitives::views::AccountView,
     near_jsonrpc_client::errors::JsonRpcError<near_jsonrpc_primitives::types::query::RpcQueryError>,
@@ -513,6 +1087,7 @@ pub fn get_account_state(
     loop {
         let query_view_method_response = network_config
             .json_rpc_client()
+            .map_err(json_rpc_client_setup_error)?
             .blocking_call_view_account(&account_id.clone(), block_reference.clone());
         match query_view_method_response {
             Ok(rpc_query_response) => {
@@ -544,7 +1119,7 @@ pub fn get_account_state(
                 eprintln!("\nAccount information ({}) cannot be fetched on <{}> network due to connectivity issue.",
                     account_id, network_config.network_name
                 );
-                if !need_check_account() {
+                if !need_check_account(scripting_mode) {
                     return Err(near_jsonrpc_client::errors::JsonRpcError::TransportError(
                         err,
                     ));
@@ -554,7 +1129,7 @@ pub fn get_account_state(
                 eprintln!("\nAccount information ({}) cannot be fetched on <{}> network due to server error.",
                     account_id, network_config.network_name
                 );
-                if !need_check_account() {
+                if !need_check_account(scripting_mode) {
                     return Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(err));
                 }
             }
@@ -562,7 +1137,10 @@ pub fn get_account_state(
     }
 }
 
-fn need_check_account() -> bool {
+fn need_check_account(scripting_mode: bool) -> bool {
+    if scripting_mode {
+        return false;
+    }
     #[derive(strum_macros::Display, PartialEq)]
     enum ConfirmOptions {
         #[strum(to_string = "Yes, I want to check the account again.")]
@@ -590,11 +1168,34 @@ pub struct KeyPairProperties {
     pub secret_keypair_str: String,
 }
 
+/// Returns the BIP39 seed of the seed phrase, after normalizing its case and whitespace. The
+/// wordlist language is detected from the words unless it is given, and the passphrase (the
+/// "25th word") is empty unless it is given.
+pub fn get_master_seed_from_seed_phrase(
+    master_seed_phrase: &str,
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    seed_phrase_passphrase: Option<&str>,
+) -> color_eyre::eyre::Result<[u8; 64]> {
+    let master_seed_phrase = &crate::types::seed_phrase::normalize_seed_phrase(master_seed_phrase);
+    let mnemonic = match seed_phrase_language {
+        Some(language) => bip39::Mnemonic::parse_in(language.into(), master_seed_phrase),
+        None => bip39::Mnemonic::parse(master_seed_phrase),
+    }
+    .map_err(|err| color_eyre::Report::msg(format!("The seed phrase is not valid: {}", err)))?;
+    Ok(mnemonic.to_seed(seed_phrase_passphrase.unwrap_or_default()))
+}
+
 pub fn get_key_pair_properties_from_seed_phrase(
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     master_seed_phrase: String,
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    seed_phrase_passphrase: Option<&str>,
 ) -> color_eyre::eyre::Result<KeyPairProperties> {
-    let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed("");
+    let master_seed = get_master_seed_from_seed_phrase(
+        &master_seed_phrase,
+        seed_phrase_language,
+        seed_phrase_passphrase,
+    )?;
     let derived_private_key = slip10::derive_key_from_path(
         &master_seed,
         slip10::Curve::Ed25519,
@@ -636,8 +1237,14 @@ pub fn get_key_pair_properties_from_seed_phrase(
 pub fn get_public_key_from_seed_phrase(
     seed_phrase_hd_path: slip10::BIP32Path,
     master_seed_phrase: &str,
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    seed_phrase_passphrase: Option<&str>,
 ) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
-    let master_seed = bip39::Mnemonic::parse(master_seed_phrase)?.to_seed("");
+    let master_seed = get_master_seed_from_seed_phrase(
+        master_seed_phrase,
+        seed_phrase_language,
+        seed_phrase_passphrase,
+    )?;
     let derived_private_key =
         slip10::derive_key_from_path(&master_seed, slip10::Curve::Ed25519, &seed_phrase_hd_path)
             .map_err(|err| {
@@ -661,17 +1268,29 @@ pub fn get_public_key_from_seed_phrase(
 pub fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
     let generate_keypair: crate::utils_command::generate_keypair_subcommand::CliGenerateKeypair =
         crate::utils_command::generate_keypair_subcommand::CliGenerateKeypair::default();
+    let seed_phrase_passphrase = generate_keypair.seed_phrase_passphrase.as_deref();
     let (master_seed_phrase, master_seed) =
         if let Some(master_seed_phrase) = generate_keypair.master_seed_phrase.as_deref() {
             (
                 master_seed_phrase.to_owned(),
-                bip39::Mnemonic::parse(master_seed_phrase)?.to_seed(""),
+                get_master_seed_from_seed_phrase(
+                    master_seed_phrase,
+                    generate_keypair.seed_phrase_language,
+                    seed_phrase_passphrase,
+                )?,
             )
         } else {
-            let mnemonic =
-                bip39::Mnemonic::generate(generate_keypair.new_master_seed_phrase_words_count)?;
+            let mnemonic = bip39::Mnemonic::generate_in(
+                generate_keypair
+                    .seed_phrase_language
+                    .map_or(bip39::Language::English, Into::into),
+                generate_keypair.new_master_seed_phrase_words_count,
+            )?;
             let master_seed_phrase = mnemonic.word_iter().collect::<Vec<&str>>().join(" ");
-            (master_seed_phrase, mnemonic.to_seed(""))
+            (
+                master_seed_phrase,
+                mnemonic.to_seed(seed_phrase_passphrase.unwrap_or_default()),
+            )
         };
 
     let derived_private_key = slip10::derive_key_from_path(
@@ -894,55 +1513,83 @@ fn print_value_successful_transaction(
     }
 }
 
+/// Returns `Ok` when the transaction is worth sending again. In scripting mode nothing is resent,
+/// so a script gets an error instead of waiting for an RPC server that may never recover.
 pub fn rpc_transaction_error(
     err: near_jsonrpc_client::errors::JsonRpcError<
         near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError,
     >,
+    scripting_mode: bool,
 ) -> CliResult {
     match &err {
         near_jsonrpc_client::errors::JsonRpcError::TransportError(_rpc_transport_error) => {
+            if scripting_mode {
+                return Err(CliError::new(ErrorCategory::RpcTransport, format!("Transport error transaction: {}", err)).into());
+            }
             eprintln!("Transport error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
         }
         near_jsonrpc_client::errors::JsonRpcError::ServerError(rpc_server_error) => match rpc_server_error {
             near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(rpc_transaction_error) => match rpc_transaction_error {
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::TimeoutError => {
+                    if scripting_mode {
+                        return Err(CliError::new(ErrorCategory::RpcHandler, format!("Timeout error transaction: {}", err))
+                            .with_nearcore_error(rpc_transaction_error, rpc_transaction_error)
+                            .into());
+                    }
                     eprintln!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
                 }
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::InvalidTransaction { context } => {
-                    let err_invalid_transaction = crate::common::handler_invalid_tx_error(context);
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("{}", err_invalid_transaction));
+                    return Err(CliError::new(ErrorCategory::TransactionInvalid, crate::common::handler_invalid_tx_error(context))
+                        .with_nearcore_error(context, context)
+                        .into());
                 }
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::DoesNotTrackShard => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("RPC Server Error: {}", err));
+                    return Err(CliError::new(ErrorCategory::RpcHandler, format!("RPC Server Error: {}", err))
+                        .with_nearcore_error(rpc_transaction_error, rpc_transaction_error)
+                        .into());
                 }
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::RequestRouted{transaction_hash} => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("RPC Server Error for transaction with hash {}\n{}", transaction_hash, err));
+                    return Err(CliError::new(ErrorCategory::RpcHandler, format!("RPC Server Error for transaction with hash {}\n{}", transaction_hash, err))
+                        .with_nearcore_error(rpc_transaction_error, rpc_transaction_error)
+                        .with_transaction_hash(transaction_hash)
+                        .into());
                 }
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::UnknownTransaction{requested_transaction_hash} => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("RPC Server Error for transaction with hash {}\n{}", requested_transaction_hash, err));
+                    return Err(CliError::new(ErrorCategory::RpcHandler, format!("RPC Server Error for transaction with hash {}\n{}", requested_transaction_hash, err))
+                        .with_nearcore_error(rpc_transaction_error, rpc_transaction_error)
+                        .with_transaction_hash(requested_transaction_hash)
+                        .into());
                 }
                 near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::InternalError{debug_info} => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("RPC Server Error: {}", debug_info));
+                    return Err(CliError::new(ErrorCategory::RpcHandler, format!("RPC Server Error: {}", debug_info))
+                        .with_nearcore_error(rpc_transaction_error, rpc_transaction_error)
+                        .into());
                 }
             }
             near_jsonrpc_client::errors::JsonRpcServerError::RequestValidationError(rpc_request_validation_error) => {
-                return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("Incompatible request with the server: {:#?}",  rpc_request_validation_error));
+                return Err(CliError::new(ErrorCategory::RpcHandler, format!("Incompatible request with the server: {:#?}",  rpc_request_validation_error)).into());
             }
             near_jsonrpc_client::errors::JsonRpcServerError::InternalError{ info } => {
+                if scripting_mode {
+                    return Err(CliError::new(ErrorCategory::RpcTransport, format!("Internal server error: {}", info.clone().unwrap_or_default())).into());
+                }
                 eprintln!("Internal server error: {}.\nPlease wait. The next try to send this transaction is happening right now ...", info.clone().unwrap_or_default());
             }
             near_jsonrpc_client::errors::JsonRpcServerError::NonContextualError(rpc_error) => {
-                return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("Unexpected response: {}", rpc_error));
+                return Err(CliError::new(ErrorCategory::RpcHandler, format!("Unexpected response: {}", rpc_error)).into());
             }
             near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(json_rpc_server_response_status_error) => match json_rpc_server_response_status_error {
                 near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::Unauthorized => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("JSON RPC server requires authentication. Please, authenticate near CLI with the JSON RPC server you use."));
+                    return Err(CliError::new(ErrorCategory::RpcTransport, "JSON RPC server requires authentication. Please, authenticate near CLI with the JSON RPC server you use.").into());
                 }
                 near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests => {
+                    if scripting_mode {
+                        return Err(CliError::new(ErrorCategory::RpcTransport, "JSON RPC server is currently busy (too many requests)").into());
+                    }
                     eprintln!("JSON RPC server is currently busy.\nPlease wait. The next try to send this transaction is happening right now ...");
                 }
                 near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::Unexpected{status} => {
-                    return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!("JSON RPC server responded with an unexpected status code: {}", status));
+                    return Err(CliError::new(ErrorCategory::RpcTransport, format!("JSON RPC server responded with an unexpected status code: {}", status)).into());
                 }
             }
         }
@@ -950,14 +1597,14 @@ pub fn rpc_transaction_error(
     Ok(())
 }
 
-pub fn print_action_error(action_error: &near_primitives::errors::ActionError) {
+pub fn action_error_message(action_error: &near_primitives::errors::ActionError) -> String {
     match &action_error.kind {
         near_primitives::errors::ActionErrorKind::AccountAlreadyExists { account_id } => {
-            eprintln!("Error: Create Account action tries to create an account with account ID <{}> which already exists in the storage.", account_id)
+            format!("Create Account action tries to create an account with account ID <{}> which already exists in the storage.", account_id)
         }
         near_primitives::errors::ActionErrorKind::AccountDoesNotExist { account_id } => {
-            eprintln!(
-                "Error: TX receiver ID <{}> doesn't exist (but action is not \"Create Account\").",
+            format!(
+                "TX receiver ID <{}> doesn't exist (but action is not \"Create Account\").",
                 account_id
             )
         }
@@ -966,26 +1613,26 @@ pub fn print_action_error(action_error: &near_primitives::errors::ActionError) {
             registrar_account_id: _,
             predecessor_id: _,
         } => {
-            eprintln!("Error: A top-level account ID can only be created by registrar.")
+            "A top-level account ID can only be created by registrar.".to_string()
         }
         near_primitives::errors::ActionErrorKind::CreateAccountNotAllowed {
             account_id,
             predecessor_id,
         } => {
-            eprintln!("Error: A newly created account <{}> must be under a namespace of the creator account <{}>.", account_id, predecessor_id)
+            format!("A newly created account <{}> must be under a namespace of the creator account <{}>.", account_id, predecessor_id)
         }
         near_primitives::errors::ActionErrorKind::ActorNoPermission {
             account_id: _,
             actor_id: _,
         } => {
-            eprintln!("Error: Administrative actions can be proceed only if sender=receiver or the first TX action is a \"Create Account\" action.")
+            "Administrative actions can be proceed only if sender=receiver or the first TX action is a \"Create Account\" action.".to_string()
         }
         near_primitives::errors::ActionErrorKind::DeleteKeyDoesNotExist {
             account_id,
             public_key,
         } => {
-            eprintln!(
-                "Error: Account <{}>  tries to remove an access key <{}> that doesn't exist.",
+            format!(
+                "Account <{}>  tries to remove an access key <{}> that doesn't exist.",
                 account_id, public_key
             )
         }
@@ -993,26 +1640,23 @@ pub fn print_action_error(action_error: &near_primitives::errors::ActionError) {
             account_id,
             public_key,
         } => {
-            eprintln!(
-                "Error: Public key <{}> is already used for an existing account ID <{}>.",
+            format!(
+                "Public key <{}> is already used for an existing account ID <{}>.",
                 public_key, account_id
             )
         }
         near_primitives::errors::ActionErrorKind::DeleteAccountStaking { account_id } => {
-            eprintln!(
-                "Error: Account <{}> is staking and can not be deleted",
-                account_id
-            )
+            format!("Account <{}> is staking and can not be deleted", account_id)
         }
         near_primitives::errors::ActionErrorKind::LackBalanceForState { account_id, amount } => {
-            eprintln!("Error: Receipt action can't be completed, because the remaining balance will not be enough to cover storage.\nAn account which needs balance: <{}>\nBalance required to complete the action: <{}>",
+            format!("Receipt action can't be completed, because the remaining balance will not be enough to cover storage.\nAn account which needs balance: <{}>\nBalance required to complete the action: <{}>",
                 account_id,
                 crate::common::NearBalance::from_yoctonear(*amount)
             )
         }
         near_primitives::errors::ActionErrorKind::TriesToUnstake { account_id } => {
-            eprintln!(
-                "Error: Account <{}> is not yet staked, but tries to unstake.",
+            format!(
+                "Account <{}> is not yet staked, but tries to unstake.",
                 account_id
             )
         }
@@ -1022,8 +1666,8 @@ pub fn print_action_error(action_error: &near_primitives::errors::ActionError) {
             locked: _,
             balance,
         } => {
-            eprintln!(
-                "Error: Account <{}> doesn't have enough balance ({}) to increase the stake ({}).",
+            format!(
+                "Account <{}> doesn't have enough balance ({}) to increase the stake ({}).",
                 account_id,
                 crate::common::NearBalance::from_yoctonear(*balance),
                 crate::common::NearBalance::from_yoctonear(*stake)
@@ -1034,28 +1678,28 @@ pub fn print_action_error(action_error: &near_primitives::errors::ActionError) {
             stake,
             minimum_stake,
         } => {
-            eprintln!(
-                "Error: Insufficient stake {}.\nThe minimum rate must be {}.",
+            format!(
+                "Insufficient stake {}.\nThe minimum rate must be {}.",
                 crate::common::NearBalance::from_yoctonear(*stake),
                 crate::common::NearBalance::from_yoctonear(*minimum_stake)
             )
         }
         near_primitives::errors::ActionErrorKind::FunctionCallError(function_call_error_ser) => {
-            eprintln!("Error: An error occurred during a `FunctionCall` Action, parameter is debug message.\n{:?}", function_call_error_ser)
+            format!("An error occurred during a `FunctionCall` Action, parameter is debug message.\n{:?}", function_call_error_ser)
         }
         near_primitives::errors::ActionErrorKind::NewReceiptValidationError(
             receipt_validation_error,
         ) => {
-            eprintln!("Error: Error occurs when a new `ActionReceipt` created by the `FunctionCall` action fails.\n{:?}", receipt_validation_error)
+            format!("Error occurs when a new `ActionReceipt` created by the `FunctionCall` action fails.\n{:?}", receipt_validation_error)
         }
         near_primitives::errors::ActionErrorKind::OnlyImplicitAccountCreationAllowed {
             account_id: _,
         } => {
-            eprintln!("Error: `CreateAccount` action is called on hex-characters account of length 64.\nSee implicit account creation NEP: https://github.com/nearprotocol/NEPs/pull/71")
+            "`CreateAccount` action is called on hex-characters account of length 64.\nSee implicit account creation NEP: https://github.com/nearprotocol/NEPs/pull/71".to_string()
         }
         near_primitives::errors::ActionErrorKind::DeleteAccountWithLargeState { account_id } => {
-            eprintln!(
-                "Error: Delete account <{}> whose state is large is temporarily banned.",
+            format!(
+                "Delete account <{}> whose state is large is temporarily banned.",
                 account_id
             )
         }
@@ -1069,19 +1713,19 @@ pub fn handler_invalid_tx_error(
         near_primitives::errors::InvalidTxError::InvalidAccessKeyError(invalid_access_key_error) => {
             match invalid_access_key_error {
                 near_primitives::errors::InvalidAccessKeyError::AccessKeyNotFound{account_id, public_key} => {
-                    format!("Error: Public key {} doesn't exist for the account <{}>.", public_key, account_id)
+                    format!("Public key {} doesn't exist for the account <{}>.", public_key, account_id)
                 },
                 near_primitives::errors::InvalidAccessKeyError::ReceiverMismatch{tx_receiver, ak_receiver} => {
-                    format!("Error: Transaction for <{}> doesn't match the access key for <{}>.", tx_receiver, ak_receiver)
+                    format!("Transaction for <{}> doesn't match the access key for <{}>.", tx_receiver, ak_receiver)
                 },
                 near_primitives::errors::InvalidAccessKeyError::MethodNameMismatch{method_name} => {
-                    format!("Error: Transaction method name <{}> isn't allowed by the access key.", method_name)
+                    format!("Transaction method name <{}> isn't allowed by the access key.", method_name)
                 },
                 near_primitives::errors::InvalidAccessKeyError::RequiresFullAccess => {
-                    "Error: Transaction requires a full permission access key.".to_string()
+                    "Transaction requires a full permission access key.".to_string()
                 },
                 near_primitives::errors::InvalidAccessKeyError::NotEnoughAllowance{account_id, public_key, allowance, cost} => {
-                    format!("Error: Access Key <{}> for account <{}> does not have enough allowance ({}) to cover transaction cost ({}).",
+                    format!("Access Key <{}> for account <{}> does not have enough allowance ({}) to cover transaction cost ({}).",
                         public_key,
                         account_id,
                         crate::common::NearBalance::from_yoctonear(*allowance),
@@ -1089,115 +1733,143 @@ pub fn handler_invalid_tx_error(
                     )
                 },
                 near_primitives::errors::InvalidAccessKeyError::DepositWithFunctionCall => {
-                    "Error: Having a deposit with a function call action is not allowed with a function call access key.".to_string()
+                    "Having a deposit with a function call action is not allowed with a function call access key.".to_string()
                 }
             }
         },
         near_primitives::errors::InvalidTxError::InvalidSignerId { signer_id } => {
-            format!("Error: TX signer ID <{}> is not in a valid format or does not satisfy requirements\nSee \"near_runtime_utils::utils::is_valid_account_id\".", signer_id)
+            format!("TX signer ID <{}> is not in a valid format or does not satisfy requirements\nSee \"near_runtime_utils::utils::is_valid_account_id\".", signer_id)
         },
         near_primitives::errors::InvalidTxError::SignerDoesNotExist { signer_id } => {
-            format!("Error: TX signer ID <{}> is not found in the storage.", signer_id)
+            format!("TX signer ID <{}> is not found in the storage.", signer_id)
         },
         near_primitives::errors::InvalidTxError::InvalidNonce { tx_nonce, ak_nonce } => {
-            format!("Error: Transaction nonce ({}) must be account[access_key].nonce ({}) + 1.", tx_nonce, ak_nonce)
+            format!("Transaction nonce ({}) must be account[access_key].nonce ({}) + 1.", tx_nonce, ak_nonce)
         },
         near_primitives::errors::InvalidTxError::NonceTooLarge { tx_nonce, upper_bound } => {
-            format!("Error: Transaction nonce ({}) is larger than the upper bound ({}) given by the block height.", tx_nonce, upper_bound)
+            format!("Transaction nonce ({}) is larger than the upper bound ({}) given by the block height.", tx_nonce, upper_bound)
         },
         near_primitives::errors::InvalidTxError::InvalidReceiverId { receiver_id } => {
-            format!("Error: TX receiver ID ({}) is not in a valid format or does not satisfy requirements\nSee \"near_runtime_utils::is_valid_account_id\".", receiver_id)
+            format!("TX receiver ID ({}) is not in a valid format or does not satisfy requirements\nSee \"near_runtime_utils::is_valid_account_id\".", receiver_id)
         },
         near_primitives::errors::InvalidTxError::InvalidSignature => {
-            "Error: TX signature is not valid".to_string()
+            "TX signature is not valid".to_string()
         },
         near_primitives::errors::InvalidTxError::NotEnoughBalance {signer_id, balance, cost} => {
-            format!("Error: Account <{}> does not have enough balance ({}) to cover TX cost ({}).",
+            format!("Account <{}> does not have enough balance ({}) to cover TX cost ({}).",
                 signer_id,
                 crate::common::NearBalance::from_yoctonear(*balance),
                 crate::common::NearBalance::from_yoctonear(*cost)
             )
         },
         near_primitives::errors::InvalidTxError::LackBalanceForState {signer_id, amount} => {
-            format!("Error: Signer account <{}> doesn't have enough balance ({}) after transaction.",
+            format!("Signer account <{}> doesn't have enough balance ({}) after transaction.",
                 signer_id,
                 crate::common::NearBalance::from_yoctonear(*amount)
             )
         },
         near_primitives::errors::InvalidTxError::CostOverflow => {
-            "Error: An integer overflow occurred during transaction cost estimation.".to_string()
+            "An integer overflow occurred during transaction cost estimation.".to_string()
         },
         near_primitives::errors::InvalidTxError::InvalidChain => {
-            "Error: Transaction parent block hash doesn't belong to the current chain.".to_string()
+            "Transaction parent block hash doesn't belong to the current chain.".to_string()
         },
         near_primitives::errors::InvalidTxError::Expired => {
-            "Error: Transaction has expired.".to_string()
+            "Transaction has expired.".to_string()
         },
         near_primitives::errors::InvalidTxError::ActionsValidation(actions_validation_error) => {
             match actions_validation_error {
                 near_primitives::errors::ActionsValidationError::DeleteActionMustBeFinal => {
-                    "Error: The delete action must be the final action in transaction.".to_string()
+                    "The delete action must be the final action in transaction.".to_string()
                 },
                 near_primitives::errors::ActionsValidationError::TotalPrepaidGasExceeded {total_prepaid_gas, limit} => {
-                    format!("Error: The total prepaid gas ({}) for all given actions exceeded the limit ({}).",
+                    format!("The total prepaid gas ({}) for all given actions exceeded the limit ({}).",
                     total_prepaid_gas,
                     limit
                     )
                 },
                 near_primitives::errors::ActionsValidationError::TotalNumberOfActionsExceeded {total_number_of_actions, limit} => {
-                    format!("Error: The number of actions ({}) exceeded the given limit ({}).", total_number_of_actions, limit)
+                    format!("The number of actions ({}) exceeded the given limit ({}).", total_number_of_actions, limit)
                 },
                 near_primitives::errors::ActionsValidationError::AddKeyMethodNamesNumberOfBytesExceeded {total_number_of_bytes, limit} => {
-                    format!("Error: The total number of bytes ({}) of the method names exceeded the limit ({}) in a Add Key action.", total_number_of_bytes, limit)
+                    format!("The total number of bytes ({}) of the method names exceeded the limit ({}) in a Add Key action.", total_number_of_bytes, limit)
                 },
                 near_primitives::errors::ActionsValidationError::AddKeyMethodNameLengthExceeded {length, limit} => {
-                    format!("Error: The length ({}) of some method name exceeded the limit ({}) in a Add Key action.", length, limit)
+                    format!("The length ({}) of some method name exceeded the limit ({}) in a Add Key action.", length, limit)
                 },
                 near_primitives::errors::ActionsValidationError::IntegerOverflow => {
-                    "Error: Integer overflow.".to_string()
+                    "Integer overflow.".to_string()
                 },
                 near_primitives::errors::ActionsValidationError::InvalidAccountId {account_id} => {
-                    format!("Error: Invalid account ID <{}>.", account_id)
+                    format!("Invalid account ID <{}>.", account_id)
                 },
                 near_primitives::errors::ActionsValidationError::ContractSizeExceeded {size, limit} => {
-                    format!("Error: The size ({}) of the contract code exceeded the limit ({}) in a DeployContract action.", size, limit)
+                    format!("The size ({}) of the contract code exceeded the limit ({}) in a DeployContract action.", size, limit)
                 },
                 near_primitives::errors::ActionsValidationError::FunctionCallMethodNameLengthExceeded {length, limit} => {
-                    format!("Error: The length ({}) of the method name exceeded the limit ({}) in a Function Call action.", length, limit)
+                    format!("The length ({}) of the method name exceeded the limit ({}) in a Function Call action.", length, limit)
                 },
                 near_primitives::errors::ActionsValidationError::FunctionCallArgumentsLengthExceeded {length, limit} => {
-                    format!("Error: The length ({}) of the arguments exceeded the limit ({}) in a Function Call action.", length, limit)
+                    format!("The length ({}) of the arguments exceeded the limit ({}) in a Function Call action.", length, limit)
                 },
                 near_primitives::errors::ActionsValidationError::UnsuitableStakingKey {public_key} => {
-                    format!("Error: An attempt to stake with a public key <{}> that is not convertible to ristretto.", public_key)
+                    format!("An attempt to stake with a public key <{}> that is not convertible to ristretto.", public_key)
                 },
                 near_primitives::errors::ActionsValidationError::FunctionCallZeroAttachedGas => {
-                    "Error: The attached amount of gas in a FunctionCall action has to be a positive number.".to_string()
+                    "The attached amount of gas in a FunctionCall action has to be a positive number.".to_string()
                 }
             }
         },
         near_primitives::errors::InvalidTxError::TransactionSizeExceeded { size, limit } => {
-            format!("Error: The size ({}) of serialized transaction exceeded the limit ({}).", size, limit)
+            format!("The size ({}) of serialized transaction exceeded the limit ({}).", size, limit)
         }
     }
 }
 
-pub fn print_transaction_error(tx_execution_error: &near_primitives::errors::TxExecutionError) {
-    eprintln!("Failed transaction");
+pub fn transaction_execution_error(
+    tx_execution_error: &near_primitives::errors::TxExecutionError,
+    transaction_hash: &CryptoHash,
+) -> CliError {
     match tx_execution_error {
-        near_primitives::errors::TxExecutionError::ActionError(action_error) => {
-            print_action_error(action_error)
-        }
+        near_primitives::errors::TxExecutionError::ActionError(action_error) => CliError::new(
+            ErrorCategory::ActionFailed,
+            format!("Failed transaction: {}", action_error_message(action_error)),
+        )
+        .with_nearcore_error(&action_error.kind, tx_execution_error),
         near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error) => {
-            eprintln!("{}", handler_invalid_tx_error(invalid_tx_error))
+            CliError::new(
+                ErrorCategory::TransactionInvalid,
+                format!(
+                    "Failed transaction: {}",
+                    handler_invalid_tx_error(invalid_tx_error)
+                ),
+            )
+            .with_nearcore_error(invalid_tx_error, tx_execution_error)
         }
     }
+    .with_transaction_hash(transaction_hash)
 }
 
 pub fn print_transaction_status(
     transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
     network_config: &crate::config::NetworkConfig,
+    output_format: &OutputFormat,
 ) -> crate::CliResult {
+    if let OutputFormat::Json = output_format {
+        if let near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) =
+            &transaction_info.status
+        {
+            return Err(transaction_execution_error(
+                tx_execution_error,
+                &transaction_info.transaction_outcome.id,
+            )
+            .into());
+        }
+        return print_json_output(&transaction_status_to_json(
+            transaction_info,
+            network_config,
+        ));
+    }
     eprintln!("--- Logs ---------------------------");
     for receipt in transaction_info.receipts_outcome.iter() {
         if receipt.outcome.logs.is_empty() {
@@ -1207,11 +1879,15 @@ pub fn print_transaction_status(
             eprintln!("  {}", receipt.outcome.logs.join("\n  "));
         };
     }
-    match &transaction_info.status {
+    let result = match &transaction_info.status {
         near_primitives::views::FinalExecutionStatus::NotStarted
         | near_primitives::views::FinalExecutionStatus::Started => unreachable!(),
         near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
-            print_transaction_error(tx_execution_error)
+            Err(transaction_execution_error(
+                tx_execution_error,
+                &transaction_info.transaction_outcome.id,
+            )
+            .into())
         }
         near_primitives::views::FinalExecutionStatus::SuccessValue(bytes_result) => {
             eprintln!("--- Result -------------------------");
@@ -1227,14 +1903,58 @@ pub fn print_transaction_status(
                 eprintln!("The returned value is not printable (binary data)");
             }
             eprintln!("------------------------------------\n");
-            print_value_successful_transaction(transaction_info.clone())
+            print_value_successful_transaction(transaction_info.clone());
+            Ok(())
         }
     };
     eprintln!("Transaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
         id=transaction_info.transaction_outcome.id,
         path=network_config.explorer_transaction_url
     );
-    Ok(())
+    result
+}
+
+fn transaction_status_to_json(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+) -> serde_json::Value {
+    let logs = transaction_info
+        .receipts_outcome
+        .iter()
+        .map(|receipt| {
+            serde_json::json!({
+                "executor_id": receipt.outcome.executor_id,
+                "logs": receipt.outcome.logs,
+            })
+        })
+        .collect::<Vec<_>>();
+    let bytes_result = match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(bytes_result) => {
+            bytes_result.clone()
+        }
+        _ => vec![],
+    };
+    let result = if bytes_result.is_empty() {
+        serde_json::Value::Null
+    } else if let Ok(json_result) = serde_json::from_slice::<serde_json::Value>(&bytes_result) {
+        json_result
+    } else if let Ok(string_result) = String::from_utf8(bytes_result.clone()) {
+        serde_json::Value::String(string_result)
+    } else {
+        serde_json::Value::Null
+    };
+    serde_json::json!({
+        "transaction_hash": transaction_info.transaction_outcome.id.to_string(),
+        "signer_id": transaction_info.transaction.signer_id,
+        "receiver_id": transaction_info.transaction.receiver_id,
+        "result": result,
+        "result_base64": base64::encode(&bytes_result),
+        "logs": logs,
+        "explorer_url": format!(
+            "{}{}",
+            network_config.explorer_transaction_url, transaction_info.transaction_outcome.id
+        ),
+    })
 }
 
 #[cfg(target_os = "macos")]
@@ -1314,32 +2034,111 @@ pub fn save_access_key_to_keychain(
     }
 }
 
-pub fn get_config_toml() -> color_eyre::eyre::Result<crate::config::Config> {
-    if let Some(mut path_config_toml) = dirs::config_dir() {
-        path_config_toml.extend(&["near-cli", "config.toml"]);
+/// The path of config.toml: `--config <path>`, `NEAR_CLI_CONFIG`, or the default location in the
+/// config dir of the user.
+pub fn config_path(config_path_arg: Option<std::path::PathBuf>) -> Option<std::path::PathBuf> {
+    config_path_arg
+        .or_else(|| std::env::var_os("NEAR_CLI_CONFIG").map(std::path::PathBuf::from))
+        .or_else(|| {
+            dirs::config_dir().map(|mut path_config_toml| {
+                path_config_toml.extend(&["near-cli", "config.toml"]);
+                path_config_toml
+            })
+        })
+}
 
+pub fn get_config_toml(
+    config_path: Option<&std::path::Path>,
+) -> color_eyre::eyre::Result<crate::config::Config> {
+    if let Some(path_config_toml) = config_path {
         if !path_config_toml.is_file() {
-            write_config_toml(crate::config::Config::default())?;
+            write_config_toml(crate::config::Config::default(), Some(path_config_toml))?;
         };
-        let config_toml = std::fs::read_to_string(&path_config_toml)?;
-        toml::from_str(&config_toml).or_else(|err| {
-            eprintln!("Warning: `near` CLI configuration file stored at {path_config_toml:?} could not be parsed due to: {err}");
-            eprintln!("Note: The default configuration printed below will be used instead:\n");
-            let default_config = crate::config::Config::default();
-            eprintln!("{}", toml::to_string(&default_config)?);
-            Ok(default_config)
-        })
+        let config_toml = std::fs::read_to_string(path_config_toml)?;
+        let config_table: toml::value::Table = toml::from_str(&config_toml).map_err(|err| {
+            color_eyre::eyre::eyre!(
+                "`near` CLI configuration file stored at {path_config_toml:?} is not a valid TOML file: {err}"
+            )
+        })?;
+        let config_version = crate::config::migrations::config_version(&config_table)?;
+        if config_version > crate::config::migrations::CONFIG_VERSION {
+            return Err(color_eyre::eyre::eyre!(
+                "`near` CLI configuration file stored at {path_config_toml:?} was written by a newer release of `near` CLI (config version {config_version}, this release supports version {}). Please, update `near` CLI.",
+                crate::config::migrations::CONFIG_VERSION
+            ));
+        }
+        if config_version == crate::config::migrations::CONFIG_VERSION {
+            return parse_config_table(config_table, path_config_toml);
+        }
+
+        let path_backup =
+            path_config_toml.with_file_name(format!("config.v{config_version}.toml.bak"));
+        std::fs::copy(path_config_toml, &path_backup).wrap_err_with(|| {
+            format!("Failed to back up the configuration file to {path_backup:?}")
+        })?;
+        let config = parse_config_table(
+            crate::config::migrations::migrate(config_table, config_version)?,
+            path_config_toml,
+        )?;
+        write_config_toml(config.clone(), Some(path_config_toml))?;
+        eprintln!(
+            "Note: `near` CLI configuration was migrated from version {config_version} to version {}, the previous file is saved in {path_backup:?}",
+            crate::config::migrations::CONFIG_VERSION
+        );
+        Ok(config)
     } else {
         Ok(crate::config::Config::default())
     }
 }
-pub fn write_config_toml(config: crate::config::Config) -> CliResult {
-    let config_toml = toml::to_string(&config)?;
-    let mut path_config_toml = dirs::config_dir().expect("Impossible to get your config dir!");
-    path_config_toml.push("near-cli");
-    std::fs::create_dir_all(&path_config_toml)?;
-    path_config_toml.push("config.toml");
-    std::fs::File::create(&path_config_toml)
+
+/// Loads the configuration, or fails with the list of the differences from the expected layout
+/// and a reference file to compare with (the configuration file is never replaced silently).
+fn parse_config_table(
+    config_table: toml::value::Table,
+    path_config_toml: &std::path::Path,
+) -> color_eyre::eyre::Result<crate::config::Config> {
+    let differences = crate::config::migrations::layout_differences(&config_table);
+    let config: crate::config::Config = toml::Value::Table(config_table)
+        .try_into::<crate::config::Config>()
+        .map_err(|err| {
+        let path_default_config_toml = path_config_toml.with_file_name("config.default.toml");
+        let default_config_hint = toml::to_string(&crate::config::Config::default())
+            .ok()
+            .and_then(|default_config_toml| {
+                std::fs::write(&path_default_config_toml, default_config_toml).ok()
+            })
+            .map(|_| {
+                format!(
+                    "\nCompare it with the default configuration: diff {:?} {:?}",
+                    path_config_toml, path_default_config_toml
+                )
+            })
+            .unwrap_or_default();
+        color_eyre::eyre::eyre!(
+            "`near` CLI configuration file stored at {:?} could not be parsed due to: {}{}{}\nFix the file, or remove it to start over with the default configuration.",
+            path_config_toml,
+            err,
+            differences
+                .iter()
+                .map(|difference| format!("\n  - {}", difference))
+                .collect::<String>(),
+            default_config_hint
+        )
+    })?;
+    Ok(config)
+}
+
+pub fn write_config_toml(
+    config: crate::config::Config,
+    config_path: Option<&std::path::Path>,
+) -> CliResult {
+    let config_toml = toml::to_string(&config.without_overrides())?;
+    let path_config_toml =
+        config_path.ok_or_else(|| color_eyre::eyre::eyre!("Impossible to get your config dir!"))?;
+    if let Some(config_dir) = path_config_toml.parent() {
+        std::fs::create_dir_all(config_dir)?;
+    }
+    std::fs::File::create(path_config_toml)
         .wrap_err_with(|| format!("Failed to create file: {path_config_toml:?}"))?
         .write(config_toml.as_bytes())
         .wrap_err_with(|| format!("Failed to write to file: {path_config_toml:?}"))?;
@@ -1418,7 +2217,26 @@ pub fn display_account_info(
     account_id: &near_primitives::types::AccountId,
     account_view: &near_primitives::views::AccountView,
     access_keys: &[near_primitives::views::AccessKeyInfoView],
-) {
+    local_keys: &crate::commands::local_keys::storage::LocalKeyAvailability,
+    output_format: &OutputFormat,
+) -> CliResult {
+    if let OutputFormat::Json = output_format {
+        return print_json_output(&serde_json::json!({
+            "account_id": account_id,
+            "block_height": viewed_at_block_height,
+            "block_hash": viewed_at_block_hash.to_string(),
+            "balance": account_view.amount.to_string(),
+            "locked": account_view.locked.to_string(),
+            "storage_usage": account_view.storage_usage,
+            "contract_sha256": if account_view.code_hash == CryptoHash::default() {
+                serde_json::Value::Null
+            } else {
+                serde_json::Value::String(hex::encode(account_view.code_hash.as_ref()))
+            },
+            "access_keys": access_keys_with_local_keys(access_keys, local_keys)?,
+        }));
+    }
+
     let mut table = Table::new();
     table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
 
@@ -1475,13 +2293,45 @@ pub fn display_account_info(
     table.printstd();
 
     if !access_keys.is_empty() {
-        display_access_key_list(access_keys);
+        display_access_key_list(access_keys, local_keys, output_format)?;
     }
+    Ok(())
+}
+
+/// The access keys with the `local_keys` field listing where their private keys are available.
+fn access_keys_with_local_keys(
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+    local_keys: &crate::commands::local_keys::storage::LocalKeyAvailability,
+) -> color_eyre::eyre::Result<Vec<serde_json::Value>> {
+    access_keys
+        .iter()
+        .map(|access_key| {
+            let mut value = serde_json::to_value(access_key)?;
+            value["local_keys"] = serde_json::json!(local_keys
+                .get(&access_key.public_key)
+                .cloned()
+                .unwrap_or_default());
+            Ok(value)
+        })
+        .collect()
 }
 
-pub fn display_access_key_list(access_keys: &[near_primitives::views::AccessKeyInfoView]) {
+pub fn display_access_key_list(
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+    local_keys: &crate::commands::local_keys::storage::LocalKeyAvailability,
+    output_format: &OutputFormat,
+) -> CliResult {
+    if let OutputFormat::Json = output_format {
+        return print_json_output(&serde_json::json!({
+            "keys": access_keys_with_local_keys(access_keys, local_keys)?,
+        }));
+    }
+
     let mut table = Table::new();
-    table.set_titles(prettytable::row![Fg=>"#", "Public Key", "Nonce", "Permissions"]);
+    table.set_titles(
+        prettytable::row![Fg=>"#", "Public Key", "Nonce", "Permissions", "Available locally"],
+    );
+    let mut unheld_full_access_keys = vec![];
 
     for (index, access_key) in access_keys.iter().enumerate() {
         let permissions_message = match &access_key.access_key.permission {
@@ -1512,16 +2362,50 @@ pub fn display_access_key_list(access_keys: &[near_primitives::views::AccessKeyI
             }
         };
 
-        table.add_row(prettytable::row![
-            Fg->index + 1,
-            access_key.public_key,
-            access_key.access_key.nonce,
-            permissions_message
-        ]);
+        match local_keys.get(&access_key.public_key) {
+            Some(descriptions) => table.add_row(prettytable::row![
+                Fg->index + 1,
+                access_key.public_key,
+                access_key.access_key.nonce,
+                permissions_message,
+                descriptions.join("\n")
+            ]),
+            None if matches!(
+                access_key.access_key.permission,
+                AccessKeyPermissionView::FullAccess
+            ) =>
+            {
+                unheld_full_access_keys.push(&access_key.public_key);
+                table.add_row(prettytable::row![
+                    Fg->index + 1,
+                    access_key.public_key,
+                    access_key.access_key.nonce,
+                    permissions_message,
+                    Fr->"not held on this machine"
+                ])
+            }
+            None => table.add_row(prettytable::row![
+                Fg->index + 1,
+                access_key.public_key,
+                access_key.access_key.nonce,
+                permissions_message,
+                "-"
+            ]),
+        };
     }
 
     table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
     table.printstd();
+    if !unheld_full_access_keys.is_empty() {
+        eprintln!(
+            "\nWarning: {} full access key(s) are not held on this machine. Make sure you know who holds them:",
+            unheld_full_access_keys.len()
+        );
+        for public_key in unheld_full_access_keys {
+            eprintln!("  {}", public_key);
+        }
+    }
+    Ok(())
 }
 
 pub fn input_network_name(
@@ -1539,8 +2423,298 @@ pub fn input_network_name(
     }
 }
 
+pub fn missing_overridden_connection_error(connection_name: &str) -> CliError {
+    CliError::new(
+        ErrorCategory::UserInput,
+        format!(
+            "The network connection <{}> set in the environment (NEAR_CLI_NETWORK or NEAR_ENV) does not exist in config.toml",
+            connection_name
+        ),
+    )
+}
+
+/// Uses the selected connection (see `near config select-connection`) instead of asking for the
+/// network name.
+pub fn input_network_name_or_selected(
+    context: &crate::GlobalContext,
+) -> color_eyre::eyre::Result<Option<String>> {
+    if let Some(connection_name) = context.0.missing_overridden_connection() {
+        return Err(missing_overridden_connection_error(connection_name).into());
+    }
+    if let Some((selected_connection, _)) = context.0.selected_network_config() {
+        eprintln!("Using the selected connection <{}>", selected_connection);
+        return Ok(Some(selected_connection.clone()));
+    }
+    input_network_name(context)
+}
+
+/// The default account of the selected connection, suggested in the signer account ID prompts.
+pub fn default_account_id(
+    config: &crate::config::Config,
+) -> Option<crate::types::account_id::AccountId> {
+    config
+        .selected_network_config()
+        .and_then(|(_, network_config)| network_config.default_account_id.clone())
+        .map(Into::into)
+}
+
+/// Asks for a confirmation of an action that cannot be undone or takes long, unless `--yes` was
+/// passed (which is required in scripting mode).
+pub fn confirm(scripting_mode: bool, yes: bool, message: &str) -> color_eyre::eyre::Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if scripting_mode {
+        return Err(CliError::new(
+            ErrorCategory::UserInput,
+            "This command requires a confirmation: pass --yes to confirm it in scripting mode",
+        )
+        .into());
+    }
+    Ok(inquire::Confirm::new(message)
+        .with_default(false)
+        .prompt()?)
+}
+
+/// Prompts for the account which signs the transaction, suggesting the default account of the
+/// selected connection.
+pub fn input_signer_account_id(
+    config: &crate::config::Config,
+    message: &str,
+) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+    let mut signer_account_id_input = inquire::CustomType::new(message);
+    if let Some(default_account_id) = default_account_id(config) {
+        signer_account_id_input = signer_account_id_input.with_default(default_account_id);
+    }
+    Ok(Some(signer_account_id_input.prompt()?))
+}
+
+/// JSON RPC client which switches to the next RPC endpoint of the connection on transport errors,
+/// 5xx and 429 responses, and retries all the endpoints with exponential backoff. A signed
+/// transaction is resent the same way: the network deduplicates it by its hash, so it is executed
+/// at most once.
+pub struct FailoverJsonRpcClient {
+    pub endpoints: Vec<(url::Url, near_jsonrpc_client::JsonRpcClient)>,
+    pub retries: u32,
+    pub retry_backoff: std::time::Duration,
+}
+
+impl FailoverJsonRpcClient {
+    pub async fn call<M>(
+        &self,
+        method: M,
+    ) -> near_jsonrpc_client::MethodCallResult<M::Response, M::Error>
+    where
+        M: near_jsonrpc_client::methods::RpcMethod,
+    {
+        let mut retry_backoff = self.retry_backoff;
+        let mut retry = 0;
+        loop {
+            for (index, (rpc_url, json_rpc_client)) in self.endpoints.iter().enumerate() {
+                let result = json_rpc_client.call(&method).await;
+                let is_last_attempt = retry == self.retries && index + 1 == self.endpoints.len();
+                match result.as_ref().err().and_then(failover_reason) {
+                    Some(reason) if !is_last_attempt => {
+                        eprintln!("Warning: the RPC endpoint <{}> failed: {}", rpc_url, reason)
+                    }
+                    _ => return result,
+                }
+            }
+            retry += 1;
+            eprintln!(
+                "Retrying the RPC request in {} ms ({}/{}) ...",
+                retry_backoff.as_millis(),
+                retry,
+                self.retries
+            );
+            tokio::time::sleep(retry_backoff).await;
+            retry_backoff *= 2;
+        }
+    }
+}
+
+/// The errors after which the request is sent to the next RPC endpoint.
+fn failover_reason<E>(err: &near_jsonrpc_client::errors::JsonRpcError<E>) -> Option<String> {
+    match err {
+        near_jsonrpc_client::errors::JsonRpcError::TransportError(err) => {
+            Some(format!("transport error: {}", err))
+        }
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::Unexpected {
+                    status,
+                },
+            ),
+        ) if status.is_server_error() => Some(format!("HTTP status {}", status)),
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests,
+            ),
+        ) => Some("too many requests".to_string()),
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::InternalError { .. },
+        ) => Some("internal server error".to_string()),
+        _ => None,
+    }
+}
+
+/// What a watched query found in a block
+pub struct WatchedValue {
+    /// Printed whenever the value changes
+    pub text: String,
+    /// Printed with `--output-format json`; a change of it is what counts as a change of the value
+    pub json: serde_json::Value,
+    /// Whether the `--exit-if` condition is met
+    pub threshold_met: bool,
+}
+
+/// Runs the query on every new final block (polled every `interval`) and prints the value only
+/// when it changes. Returns a [`ErrorCategory::ThresholdMet`] error once the `--exit-if`
+/// condition is met, so monitoring scripts can rely on the exit code.
+pub fn watch_final_blocks(
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+    interval: std::time::Duration,
+    output_format: &OutputFormat,
+    query: impl Fn(&near_primitives::types::BlockReference) -> color_eyre::eyre::Result<WatchedValue>,
+) -> crate::CliResult {
+    if !matches!(
+        block_reference,
+        near_primitives::types::BlockReference::Finality(_)
+    ) {
+        return Err(CliError::new(
+            ErrorCategory::UserInput,
+            "--watch follows the new final blocks, so it can only be used with `now`",
+        )
+        .into());
+    }
+    let json_rpc_client = network_config.json_rpc_client()?;
+    let mut last_block_height = None;
+    let mut last_value = None;
+    eprintln!(
+        "Watching <{}> network every {} (press Ctrl+C to stop) ...",
+        network_config.network_name,
+        crate::types::duration::Duration(interval)
+    );
+    loop {
+        let block = json_rpc_client
+            .blocking_call(near_jsonrpc_client::methods::block::RpcBlockRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+            })
+            .wrap_err("Failed to fetch the final block");
+        let Some(block) = retry_on_next_tick(block)? else {
+            std::thread::sleep(interval);
+            continue;
+        };
+        if last_block_height != Some(block.header.height) {
+            let value = query(&near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(block.header.hash),
+            ));
+            let Some(value) = retry_on_next_tick(value)? else {
+                std::thread::sleep(interval);
+                continue;
+            };
+            last_block_height = Some(block.header.height);
+            if last_value.as_ref() != Some(&value.json) {
+                let timestamp = format_block_timestamp(block.header.timestamp_nanosec);
+                if let OutputFormat::Json = output_format {
+                    print_json_output(&serde_json::json!({
+                        "timestamp": timestamp,
+                        "block_height": block.header.height,
+                        "block_hash": block.header.hash,
+                        "value": value.json,
+                    }))?;
+                } else {
+                    println!("{}  #{}  {}", timestamp, block.header.height, value.text);
+                }
+                last_value = Some(value.json);
+            }
+            if value.threshold_met {
+                return Err(CliError::new(
+                    ErrorCategory::ThresholdMet,
+                    format!(
+                        "The --exit-if condition is met at block #{}",
+                        block.header.height
+                    ),
+                )
+                .into());
+            }
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// A watch keeps running through connectivity issues: a transient RPC error (see
+/// [`is_transient_rpc_error`]) is logged and the block is fetched again on the next tick.
+fn retry_on_next_tick<T>(
+    result: color_eyre::eyre::Result<T>,
+) -> color_eyre::eyre::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if is_transient_rpc_error(&err) => {
+            eprintln!("Warning: {:#} (retrying on the next tick)", err);
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Transport errors and unexpected HTTP statuses (e.g. 503 or 429) of the RPC server can go away
+/// on their own, unlike the errors of the request itself (e.g. an unknown account).
+fn is_transient_rpc_error(err: &color_eyre::Report) -> bool {
+    categorize_error(err).map_or(false, |cli_error| {
+        cli_error.category == ErrorCategory::RpcTransport
+    })
+}
+
+/// `--exit-if` is checked on every watched block, so it makes no sense without `--watch`.
+pub fn check_exit_if_needs_watch(
+    watch: Option<&crate::types::duration::Duration>,
+    exit_if: Option<&crate::types::threshold::Threshold>,
+) -> color_eyre::eyre::Result<()> {
+    if watch.is_none() && exit_if.is_some() {
+        return Err(CliError::new(
+            ErrorCategory::UserInput,
+            "--exit-if can only be used together with --watch",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Formats the block timestamp (nanoseconds since the Unix epoch) as UTC RFC 3339, e.g.
+/// `2023-05-01T12:30:00Z`.
+pub fn format_block_timestamp(timestamp_nanosec: u64) -> String {
+    let seconds = timestamp_nanosec / 1_000_000_000;
+    let (days, seconds_of_day) = ((seconds / 86400) as i64, seconds % 86400);
+    // Howard Hinnant's days-to-civil algorithm
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
+
 #[easy_ext::ext(JsonRpcClientExt)]
-pub impl near_jsonrpc_client::JsonRpcClient {
+pub impl FailoverJsonRpcClient {
     fn blocking_call<M>(
         &self,
         method: M,
@@ -2097,6 +3271,34 @@ mod tests {
         );
     }
     #[test]
+    fn parse_decimal_amount_with_fraction() {
+        assert_eq!(parse_decimal_amount("1.5", 6), Ok(1500000));
+        assert_eq!(parse_decimal_amount(".25", 2), Ok(25));
+        assert_eq!(parse_decimal_amount("10", 0), Ok(10));
+    }
+    #[test]
+    fn parse_decimal_amount_too_many_decimal_places() {
+        assert_eq!(
+            parse_decimal_amount("0.001", 2),
+            Err("<0.001> has more than 2 decimal places".to_string())
+        );
+    }
+    #[test]
+    fn parse_decimal_amount_not_a_number() {
+        assert_eq!(
+            parse_decimal_amount("-1", 2),
+            Err("<-1> is not a decimal number".to_string())
+        );
+    }
+    #[test]
+    fn format_block_timestamp_utc() {
+        assert_eq!(format_block_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_block_timestamp(1_709_210_096_123_456_789),
+            "2024-02-29T12:34:56Z"
+        );
+    }
+    #[test]
     fn near_gas_from_str_negative_value() {
         let near_gas = NearGas::from_str("-100 ggas");
         assert_eq!(
@@ -2104,4 +3306,546 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+
+    fn query_error_report(
+        err: near_jsonrpc_client::errors::JsonRpcError<
+            near_jsonrpc_primitives::types::query::RpcQueryError,
+        >,
+    ) -> color_eyre::Report {
+        color_eyre::Report::new(err).wrap_err("Failed to fetch query for view account")
+    }
+
+    #[test]
+    fn categorize_user_input_error() {
+        let err = color_eyre::Report::new(MissingArgumentError {
+            argument: "--allowance".to_string(),
+        });
+        let cli_error = categorize_error(&err).unwrap();
+        assert_eq!(cli_error.category, ErrorCategory::UserInput);
+        assert_eq!(cli_error.variant, None);
+    }
+
+    #[test]
+    fn categorize_rpc_transport_error() {
+        let err = query_error_report(near_jsonrpc_client::errors::JsonRpcError::TransportError(
+            near_jsonrpc_client::errors::RpcTransportError::SendError(
+                near_jsonrpc_client::errors::JsonRpcTransportSendError::PayloadSerializeError(
+                    std::io::Error::new(std::io::ErrorKind::Other, "connection refused"),
+                ),
+            ),
+        ));
+        let cli_error = categorize_error(&err).unwrap();
+        assert_eq!(cli_error.category, ErrorCategory::RpcTransport);
+        assert_eq!(cli_error.variant, None);
+    }
+
+    #[test]
+    fn categorize_rpc_handler_error() {
+        let err = query_error_report(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount {
+                    requested_account_id: "alice.testnet".parse().unwrap(),
+                    block_height: 1,
+                    block_hash: CryptoHash::default(),
+                },
+            ),
+        ));
+        let cli_error = categorize_error(&err).unwrap();
+        assert_eq!(cli_error.category, ErrorCategory::RpcHandler);
+        assert_eq!(cli_error.variant.as_deref(), Some("UnknownAccount"));
+        assert_eq!(
+            cli_error.details["name"],
+            serde_json::json!("UNKNOWN_ACCOUNT")
+        );
+    }
+
+    #[test]
+    fn watch_retries_transient_rpc_errors_only() {
+        let transient = query_error_report(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests,
+            ),
+        ));
+        assert!(matches!(retry_on_next_tick::<()>(Err(transient)), Ok(None)));
+
+        let unknown_account =
+            query_error_report(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount {
+                        requested_account_id: "alice.testnet".parse().unwrap(),
+                        block_height: 1,
+                        block_hash: CryptoHash::default(),
+                    },
+                ),
+            ));
+        assert!(retry_on_next_tick::<()>(Err(unknown_account)).is_err());
+        assert!(retry_on_next_tick::<()>(Err(color_eyre::eyre::eyre!("other"))).is_err());
+        assert!(matches!(retry_on_next_tick(Ok(1)), Ok(Some(1))));
+    }
+
+    #[test]
+    fn categorize_invalid_transaction_error() {
+        let err = rpc_transaction_error(
+            near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::InvalidTransaction {
+                        context: near_primitives::errors::InvalidTxError::InvalidAccessKeyError(
+                            near_primitives::errors::InvalidAccessKeyError::NotEnoughAllowance {
+                                account_id: "alice.testnet".parse().unwrap(),
+                                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                                allowance: 1,
+                                cost: 2,
+                            },
+                        ),
+                    },
+                ),
+            ),
+            true,
+        )
+        .unwrap_err();
+        let cli_error = categorize_error(&err).unwrap();
+        assert_eq!(cli_error.category, ErrorCategory::TransactionInvalid);
+        assert_eq!(cli_error.variant.as_deref(), Some("NotEnoughAllowance"));
+    }
+
+    #[test]
+    fn resend_transaction_only_interactively() {
+        let retryable_errors = || {
+            vec![
+                (
+                    near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                        near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                            near_jsonrpc_client::methods::broadcast_tx_commit::RpcTransactionError::TimeoutError,
+                        ),
+                    ),
+                    ErrorCategory::RpcHandler,
+                ),
+                (
+                    near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                        near_jsonrpc_client::errors::JsonRpcServerError::InternalError { info: None },
+                    ),
+                    ErrorCategory::RpcTransport,
+                ),
+                (
+                    near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                        near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                            near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests,
+                        ),
+                    ),
+                    ErrorCategory::RpcTransport,
+                ),
+            ]
+        };
+        for (err, _) in retryable_errors() {
+            assert!(rpc_transaction_error(err, false).is_ok());
+        }
+        for (err, category) in retryable_errors() {
+            let err = rpc_transaction_error(err, true).unwrap_err();
+            assert_eq!(categorize_error(&err).unwrap().category, category);
+        }
+    }
+
+    #[test]
+    fn categorize_action_failed_error() {
+        let action_error = |kind| {
+            transaction_execution_error(
+                &near_primitives::errors::TxExecutionError::ActionError(
+                    near_primitives::errors::ActionError {
+                        index: Some(0),
+                        kind,
+                    },
+                ),
+                &CryptoHash::default(),
+            )
+        };
+        let cli_error = action_error(
+            near_primitives::errors::ActionErrorKind::AccountDoesNotExist {
+                account_id: "bob.testnet".parse().unwrap(),
+            },
+        );
+        assert_eq!(cli_error.category, ErrorCategory::ActionFailed);
+        assert_eq!(cli_error.variant.as_deref(), Some("AccountDoesNotExist"));
+        assert_eq!(
+            cli_error.transaction_hash,
+            Some(CryptoHash::default().to_string())
+        );
+        let cli_error = action_error(near_primitives::errors::ActionErrorKind::FunctionCallError(
+            near_vm_errors::FunctionCallErrorSer::WasmTrap(near_vm_errors::WasmTrap::Unreachable),
+        ));
+        assert_eq!(cli_error.variant.as_deref(), Some("Unreachable"));
+    }
+
+    #[test]
+    fn categorize_passes_cli_errors_through() {
+        let err = color_eyre::Report::new(CliError::new(
+            ErrorCategory::LocalKeyStorage,
+            "Access key not found",
+        ))
+        .wrap_err("Failed to sign the transaction");
+        let cli_error = categorize_error(&err).unwrap();
+        assert_eq!(cli_error.category, ErrorCategory::LocalKeyStorage);
+        assert!(categorize_error(&color_eyre::eyre::eyre!("Unexpected error")).is_none());
+    }
+
+    const BIP39_TEST_SEED_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn master_seed_of_bip39_test_vector() {
+        // The first English test vector of BIP39, with the "TREZOR" passphrase
+        let master_seed = get_master_seed_from_seed_phrase(
+            BIP39_TEST_SEED_PHRASE,
+            Some(crate::types::bip39_language::Bip39Language(
+                bip39::Language::English,
+            )),
+            Some("TREZOR"),
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(master_seed),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn master_seed_detects_language_and_defaults_to_empty_passphrase() {
+        let detected =
+            get_master_seed_from_seed_phrase(BIP39_TEST_SEED_PHRASE, None, None).unwrap();
+        let explicit = get_master_seed_from_seed_phrase(
+            BIP39_TEST_SEED_PHRASE,
+            Some(crate::types::bip39_language::Bip39Language(
+                bip39::Language::English,
+            )),
+            Some(""),
+        )
+        .unwrap();
+        assert_eq!(detected, explicit);
+        let not_normalized = get_master_seed_from_seed_phrase(
+            &format!(
+                "  {}\n",
+                BIP39_TEST_SEED_PHRASE.to_uppercase().replace(' ', "\t")
+            ),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(detected, not_normalized);
+        let with_passphrase =
+            get_master_seed_from_seed_phrase(BIP39_TEST_SEED_PHRASE, None, Some("TREZOR")).unwrap();
+        assert_ne!(detected, with_passphrase);
+    }
+
+    #[test]
+    fn master_seed_rejects_invalid_seed_phrases() {
+        // Bad checksum
+        assert!(get_master_seed_from_seed_phrase(&["abandon"; 12].join(" "), None, None).is_err());
+        // Words of another wordlist
+        assert!(get_master_seed_from_seed_phrase(
+            BIP39_TEST_SEED_PHRASE,
+            Some(crate::types::bip39_language::Bip39Language(
+                bip39::Language::Spanish,
+            )),
+            None
+        )
+        .is_err());
+    }
+
+    const V1_CONFIG: &str = r#"
+credentials_home_dir = "/home/user/.near-credentials"
+
+[network_connection.testnet]
+network_name = "testnet"
+rpc_url = "https://archival-rpc.testnet.near.org/"
+wallet_url = "https://wallet.testnet.near.org/"
+explorer_transaction_url = "https://explorer.testnet.near.org/transactions/"
+linkdrop_account_id = "testnet"
+"#;
+
+    /// Writes the configuration file into a new directory, so the tests don't share files.
+    fn config_fixture(test_name: &str, config_toml: &str) -> std::path::PathBuf {
+        let config_dir =
+            std::env::temp_dir().join(format!("near-cli-{}-{}", test_name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&config_dir);
+        std::fs::create_dir_all(&config_dir).unwrap();
+        let path_config_toml = config_dir.join("config.toml");
+        std::fs::write(&path_config_toml, config_toml).unwrap();
+        path_config_toml
+    }
+
+    #[test]
+    fn get_config_toml_migrates_v1_config() {
+        let path_config_toml = config_fixture("migrates-v1-config", V1_CONFIG);
+        let config = get_config_toml(Some(&path_config_toml)).unwrap();
+        assert_eq!(
+            config.network_connection["testnet"].rpc_url.as_str(),
+            "https://archival-rpc.testnet.near.org/"
+        );
+
+        let path_backup = path_config_toml.with_file_name("config.v1.toml.bak");
+        assert_eq!(std::fs::read_to_string(path_backup).unwrap(), V1_CONFIG);
+        let migrated_table: toml::value::Table =
+            toml::from_str(&std::fs::read_to_string(&path_config_toml).unwrap()).unwrap();
+        assert_eq!(
+            crate::config::migrations::config_version(&migrated_table).unwrap(),
+            crate::config::migrations::CONFIG_VERSION
+        );
+        // The migrated file is loaded as it is
+        assert_eq!(
+            get_config_toml(Some(&path_config_toml))
+                .unwrap()
+                .network_connection["testnet"]
+                .rpc_url,
+            config.network_connection["testnet"].rpc_url
+        );
+        std::fs::remove_dir_all(path_config_toml.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn get_config_toml_defers_http_settings_errors() {
+        let config_toml = format!(
+            "version = {}\n{}extra_root_certificates = [\"/nonexistent/ca.pem\"]\n",
+            crate::config::migrations::CONFIG_VERSION,
+            V1_CONFIG
+        );
+        let path_config_toml = config_fixture("defers-http-settings-errors", &config_toml);
+        // The other commands (e.g. delete-connection) still work with the configuration
+        let config = get_config_toml(Some(&path_config_toml)).unwrap();
+        let err = config.network_connection["testnet"]
+            .http_client()
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("Failed to read the root certificate"));
+        std::fs::remove_dir_all(path_config_toml.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn get_config_toml_rejects_newer_config() {
+        let config_toml = format!(
+            "version = {}\n{}",
+            crate::config::migrations::CONFIG_VERSION + 1,
+            V1_CONFIG
+        );
+        let path_config_toml = config_fixture("rejects-newer-config", &config_toml);
+        let err = get_config_toml(Some(&path_config_toml)).unwrap_err();
+        assert!(err.to_string().contains("written by a newer release"));
+        // The file is left untouched
+        assert_eq!(
+            std::fs::read_to_string(&path_config_toml).unwrap(),
+            config_toml
+        );
+        std::fs::remove_dir_all(path_config_toml.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn get_config_toml_rejects_unparsable_config() {
+        let path_invalid_toml = config_fixture("rejects-invalid-toml", "version = [");
+        let err = get_config_toml(Some(&path_invalid_toml)).unwrap_err();
+        assert!(err.to_string().contains("is not a valid TOML file"));
+
+        let config_toml = V1_CONFIG.replace("rpc_url", "rpc_uri");
+        let path_invalid_layout = config_fixture("rejects-invalid-layout", &config_toml);
+        let err = get_config_toml(Some(&path_invalid_layout)).unwrap_err();
+        assert!(err.to_string().contains("could not be parsed"));
+        assert!(err
+            .to_string()
+            .contains("missing field `network_connection.testnet.rpc_url`"));
+        // The default configuration is written next to the file to compare with it
+        assert!(path_invalid_layout
+            .with_file_name("config.default.toml")
+            .is_file());
+        for path_config_toml in [path_invalid_toml, path_invalid_layout] {
+            std::fs::remove_dir_all(path_config_toml.parent().unwrap()).unwrap();
+        }
+    }
+
+    const SERVICE_UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const TOO_MANY_REQUESTS: &str =
+        "HTTP/1.1 429 Too Many Requests\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const UNAUTHORIZED: &str =
+        "HTTP/1.1 401 Unauthorized\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const HEALTHY: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 47\r\nconnection: close\r\n\r\n{\"jsonrpc\":\"2.0\",\"id\":\"dontcare\",\"result\":null}";
+
+    type RecordedRequests = std::sync::Arc<std::sync::Mutex<Vec<(usize, String)>>>;
+
+    /// Serves the responses in order and records the JSON RPC methods it received together with
+    /// the endpoint index. Once the responses are served, the port is closed, so the next
+    /// requests fail with a transport error.
+    fn fake_rpc_endpoint(
+        index: usize,
+        responses: Vec<&'static str>,
+        requests: &RecordedRequests,
+    ) -> (url::Url, near_jsonrpc_client::JsonRpcClient) {
+        use std::io::{BufRead, Read};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let rpc_url: url::Url = format!("http://{}/", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let requests = requests.clone();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = std::io::BufReader::new(&stream);
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                requests
+                    .lock()
+                    .unwrap()
+                    .push((index, request["method"].as_str().unwrap().to_string()));
+                (&stream).write_all(response.as_bytes()).unwrap();
+            }
+        });
+        let json_rpc_client = near_jsonrpc_client::JsonRpcClient::connect(rpc_url.as_str());
+        (rpc_url, json_rpc_client)
+    }
+
+    fn failover_json_rpc_client(
+        endpoints_responses: Vec<Vec<&'static str>>,
+        retries: u32,
+        requests: &RecordedRequests,
+    ) -> FailoverJsonRpcClient {
+        FailoverJsonRpcClient {
+            endpoints: endpoints_responses
+                .into_iter()
+                .enumerate()
+                .map(|(index, responses)| fake_rpc_endpoint(index, responses, requests))
+                .collect(),
+            retries,
+            retry_backoff: std::time::Duration::from_millis(50),
+        }
+    }
+
+    fn recorded_endpoints(requests: &RecordedRequests) -> Vec<usize> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect()
+    }
+
+    #[test]
+    fn failover_to_the_next_endpoints_in_order() {
+        let requests = RecordedRequests::default();
+        let json_rpc_client = failover_json_rpc_client(
+            vec![
+                vec![SERVICE_UNAVAILABLE],
+                vec![TOO_MANY_REQUESTS],
+                vec![HEALTHY],
+            ],
+            0,
+            &requests,
+        );
+        assert!(json_rpc_client
+            .blocking_call(near_jsonrpc_client::methods::health::RpcHealthRequest)
+            .is_ok());
+        assert_eq!(recorded_endpoints(&requests), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn no_failover_on_other_errors() {
+        let requests = RecordedRequests::default();
+        let json_rpc_client =
+            failover_json_rpc_client(vec![vec![UNAUTHORIZED], vec![HEALTHY]], 2, &requests);
+        assert!(matches!(
+            json_rpc_client.blocking_call(near_jsonrpc_client::methods::health::RpcHealthRequest),
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                    near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::Unauthorized
+                )
+            ))
+        ));
+        assert_eq!(recorded_endpoints(&requests), vec![0]);
+    }
+
+    #[test]
+    fn retry_all_endpoints_with_exponential_backoff() {
+        let requests = RecordedRequests::default();
+        // The second endpoint closes its port after the first request (a transport error)
+        let json_rpc_client = failover_json_rpc_client(
+            vec![
+                vec![SERVICE_UNAVAILABLE, SERVICE_UNAVAILABLE, HEALTHY],
+                vec![SERVICE_UNAVAILABLE],
+            ],
+            2,
+            &requests,
+        );
+        let start = std::time::Instant::now();
+        assert!(json_rpc_client
+            .blocking_call(near_jsonrpc_client::methods::health::RpcHealthRequest)
+            .is_ok());
+        // 50 ms before the first retry and 100 ms before the second one
+        assert!(start.elapsed() >= std::time::Duration::from_millis(150));
+        assert_eq!(recorded_endpoints(&requests), vec![0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn give_up_after_the_last_retry() {
+        let requests = RecordedRequests::default();
+        let json_rpc_client = failover_json_rpc_client(
+            vec![vec![SERVICE_UNAVAILABLE; 2], vec![TOO_MANY_REQUESTS; 2]],
+            1,
+            &requests,
+        );
+        assert!(matches!(
+            json_rpc_client.blocking_call(near_jsonrpc_client::methods::health::RpcHealthRequest),
+            Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(
+                    near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests
+                )
+            ))
+        ));
+        assert_eq!(recorded_endpoints(&requests), vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn failover_signed_transactions() {
+        let requests = RecordedRequests::default();
+        let json_rpc_client = failover_json_rpc_client(
+            vec![vec![SERVICE_UNAVAILABLE], vec![SERVICE_UNAVAILABLE]],
+            0,
+            &requests,
+        );
+        let signer = near_crypto::InMemorySigner::from_seed(
+            "alice.near".parse().unwrap(),
+            near_crypto::KeyType::ED25519,
+            "alice.near",
+        );
+        let signed_transaction = near_primitives::transaction::Transaction {
+            signer_id: "alice.near".parse().unwrap(),
+            public_key: signer.public_key.clone(),
+            nonce: 1,
+            receiver_id: "bob.near".parse().unwrap(),
+            block_hash: Default::default(),
+            actions: vec![],
+        }
+        .sign(&signer);
+        assert!(json_rpc_client
+            .blocking_call(
+                near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                    signed_transaction,
+                }
+            )
+            .is_err());
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                (0, "broadcast_tx_commit".to_string()),
+                (1, "broadcast_tx_commit".to_string())
+            ]
+        );
+    }
 }
@@ -1,7 +1,53 @@
+use std::str::FromStr;
+
+use color_eyre::eyre::WrapErr;
+
+pub mod migrations;
+
+/// New fields have to be optional (or have a default value), so the existing files stay valid;
+/// changes of the layout which break the existing files need a migration in [migrations].
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Config {
+    /// The layout version of config.toml
+    pub version: u32,
     pub credentials_home_dir: std::path::PathBuf,
+    /// The connection which is used when the network name is omitted
+    pub selected_connection: Option<String>,
+    /// CLI-wide preferences (`near config cli`)
+    #[serde(default)]
+    pub cli: CliSettings,
     pub network_connection: linked_hash_map::LinkedHashMap<String, NetworkConfig>,
+    /// The values of config.toml which are overridden by the environment variables in this run
+    #[serde(skip)]
+    applied_overrides: AppliedOverrides,
+}
+
+/// The settings which can be overridden by the environment variables without changing config.toml
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub credentials_home_dir: Option<std::path::PathBuf>,
+    pub selected_connection: Option<String>,
+}
+
+impl ConfigOverrides {
+    /// `NEAR_CLI_CREDENTIALS_DIR` replaces the credentials home dir, and `NEAR_CLI_NETWORK` (or
+    /// `NEAR_ENV`, which is also used by the JS near CLI) selects the network connection.
+    pub fn from_env() -> Self {
+        Self {
+            credentials_home_dir: std::env::var_os("NEAR_CLI_CREDENTIALS_DIR")
+                .map(std::path::PathBuf::from),
+            selected_connection: std::env::var("NEAR_CLI_NETWORK")
+                .or_else(|_| std::env::var("NEAR_ENV"))
+                .ok(),
+        }
+    }
+}
+
+/// The original and the overriding values of every overridden setting
+#[derive(Debug, Clone, Default)]
+struct AppliedOverrides {
+    credentials_home_dir: Option<(std::path::PathBuf, std::path::PathBuf)>,
+    selected_connection: Option<(Option<String>, String)>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -14,6 +60,111 @@ pub struct NetworkConfig {
     // https://github.com/near/near-cli-rs/issues/116
     pub linkdrop_account_id: Option<near_primitives::types::AccountId>,
     pub faucet_url: Option<url::Url>,
+    /// The signing method which is used when it is omitted for a transaction on this connection
+    pub default_signing_method: Option<SigningMethod>,
+    /// The account which is suggested as the signer on this connection
+    pub default_account_id: Option<near_primitives::types::AccountId>,
+    /// How many times the RPC endpoints are retried after all of them failed (2 by default, 0 in
+    /// scripting mode)
+    pub rpc_retries: Option<u32>,
+    /// The delay before the first retry, doubled for every next one (500 ms by default)
+    pub rpc_retry_backoff_ms: Option<u64>,
+    /// The timeout of every RPC and faucet request
+    pub request_timeout_secs: Option<u64>,
+    /// The HTTP(S) proxy for the RPC and faucet requests
+    pub proxy_url: Option<url::Url>,
+    /// PEM files with the root certificates which are trusted in addition to the system ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_root_certificates: Vec<std::path::PathBuf>,
+    /// The HTTP headers which are sent with every RPC and faucet request
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub http_headers: std::collections::BTreeMap<String, String>,
+    /// The authorization which is sent with every RPC and faucet request
+    pub auth: Option<HttpAuth>,
+    /// The RPC endpoints which are tried in order when `rpc_url` is unavailable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_rpc_endpoints: Vec<RpcEndpoint>,
+    /// Whether near CLI runs in scripting mode in this run, where the RPC requests are not retried
+    /// unless `rpc_retries` is set
+    #[serde(skip)]
+    pub scripting_mode: bool,
+    /// The HTTP client built from the settings above, shared by all the requests of this run
+    #[serde(skip)]
+    pub http_client: std::sync::Arc<once_cell::sync::OnceCell<reqwest::Client>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "scheme", rename_all = "kebab-case")]
+pub enum HttpAuth {
+    Bearer {
+        token: String,
+    },
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+}
+
+impl HttpAuth {
+    fn header_value(&self) -> String {
+        match self {
+            Self::Bearer { token } => format!("Bearer {}", token),
+            Self::Basic { username, password } => format!(
+                "Basic {}",
+                base64::encode(format!(
+                    "{}:{}",
+                    username,
+                    password.as_deref().unwrap_or_default()
+                ))
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RpcEndpoint {
+    pub rpc_url: url::Url,
+    pub rpc_api_key: Option<crate::types::api_key::ApiKey>,
+}
+
+/// The signing methods which don't need any extra arguments, so they can be used by default.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SigningMethod {
+    SignWithMacosKeychain,
+    SignWithKeychain,
+    SignWithLedger,
+}
+
+impl interactive_clap::ToCli for SigningMethod {
+    type CliVariant = SigningMethod;
+}
+
+impl std::str::FromStr for SigningMethod {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sign-with-macos-keychain" => Ok(Self::SignWithMacosKeychain),
+            "sign-with-keychain" => Ok(Self::SignWithKeychain),
+            "sign-with-ledger" => Ok(Self::SignWithLedger),
+            _ => Err(format!(
+                "SigningMethod: incorrect value entered <{}> (expected sign-with-macos-keychain, sign-with-keychain or sign-with-ledger)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for SigningMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SignWithMacosKeychain => write!(f, "sign-with-macos-keychain"),
+            Self::SignWithKeychain => write!(f, "sign-with-keychain"),
+            Self::SignWithLedger => write!(f, "sign-with-ledger"),
+        }
+    }
 }
 
 impl Default for Config {
@@ -35,6 +186,18 @@ impl Default for Config {
                 rpc_api_key: None,
                 linkdrop_account_id: Some("near".parse().unwrap()),
                 faucet_url: None,
+                default_signing_method: None,
+                default_account_id: None,
+                rpc_retries: None,
+                rpc_retry_backoff_ms: None,
+                request_timeout_secs: None,
+                proxy_url: None,
+                extra_root_certificates: Vec::new(),
+                http_headers: std::collections::BTreeMap::new(),
+                auth: None,
+                fallback_rpc_endpoints: Vec::new(),
+                scripting_mode: false,
+                http_client: Default::default(),
             },
         );
         network_connection.insert(
@@ -49,23 +212,509 @@ impl Default for Config {
                 rpc_api_key: None,
                 linkdrop_account_id: Some("testnet".parse().unwrap()),
                 faucet_url: Some("https://helper.nearprotocol.com/account".parse().unwrap()),
+                default_signing_method: None,
+                default_account_id: None,
+                rpc_retries: None,
+                rpc_retry_backoff_ms: None,
+                request_timeout_secs: None,
+                proxy_url: None,
+                extra_root_certificates: Vec::new(),
+                http_headers: std::collections::BTreeMap::new(),
+                auth: None,
+                fallback_rpc_endpoints: Vec::new(),
+                scripting_mode: false,
+                http_client: Default::default(),
             },
         );
         Self {
+            version: migrations::CONFIG_VERSION,
             credentials_home_dir,
+            selected_connection: None,
+            cli: CliSettings::default(),
             network_connection,
+            applied_overrides: AppliedOverrides::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Returns the name and the config of the selected connection, if it still exists.
+    pub fn selected_network_config(&self) -> Option<(&String, &NetworkConfig)> {
+        let selected_connection = self.selected_connection.as_ref()?;
+        self.network_connection
+            .get(selected_connection)
+            .map(|network_config| (selected_connection, network_config))
+    }
+
+    /// Scripts handle the failures themselves, so the RPC requests of the connections are not
+    /// retried in scripting mode unless `rpc_retries` is set.
+    pub fn set_scripting_mode(&mut self, scripting_mode: bool) {
+        for (_, network_config) in self.network_connection.iter_mut() {
+            network_config.scripting_mode = scripting_mode;
+        }
+    }
+
+    /// Applies the overrides to this run only; the original values are kept to be written back
+    /// to config.toml. A selected connection which does not exist is reported only when a command
+    /// uses it (see [Config::missing_overridden_connection]).
+    pub fn apply_overrides(&mut self, overrides: ConfigOverrides) {
+        let mut applied_overrides = AppliedOverrides::default();
+        if let Some(credentials_home_dir) = overrides.credentials_home_dir {
+            applied_overrides.credentials_home_dir = Some((
+                std::mem::replace(&mut self.credentials_home_dir, credentials_home_dir.clone()),
+                credentials_home_dir,
+            ));
+        }
+        if let Some(selected_connection) = overrides.selected_connection {
+            applied_overrides.selected_connection = Some((
+                std::mem::replace(
+                    &mut self.selected_connection,
+                    Some(selected_connection.clone()),
+                ),
+                selected_connection,
+            ));
+        }
+        self.applied_overrides = applied_overrides;
+    }
+
+    /// The connection selected by `NEAR_CLI_NETWORK` or `NEAR_ENV` which does not exist in
+    /// config.toml.
+    pub fn missing_overridden_connection(&self) -> Option<&str> {
+        let (_, overriding_value) = self.applied_overrides.selected_connection.as_ref()?;
+        (self.selected_connection.as_ref() == Some(overriding_value)
+            && !self.network_connection.contains_key(overriding_value))
+        .then_some(overriding_value.as_str())
+    }
+
+    /// Selects the connection in config.toml, even if it is the one selected by the override.
+    pub fn select_connection(&mut self, connection_name: String) {
+        self.selected_connection = Some(connection_name);
+        self.applied_overrides.selected_connection = None;
+    }
+
+    /// Restores the values overridden by [Config::apply_overrides], unless they were changed
+    /// since then (e.g. by [Config::select_connection]).
+    pub fn without_overrides(mut self) -> Self {
+        let applied_overrides = std::mem::take(&mut self.applied_overrides);
+        if let Some((credentials_home_dir, overriding_value)) =
+            applied_overrides.credentials_home_dir
+        {
+            if self.credentials_home_dir == overriding_value {
+                self.credentials_home_dir = credentials_home_dir;
+            }
+        }
+        if let Some((selected_connection, overriding_value)) = applied_overrides.selected_connection
+        {
+            if self.selected_connection.as_ref() == Some(&overriding_value) {
+                self.selected_connection = selected_connection;
+            }
+        }
+        self
+    }
+}
+
+/// CLI-wide preferences stored in the `[cli]` section of config.toml. The settings which are not
+/// set fall back to the built-in defaults.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CliSettings {
+    pub output_format: Option<crate::common::OutputFormat>,
+    pub function_call_gas: Option<String>,
+    pub function_call_deposit: Option<String>,
+    pub token_transfer_deposit: Option<String>,
+    pub update_check: Option<bool>,
+    pub color: Option<bool>,
+    /// Network names (e.g. mainnet) for which near CLI asks for a confirmation before signing a
+    /// transaction
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub confirm_transactions: std::collections::BTreeMap<String, bool>,
+}
+
+impl CliSettings {
+    pub fn output_format(&self) -> crate::common::OutputFormat {
+        self.output_format.clone().unwrap_or_default()
+    }
+
+    pub fn function_call_gas(&self) -> &str {
+        self.function_call_gas.as_deref().unwrap_or("100 TeraGas")
+    }
+
+    pub fn function_call_deposit(&self) -> &str {
+        self.function_call_deposit.as_deref().unwrap_or("0 NEAR")
+    }
+
+    pub fn token_transfer_deposit(&self) -> &str {
+        self.token_transfer_deposit
+            .as_deref()
+            .unwrap_or("1 yoctoNEAR")
+    }
+
+    pub fn update_check(&self) -> bool {
+        self.update_check.unwrap_or(true)
+    }
+
+    pub fn color(&self) -> bool {
+        self.color.unwrap_or(true)
+    }
+
+    pub fn confirm_transactions(&self, network_name: &str) -> bool {
+        self.confirm_transactions
+            .get(network_name)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    pub fn get(&self, key: &CliSettingKey) -> String {
+        match key {
+            CliSettingKey::OutputFormat => self.output_format().to_string(),
+            CliSettingKey::FunctionCallGas => self.function_call_gas().to_string(),
+            CliSettingKey::FunctionCallDeposit => self.function_call_deposit().to_string(),
+            CliSettingKey::TokenTransferDeposit => self.token_transfer_deposit().to_string(),
+            CliSettingKey::UpdateCheck => self.update_check().to_string(),
+            CliSettingKey::Color => self.color().to_string(),
+            CliSettingKey::ConfirmTransactions(network_name) => {
+                self.confirm_transactions(network_name).to_string()
+            }
+        }
+    }
+
+    pub fn is_set(&self, key: &CliSettingKey) -> bool {
+        match key {
+            CliSettingKey::OutputFormat => self.output_format.is_some(),
+            CliSettingKey::FunctionCallGas => self.function_call_gas.is_some(),
+            CliSettingKey::FunctionCallDeposit => self.function_call_deposit.is_some(),
+            CliSettingKey::TokenTransferDeposit => self.token_transfer_deposit.is_some(),
+            CliSettingKey::UpdateCheck => self.update_check.is_some(),
+            CliSettingKey::Color => self.color.is_some(),
+            CliSettingKey::ConfirmTransactions(network_name) => {
+                self.confirm_transactions.contains_key(network_name)
+            }
+        }
+    }
+
+    /// Validates the value and stores it.
+    pub fn set(&mut self, key: &CliSettingKey, value: &str) -> Result<(), String> {
+        match key {
+            CliSettingKey::OutputFormat => {
+                self.output_format = Some(value.parse().map_err(|_| {
+                    format!(
+                        "<{}> is not an output format (expected plaintext or json)",
+                        value
+                    )
+                })?)
+            }
+            CliSettingKey::FunctionCallGas => {
+                crate::common::NearGas::from_str(value)?;
+                self.function_call_gas = Some(value.trim().to_string())
+            }
+            CliSettingKey::FunctionCallDeposit => {
+                crate::common::NearBalance::from_str(value)?;
+                self.function_call_deposit = Some(value.trim().to_string())
+            }
+            CliSettingKey::TokenTransferDeposit => {
+                crate::common::NearBalance::from_str(value)?;
+                self.token_transfer_deposit = Some(value.trim().to_string())
+            }
+            CliSettingKey::UpdateCheck => self.update_check = Some(parse_switch(value)?),
+            CliSettingKey::Color => self.color = Some(parse_switch(value)?),
+            CliSettingKey::ConfirmTransactions(network_name) => {
+                self.confirm_transactions
+                    .insert(network_name.clone(), parse_switch(value)?);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn unset(&mut self, key: &CliSettingKey) {
+        match key {
+            CliSettingKey::OutputFormat => self.output_format = None,
+            CliSettingKey::FunctionCallGas => self.function_call_gas = None,
+            CliSettingKey::FunctionCallDeposit => self.function_call_deposit = None,
+            CliSettingKey::TokenTransferDeposit => self.token_transfer_deposit = None,
+            CliSettingKey::UpdateCheck => self.update_check = None,
+            CliSettingKey::Color => self.color = None,
+            CliSettingKey::ConfirmTransactions(network_name) => {
+                self.confirm_transactions.remove(network_name);
+            }
+        }
+    }
+
+    /// All the known keys, including `confirm-transactions.<network-name>` for every network name
+    /// which is configured.
+    pub fn keys(&self, config: &Config) -> Vec<CliSettingKey> {
+        let mut network_names = config
+            .network_connection
+            .values()
+            .map(|network_config| network_config.network_name.clone())
+            .chain(self.confirm_transactions.keys().cloned())
+            .collect::<Vec<_>>();
+        network_names.sort();
+        network_names.dedup();
+        [
+            CliSettingKey::OutputFormat,
+            CliSettingKey::FunctionCallGas,
+            CliSettingKey::FunctionCallDeposit,
+            CliSettingKey::TokenTransferDeposit,
+            CliSettingKey::UpdateCheck,
+            CliSettingKey::Color,
+        ]
+        .into_iter()
+        .chain(
+            network_names
+                .into_iter()
+                .map(CliSettingKey::ConfirmTransactions),
+        )
+        .collect()
+    }
+}
+
+fn parse_switch(value: &str) -> Result<bool, String> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "on" | "yes" | "enabled" => Ok(true),
+        "false" | "off" | "no" | "disabled" => Ok(false),
+        _ => Err(format!(
+            "<{}> is not a switch (expected true or false)",
+            value
+        )),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliSettingKey {
+    /// Output format used when `--output-format` is omitted
+    OutputFormat,
+    /// Gas suggested for function calls
+    FunctionCallGas,
+    /// Deposit suggested for function calls
+    FunctionCallDeposit,
+    /// Deposit suggested for FT and NFT transfers
+    TokenTransferDeposit,
+    /// Whether near CLI checks for a new version
+    UpdateCheck,
+    /// Whether the errors and notices are colored
+    Color,
+    /// Whether near CLI asks for a confirmation before signing a transaction on the network
+    ConfirmTransactions(String),
+}
+
+impl interactive_clap::ToCli for CliSettingKey {
+    type CliVariant = CliSettingKey;
+}
+
+impl std::str::FromStr for CliSettingKey {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "output-format" => Ok(Self::OutputFormat),
+            "function-call-gas" => Ok(Self::FunctionCallGas),
+            "function-call-deposit" => Ok(Self::FunctionCallDeposit),
+            "token-transfer-deposit" => Ok(Self::TokenTransferDeposit),
+            "update-check" => Ok(Self::UpdateCheck),
+            "color" => Ok(Self::Color),
+            _ => match s.split_once('.') {
+                Some(("confirm-transactions", network_name)) if !network_name.is_empty() => {
+                    Ok(Self::ConfirmTransactions(network_name.to_string()))
+                }
+                _ => Err(format!(
+                    "CliSettingKey: incorrect value entered <{}> (expected output-format, function-call-gas, function-call-deposit, token-transfer-deposit, update-check, color or confirm-transactions.<network-name>)",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for CliSettingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OutputFormat => write!(f, "output-format"),
+            Self::FunctionCallGas => write!(f, "function-call-gas"),
+            Self::FunctionCallDeposit => write!(f, "function-call-deposit"),
+            Self::TokenTransferDeposit => write!(f, "token-transfer-deposit"),
+            Self::UpdateCheck => write!(f, "update-check"),
+            Self::Color => write!(f, "color"),
+            Self::ConfirmTransactions(network_name) => {
+                write!(f, "confirm-transactions.{}", network_name)
+            }
         }
     }
 }
 
 impl NetworkConfig {
-    pub fn json_rpc_client(&self) -> near_jsonrpc_client::JsonRpcClient {
-        let mut json_rpc_client =
-            near_jsonrpc_client::JsonRpcClient::connect(self.rpc_url.as_ref());
-        if let Some(rpc_api_key) = &self.rpc_api_key {
+    /// Whether both configs are copies of the same connection of config.toml, which may differ
+    /// from other connections to the same network only by the name. The copies of a connection
+    /// share its HTTP client.
+    pub fn is_same_connection(&self, other: &NetworkConfig) -> bool {
+        std::sync::Arc::ptr_eq(&self.http_client, &other.http_client)
+    }
+
+    /// `rpc_url` followed by the fallback endpoints, in the order they are tried.
+    pub fn rpc_endpoints(&self) -> Vec<RpcEndpoint> {
+        std::iter::once(RpcEndpoint {
+            rpc_url: self.rpc_url.clone(),
+            rpc_api_key: self.rpc_api_key.clone(),
+        })
+        .chain(self.fallback_rpc_endpoints.iter().cloned())
+        .collect()
+    }
+
+    /// The HTTP client for the RPC and faucet requests with the headers, the authorization, the
+    /// timeout, the proxy and the root certificates of this connection. It is built once, when it
+    /// is first needed, so invalid settings only break the commands which use this connection.
+    pub fn http_client(&self) -> color_eyre::eyre::Result<reqwest::Client> {
+        self.http_client
+            .get_or_try_init(|| {
+                self.build_http_client().wrap_err_with(|| {
+                    format!(
+                        "Invalid HTTP settings of the network connection to <{}> in config.toml",
+                        self.network_name
+                    )
+                })
+            })
+            .cloned()
+    }
+
+    fn build_http_client(&self) -> color_eyre::eyre::Result<reqwest::Client> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (name, value) in &self.http_headers {
+            headers.insert(
+                reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                    .wrap_err_with(|| format!("Invalid HTTP header name <{}>", name))?,
+                reqwest::header::HeaderValue::from_str(value)
+                    .wrap_err_with(|| format!("Invalid value of the HTTP header <{}>", name))?,
+            );
+        }
+        if let Some(auth) = &self.auth {
+            let mut authorization = reqwest::header::HeaderValue::from_str(&auth.header_value())
+                .wrap_err("Invalid authorization credentials")?;
+            authorization.set_sensitive(true);
+            headers.insert(reqwest::header::AUTHORIZATION, authorization);
+        }
+
+        let mut http_client = reqwest::Client::builder().default_headers(headers);
+        if let Some(request_timeout_secs) = self.request_timeout_secs {
+            http_client = http_client.timeout(std::time::Duration::from_secs(request_timeout_secs));
+        }
+        if let Some(proxy_url) = &self.proxy_url {
+            http_client = http_client.proxy(
+                reqwest::Proxy::all(proxy_url.clone())
+                    .wrap_err_with(|| format!("Invalid proxy URL <{}>", proxy_url))?,
+            );
+        }
+        for path in &self.extra_root_certificates {
+            let certificate = std::fs::read(path)
+                .wrap_err_with(|| format!("Failed to read the root certificate from {:?}", path))?;
+            http_client = http_client.add_root_certificate(
+                reqwest::Certificate::from_pem(&certificate)
+                    .wrap_err_with(|| format!("Invalid PEM root certificate in {:?}", path))?,
+            );
+        }
+        http_client
+            .build()
+            .wrap_err("Failed to set up the HTTP client")
+    }
+
+    pub fn connect_rpc_endpoint(
+        &self,
+        rpc_endpoint: &RpcEndpoint,
+    ) -> color_eyre::eyre::Result<near_jsonrpc_client::JsonRpcClient> {
+        let mut json_rpc_client = near_jsonrpc_client::JsonRpcClient::with(self.http_client()?)
+            .connect(rpc_endpoint.rpc_url.as_ref());
+        if let Some(rpc_api_key) = &rpc_endpoint.rpc_api_key {
             json_rpc_client =
                 json_rpc_client.header(near_jsonrpc_client::auth::ApiKey::from(rpc_api_key.clone()))
         };
-        json_rpc_client
+        Ok(json_rpc_client)
+    }
+
+    pub fn json_rpc_client(
+        &self,
+    ) -> color_eyre::eyre::Result<crate::common::FailoverJsonRpcClient> {
+        Ok(crate::common::FailoverJsonRpcClient {
+            endpoints: self
+                .rpc_endpoints()
+                .into_iter()
+                .map(|rpc_endpoint| {
+                    let json_rpc_client = self.connect_rpc_endpoint(&rpc_endpoint)?;
+                    Ok((rpc_endpoint.rpc_url, json_rpc_client))
+                })
+                .collect::<color_eyre::eyre::Result<_>>()?,
+            retries: self
+                .rpc_retries
+                .unwrap_or(if self.scripting_mode { 0 } else { 2 }),
+            retry_backoff: std::time::Duration::from_millis(
+                self.rpc_retry_backoff_ms.unwrap_or(500),
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(selected_connection: &str) -> ConfigOverrides {
+        ConfigOverrides {
+            credentials_home_dir: Some(std::path::PathBuf::from("/tmp/near-credentials")),
+            selected_connection: Some(selected_connection.to_string()),
+        }
+    }
+
+    #[test]
+    fn overrides_are_not_written_back() {
+        let original_config = Config::default();
+        let mut config = original_config.clone();
+        config.apply_overrides(overrides("mainnet"));
+        assert_eq!(
+            config.credentials_home_dir,
+            std::path::PathBuf::from("/tmp/near-credentials")
+        );
+        assert_eq!(config.selected_connection.as_deref(), Some("mainnet"));
+
+        let config = config.without_overrides();
+        assert_eq!(
+            config.credentials_home_dir,
+            original_config.credentials_home_dir
+        );
+        assert_eq!(
+            config.selected_connection,
+            original_config.selected_connection
+        );
+    }
+
+    #[test]
+    fn changed_values_are_written_back() {
+        let mut config = Config::default();
+        config.apply_overrides(overrides("mainnet"));
+        config.select_connection("testnet".to_string());
+        assert_eq!(
+            config.without_overrides().selected_connection.as_deref(),
+            Some("testnet")
+        );
+
+        // Selecting the connection which is selected by the override as well
+        let mut config = Config::default();
+        config.apply_overrides(overrides("mainnet"));
+        config.select_connection("mainnet".to_string());
+        let config = config.without_overrides();
+        assert_eq!(config.selected_connection.as_deref(), Some("mainnet"));
+        assert_eq!(
+            config.credentials_home_dir,
+            Config::default().credentials_home_dir
+        );
+    }
+
+    #[test]
+    fn missing_overridden_connection() {
+        let mut config = Config::default();
+        assert_eq!(config.missing_overridden_connection(), None);
+        config.apply_overrides(overrides("localnet"));
+        assert_eq!(config.missing_overridden_connection(), Some("localnet"));
+        config.select_connection("testnet".to_string());
+        assert_eq!(config.missing_overridden_connection(), None);
+
+        let mut config = Config::default();
+        config.apply_overrides(overrides("testnet"));
+        assert_eq!(config.missing_overridden_connection(), None);
     }
 }
@@ -0,0 +1,200 @@
+//! Every migration upgrades the raw TOML document of config.toml by one layout version, so the
+//! files written by any older release of near CLI can still be loaded.
+
+/// The layout version written by this release.
+pub const CONFIG_VERSION: u32 = 2;
+
+type Migration = fn(toml::value::Table) -> color_eyre::eyre::Result<toml::value::Table>;
+
+/// `MIGRATIONS[i]` upgrades the layout from version `i + 1` to version `i + 2`.
+const MIGRATIONS: [Migration; (CONFIG_VERSION - 1) as usize] = [migrate_v1_to_v2];
+
+/// Version 1 is the layout without the `version` field. Everything that was added in version 2
+/// (the selected connection, the `[cli]` section, the default signer and account of a connection)
+/// is optional, so only the version needs to be set.
+fn migrate_v1_to_v2(mut table: toml::value::Table) -> color_eyre::eyre::Result<toml::value::Table> {
+    table.insert("version".to_string(), toml::Value::Integer(2));
+    Ok(table)
+}
+
+pub fn config_version(table: &toml::value::Table) -> color_eyre::eyre::Result<u32> {
+    match table.get("version") {
+        None => Ok(1),
+        Some(toml::Value::Integer(version)) if *version >= 1 => Ok(*version as u32),
+        Some(version) => color_eyre::eyre::bail!("Unexpected config version <{}>", version),
+    }
+}
+
+pub fn migrate(
+    mut table: toml::value::Table,
+    from_version: u32,
+) -> color_eyre::eyre::Result<toml::value::Table> {
+    for migration in &MIGRATIONS[(from_version - 1) as usize..] {
+        table = migration(table)?;
+    }
+    Ok(table)
+}
+
+const REQUIRED_FIELDS: [&str; 3] = ["version", "credentials_home_dir", "network_connection"];
+const OPTIONAL_FIELDS: [&str; 2] = ["selected_connection", "cli"];
+const REQUIRED_CONNECTION_FIELDS: [&str; 4] = [
+    "network_name",
+    "rpc_url",
+    "wallet_url",
+    "explorer_transaction_url",
+];
+const OPTIONAL_CONNECTION_FIELDS: [&str; 13] = [
+    "rpc_api_key",
+    "linkdrop_account_id",
+    "faucet_url",
+    "default_signing_method",
+    "default_account_id",
+    "rpc_retries",
+    "rpc_retry_backoff_ms",
+    "request_timeout_secs",
+    "proxy_url",
+    "extra_root_certificates",
+    "http_headers",
+    "auth",
+    "fallback_rpc_endpoints",
+];
+
+/// Lists how the fields of the document differ from the layout of this release.
+pub fn layout_differences(table: &toml::value::Table) -> Vec<String> {
+    let mut differences = fields_differences("", table, &REQUIRED_FIELDS, &OPTIONAL_FIELDS);
+    match table.get("network_connection") {
+        Some(toml::Value::Table(network_connection)) => {
+            for (connection_name, network_config) in network_connection {
+                match network_config {
+                    toml::Value::Table(network_config) => differences.extend(fields_differences(
+                        &format!("network_connection.{}.", connection_name),
+                        network_config,
+                        &REQUIRED_CONNECTION_FIELDS,
+                        &OPTIONAL_CONNECTION_FIELDS,
+                    )),
+                    _ => differences.push(format!(
+                        "`network_connection.{}` is expected to be a table",
+                        connection_name
+                    )),
+                }
+            }
+        }
+        Some(_) => differences.push("`network_connection` is expected to be a table".to_string()),
+        None => {}
+    }
+    differences
+}
+
+fn fields_differences(
+    prefix: &str,
+    table: &toml::value::Table,
+    required_fields: &[&str],
+    optional_fields: &[&str],
+) -> Vec<String> {
+    let missing_fields = required_fields
+        .iter()
+        .filter(|field| !table.contains_key(**field))
+        .map(|field| format!("missing field `{}{}`", prefix, field));
+    let unknown_fields = table
+        .keys()
+        .filter(|field| {
+            !required_fields.contains(&field.as_str()) && !optional_fields.contains(&field.as_str())
+        })
+        .map(|field| format!("unknown field `{}{}` (it is ignored)", prefix, field));
+    missing_fields.chain(unknown_fields).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_CONFIG: &str = r#"
+credentials_home_dir = "/home/user/.near-credentials"
+
+[network_connection.testnet]
+network_name = "testnet"
+rpc_url = "https://archival-rpc.testnet.near.org/"
+wallet_url = "https://wallet.testnet.near.org/"
+explorer_transaction_url = "https://explorer.testnet.near.org/transactions/"
+linkdrop_account_id = "testnet"
+faucet_url = "https://helper.nearprotocol.com/account"
+"#;
+
+    #[test]
+    fn config_version_defaults_to_1() {
+        let mut table: toml::value::Table = toml::from_str(V1_CONFIG).unwrap();
+        assert_eq!(config_version(&table).unwrap(), 1);
+        table.insert("version".to_string(), toml::Value::Integer(3));
+        assert_eq!(config_version(&table).unwrap(), 3);
+        for version in [
+            toml::Value::Integer(0),
+            toml::Value::Integer(-1),
+            toml::Value::String("2".to_string()),
+        ] {
+            table.insert("version".to_string(), version);
+            assert!(config_version(&table).is_err());
+        }
+    }
+
+    #[test]
+    fn migrate_v1_config() {
+        let table: toml::value::Table = toml::from_str(V1_CONFIG).unwrap();
+        let migrated_table = migrate(table.clone(), 1).unwrap();
+        assert_eq!(config_version(&migrated_table).unwrap(), CONFIG_VERSION);
+        assert!(layout_differences(&migrated_table).is_empty());
+        let mut expected_table = table;
+        expected_table.insert(
+            "version".to_string(),
+            toml::Value::Integer(CONFIG_VERSION.into()),
+        );
+        assert_eq!(migrated_table, expected_table);
+        assert!(toml::Value::Table(migrated_table)
+            .try_into::<crate::config::Config>()
+            .is_ok());
+    }
+
+    #[test]
+    fn migrate_current_config_is_noop() {
+        let mut table: toml::value::Table = toml::from_str(V1_CONFIG).unwrap();
+        table.insert(
+            "version".to_string(),
+            toml::Value::Integer(CONFIG_VERSION.into()),
+        );
+        assert_eq!(migrate(table.clone(), CONFIG_VERSION).unwrap(), table);
+    }
+
+    #[test]
+    fn layout_differences_lists_missing_and_unknown_fields() {
+        let mut table = migrate(toml::from_str(V1_CONFIG).unwrap(), 1).unwrap();
+        table.remove("credentials_home_dir");
+        table.insert("colour".to_string(), toml::Value::Boolean(true));
+        let network_connection = table
+            .get_mut("network_connection")
+            .and_then(toml::Value::as_table_mut)
+            .unwrap();
+        let testnet = network_connection
+            .get_mut("testnet")
+            .and_then(toml::Value::as_table_mut)
+            .unwrap();
+        testnet.remove("rpc_url");
+        testnet.insert("rpc_uri".to_string(), toml::Value::Boolean(true));
+        network_connection.insert("broken".to_string(), toml::Value::Integer(1));
+        assert_eq!(
+            layout_differences(&table),
+            vec![
+                "missing field `credentials_home_dir`",
+                "unknown field `colour` (it is ignored)",
+                "`network_connection.broken` is expected to be a table",
+                "missing field `network_connection.testnet.rpc_url`",
+                "unknown field `network_connection.testnet.rpc_uri` (it is ignored)",
+            ]
+        );
+
+        table.insert(
+            "network_connection".to_string(),
+            toml::Value::String("testnet".to_string()),
+        );
+        assert!(layout_differences(&table)
+            .contains(&"`network_connection` is expected to be a table".to_string()));
+    }
+}
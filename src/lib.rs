@@ -11,4 +11,4 @@ pub mod transaction_signature_options;
 pub mod types;
 pub mod utils_command;
 
-pub type GlobalContext = (crate::config::Config,);
+pub type GlobalContext = (crate::config::Config, crate::common::GlobalOptions);
@@ -16,35 +16,24 @@ mod types;
 mod utils_command;
 
 pub use common::CliResult;
-pub type GlobalContext = (crate::config::Config,);
-
-#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
-#[interactive_clap(context = crate::GlobalContext)]
-struct Cmd {
-    #[interactive_clap(subcommand)]
-    top_level: crate::commands::TopLevelCommand,
-}
+// interactive_clap only builds the `Cli*` types, so the commands are reachable from the crate root
+// only through the root command
+pub use crate::commands::{CliCmd, Cmd};
+pub type GlobalContext = (crate::config::Config, crate::common::GlobalOptions);
 
 fn main() -> crate::common::CliResult {
-    let config = crate::common::get_config_toml()?;
-
-    color_eyre::install()?;
-
-    #[cfg(feature = "self-update")]
-    let handle = std::thread::spawn(|| -> color_eyre::eyre::Result<String> {
-        crate::commands::extensions::self_update::get_latest_version()
-    });
+    let (scripting_mode, args) = crate::common::extract_scripting_mode_flags(std::env::args_os());
 
     let near_cli_exec_path = std::env::args()
         .next()
         .unwrap_or_else(|| "./near".to_owned());
 
-    let cli = match Cmd::try_parse() {
+    let cli = match CliCmd::try_parse_from(&args) {
         Ok(cli) => cli,
         Err(error) => {
             match error.kind() {
                 clap::error::ErrorKind::DisplayHelp | clap::error::ErrorKind::DisplayVersion => {}
-                _ => match crate::js_command_match::JsCmd::try_parse() {
+                _ => match crate::js_command_match::JsCmd::try_parse_from(&args) {
                     Ok(js_cmd) => {
                         match js_cmd.rust_command_generation() {
                             Ok(vec_cmd) => {
@@ -82,36 +71,86 @@ fn main() -> crate::common::CliResult {
         }
     };
 
-    let cli_cmd = match <Cmd as interactive_clap::FromCli>::from_cli(Some(cli), (config,)) {
-        interactive_clap::ResultFromCli::Ok(cli_cmd)
-        | interactive_clap::ResultFromCli::Cancel(Some(cli_cmd)) => {
-            eprintln!(
-                "Your console command:\n{}",
-                shell_words::join(
-                    std::iter::once(&near_cli_exec_path).chain(&cli_cmd.to_cli_args())
-                )
-            );
-            Ok(Some(cli_cmd))
-        }
-        interactive_clap::ResultFromCli::Cancel(None) => {
-            eprintln!("Goodbye!");
-            Ok(None)
-        }
-        interactive_clap::ResultFromCli::Back => {
-            unreachable!("TopLevelCommand does not have back option");
-        }
-        interactive_clap::ResultFromCli::Err(optional_cli_cmd, err) => {
-            if let Some(cli_cmd) = optional_cli_cmd {
-                eprintln!(
-                    "Your console command:\n{}",
-                    shell_words::join(
-                        std::iter::once(&near_cli_exec_path).chain(&cli_cmd.to_cli_args())
-                    )
-                );
-            }
-            Err(err)
+    let config_path = crate::common::config_path(cli.config.clone().map(Into::into));
+    let mut config = crate::common::get_config_toml(config_path.as_deref())?;
+    config.apply_overrides(crate::config::ConfigOverrides::from_env());
+    // The config commands are how a missing connection gets fixed, so they only warn about it
+    if let (Some(connection_name), Some(crate::commands::CliTopLevelCommand::Config(_))) =
+        (config.missing_overridden_connection(), &cli.top_level)
+    {
+        eprintln!(
+            "Warning: {}",
+            crate::common::missing_overridden_connection_error(connection_name)
+        );
+    }
+    config.set_scripting_mode(scripting_mode);
+    let cli_settings = config.cli.clone();
+
+    if cli_settings.color() {
+        color_eyre::install()?;
+    } else {
+        color_eyre::config::HookBuilder::default()
+            .theme(color_eyre::config::Theme::new())
+            .install()?;
+    }
+
+    // There is no one to read the update notice in scripting mode, so we don't wait for it, and
+    // the completion script is loaded in every new shell, which must not wait for GitHub either
+    #[cfg(feature = "self-update")]
+    let handle = (!scripting_mode
+        && cli_settings.update_check()
+        && !matches!(
+            cli.top_level,
+            Some(crate::commands::CliTopLevelCommand::Completions(_))
+        ))
+    .then(|| {
+        std::thread::spawn(|| -> color_eyre::eyre::Result<String> {
+            crate::commands::extensions::self_update::get_latest_version()
+        })
+    });
+
+    if scripting_mode {
+        if let Err(error) = crate::common::scripting_mode_command(
+            <CliCmd as clap::CommandFactory>::command(),
+            &config,
+        )
+        .try_get_matches_from(&args)
+        {
+            error.exit();
         }
+    }
+
+    let global_options = crate::common::GlobalOptions {
+        output_format: cli
+            .output_format
+            .clone()
+            .unwrap_or_else(|| cli_settings.output_format()),
+        scripting_mode,
+        config_path,
     };
+    let output_format = global_options.output_format.clone();
+
+    let cli_cmd =
+        match <Cmd as interactive_clap::FromCli>::from_cli(Some(cli), (config, global_options)) {
+            interactive_clap::ResultFromCli::Ok(cli_cmd)
+            | interactive_clap::ResultFromCli::Cancel(Some(cli_cmd)) => {
+                print_console_command(&near_cli_exec_path, &cli_cmd);
+                Ok(Some(cli_cmd))
+            }
+            interactive_clap::ResultFromCli::Cancel(None) => {
+                eprintln!("Goodbye!");
+                Ok(None)
+            }
+            interactive_clap::ResultFromCli::Back => {
+                unreachable!("TopLevelCommand does not have back option");
+            }
+            interactive_clap::ResultFromCli::Err(optional_cli_cmd, err) => {
+                if let Some(cli_cmd) = optional_cli_cmd {
+                    print_console_command(&near_cli_exec_path, &cli_cmd);
+                }
+                Err(err)
+            }
+        };
 
     #[cfg(feature = "self-update")]
     // We don't need to check the version if user has just called self-update
@@ -127,9 +166,10 @@ fn main() -> crate::common::CliResult {
                     ),
                 },
             )),
+            ..
         }))
     ) {
-        if let Ok(Ok(latest_version)) = handle.join() {
+        if let Some(Ok(Ok(latest_version))) = handle.map(|handle| handle.join()) {
             let current_version = semver::Version::parse(self_update::cargo_crate_version!())
                 .wrap_err("Failed to parse current version of `near` CLI")?;
 
@@ -138,10 +178,18 @@ fn main() -> crate::common::CliResult {
 
             if current_version < latest_version {
                 eprintln!();
-                eprintln!(
-                    "`near` CLI has a new update available \x1b[2m{current_version}\x1b[0m →  \x1b[32m{latest_version}\x1b[0m"
-                );
+                if cli_settings.color() {
+                    eprintln!(
+                        "`near` CLI has a new update available \x1b[2m{current_version}\x1b[0m →  \x1b[32m{latest_version}\x1b[0m"
+                    );
+                } else {
+                    eprintln!(
+                        "`near` CLI has a new update available {current_version} →  {latest_version}"
+                    );
+                }
                 let self_update_cli_cmd = CliCmd {
+                    output_format: None,
+                    config: None,
                     top_level:
                         Some(crate::commands::CliTopLevelCommand::Extensions(
                             crate::commands::extensions::CliExtensionsCommands {
@@ -163,5 +211,21 @@ fn main() -> crate::common::CliResult {
         }
     };
 
-    cli_cmd.map(|_| ())
+    if let Err(err) = cli_cmd {
+        std::process::exit(crate::common::report_error(&err, &output_format));
+    }
+    Ok(())
+}
+
+fn print_console_command(near_cli_exec_path: &str, cli_cmd: &CliCmd) {
+    // The completion script is usually sourced straight from the shell profile, so nothing else is printed
+    if let Some(crate::commands::CliTopLevelCommand::Completions(_)) = cli_cmd.top_level {
+        return;
+    }
+    eprintln!(
+        "Your console command:\n{}",
+        shell_words::join(
+            std::iter::once(near_cli_exec_path.to_owned()).chain(cli_cmd.to_cli_args())
+        )
+    );
 }
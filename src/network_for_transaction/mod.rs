@@ -6,13 +6,16 @@ pub struct NetworkForTransactionArgs {
     /// What is the name of the network
     #[interactive_clap(skip_default_input_arg)]
     network_name: String,
+    #[interactive_clap(long)]
+    /// Sign the transaction without the confirmation required by the `confirm-transactions` setting of the network
+    yes: bool,
     #[interactive_clap(subcommand)]
     transaction_signature_options: crate::transaction_signature_options::SignWith,
 }
 
 #[derive(Clone)]
 pub struct NetworkForTransactionArgsContext {
-    config: crate::config::Config,
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     prepopulated_transaction: crate::commands::PrepopulatedTransaction,
     on_before_signing_callback: crate::commands::OnBeforeSigningCallback,
@@ -27,7 +30,7 @@ impl NetworkForTransactionArgsContext {
         previous_context: crate::commands::ActionContext,
         scope: &<NetworkForTransactionArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let network_connection = previous_context.config.network_connection.clone();
+        let network_connection = previous_context.global_context.0.network_connection.clone();
         let network_config = network_connection
             .get(&scope.network_name)
             .expect("Failed to get network config!")
@@ -35,7 +38,7 @@ impl NetworkForTransactionArgsContext {
         let prepopulated_transaction =
             (previous_context.on_after_getting_network_callback)(&network_config)?;
         Ok(Self {
-            config: previous_context.config,
+            global_context: previous_context.global_context,
             network_config,
             prepopulated_transaction,
             on_before_signing_callback: previous_context.on_before_signing_callback,
@@ -50,7 +53,7 @@ impl NetworkForTransactionArgsContext {
 impl From<NetworkForTransactionArgsContext> for crate::commands::TransactionContext {
     fn from(item: NetworkForTransactionArgsContext) -> Self {
         Self {
-            config: item.config,
+            global_context: item.global_context,
             network_config: item.network_config,
             prepopulated_transaction: item.prepopulated_transaction,
             on_before_signing_callback: item.on_before_signing_callback,
@@ -87,8 +90,10 @@ impl interactive_clap::FromCli for NetworkForTransactionArgs {
         }
         let network_name = clap_variant.network_name.clone().expect("Unexpected error");
 
-        let new_context_scope =
-            InteractiveClapContextScopeForNetworkForTransactionArgs { network_name };
+        let new_context_scope = InteractiveClapContextScopeForNetworkForTransactionArgs {
+            network_name,
+            yes: clap_variant.yes,
+        };
         let new_context = match NetworkForTransactionArgsContext::from_previous_context(
             context,
             &new_context_scope,
@@ -101,10 +106,56 @@ impl interactive_clap::FromCli for NetworkForTransactionArgs {
             return interactive_clap::ResultFromCli::Cancel(Some(clap_variant));
         }
 
+        if clap_variant.transaction_signature_options.is_none() {
+            if let Some(signing_method) = new_context.network_config.default_signing_method {
+                clap_variant.transaction_signature_options =
+                    crate::transaction_signature_options::default_sign_with(
+                        signing_method,
+                        new_context.global_context.1.scripting_mode,
+                    );
+                if clap_variant.transaction_signature_options.is_some() {
+                    eprintln!(
+                        "Using the default signing method <{}> of the connection",
+                        signing_method
+                    );
+                }
+            }
+        }
+        if clap_variant.transaction_signature_options.is_none() {
+            if let Err(err) = new_context
+                .global_context
+                .1
+                .ensure_interactive_mode("sign-with-...")
+            {
+                return interactive_clap::ResultFromCli::Err(Some(clap_variant), err);
+            }
+        }
+
         eprintln!("\nUnsigned transaction:\n");
         crate::common::print_unsigned_transaction(&new_context.prepopulated_transaction);
         eprintln!();
 
+        // Scripts confirm the transaction in advance with --yes
+        if new_context
+            .global_context
+            .0
+            .cli
+            .confirm_transactions(&new_context.network_config.network_name)
+        {
+            match crate::common::confirm(
+                new_context.global_context.1.scripting_mode,
+                clap_variant.yes,
+                &format!(
+                    "Do you want to sign this transaction on {}?",
+                    new_context.network_config.network_name
+                ),
+            ) {
+                Ok(true) => (),
+                Ok(false) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
+            }
+        }
+
         match <crate::transaction_signature_options::SignWith as interactive_clap::FromCli>::from_cli(
                 clap_variant.transaction_signature_options.take(),
                 new_context.into(),
@@ -127,7 +178,7 @@ impl NetworkForTransactionArgs {
     fn input_network_name(
         context: &crate::commands::ActionContext,
     ) -> color_eyre::eyre::Result<Option<String>> {
-        crate::common::input_network_name(&(context.config.clone(),))
+        crate::common::input_network_name_or_selected(&context.global_context)
     }
 
     pub fn get_network_config(
@@ -7,7 +7,7 @@ pub type OnAfterGettingBlockReferenceCallback =
 
 #[derive(Clone)]
 pub struct ArgsForViewContext {
-    pub config: crate::config::Config,
+    pub global_context: crate::GlobalContext,
     pub on_after_getting_block_reference_callback: OnAfterGettingBlockReferenceCallback,
 }
 
@@ -24,8 +24,8 @@ pub struct NetworkViewAtBlockArgs {
 
 #[derive(Clone)]
 pub struct NetworkViewAtBlockArgsContext {
-    network_config: crate::config::NetworkConfig,
-    on_after_getting_block_reference_callback: OnAfterGettingBlockReferenceCallback,
+    pub network_config: crate::config::NetworkConfig,
+    pub on_after_getting_block_reference_callback: OnAfterGettingBlockReferenceCallback,
 }
 
 impl NetworkViewAtBlockArgsContext {
@@ -33,7 +33,7 @@ impl NetworkViewAtBlockArgsContext {
         previous_context: ArgsForViewContext,
         scope: &<NetworkViewAtBlockArgs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let network_connection = previous_context.config.network_connection.clone();
+        let network_connection = previous_context.global_context.0.network_connection.clone();
         let network_config = network_connection
             .get(&scope.network_name)
             .expect("Failed to get network config!")
@@ -50,7 +50,7 @@ impl NetworkViewAtBlockArgs {
     fn input_network_name(
         context: &ArgsForViewContext,
     ) -> color_eyre::eyre::Result<Option<String>> {
-        crate::common::input_network_name(&(context.config.clone(),))
+        crate::common::input_network_name_or_selected(&context.global_context)
     }
 }
 
@@ -52,6 +52,42 @@ pub enum SignWith {
     SignWithSeedPhrase(self::sign_with_seed_phrase::SignSeedPhrase),
 }
 
+/// Builds the signing subcommand for the default signing method of the connection. Returns `None`
+/// if the signing method is not available in this build.
+pub fn default_sign_with(
+    signing_method: crate::config::SigningMethod,
+    scripting_mode: bool,
+) -> Option<CliSignWith> {
+    // Nobody can choose how to proceed in scripting mode, so the transaction is sent
+    let submit = scripting_mode.then_some(CliSubmit::Send);
+    match signing_method {
+        #[cfg(target_os = "macos")]
+        crate::config::SigningMethod::SignWithMacosKeychain => {
+            Some(CliSignWith::SignWithMacosKeychain(
+                self::sign_with_macos_keychain::CliSignMacosKeychain {
+                    submit,
+                    ..Default::default()
+                },
+            ))
+        }
+        crate::config::SigningMethod::SignWithKeychain => Some(CliSignWith::SignWithKeychain(
+            self::sign_with_keychain::CliSignKeychain {
+                submit,
+                ..Default::default()
+            },
+        )),
+        #[cfg(feature = "ledger")]
+        crate::config::SigningMethod::SignWithLedger => Some(CliSignWith::SignWithLedger(
+            self::sign_with_ledger::CliSignLedger {
+                submit,
+                ..Default::default()
+            },
+        )),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
 //-----------------------------------------------------------------------------------
 //---- these functions are used for offline mode ----
 // pub fn input_access_key_nonce(public_key: &str) -> color_eyre::eyre::Result<u64> {
@@ -110,6 +146,13 @@ impl interactive_clap::FromCli for Submit {
         let mut storage_message = String::new();
 
         if optional_clap_variant.is_none() {
+            if let Err(err) = context
+                .global_context
+                .1
+                .ensure_interactive_mode("send or display")
+            {
+                return interactive_clap::ResultFromCli::Err(optional_clap_variant, err);
+            }
             match Self::choose_variant(context.clone()) {
                 interactive_clap::ResultFromCli::Ok(cli_submit) => {
                     optional_clap_variant = Some(cli_submit)
@@ -127,16 +170,19 @@ impl interactive_clap::FromCli for Submit {
                 ) {
                     Ok(_) => (),
                     Err(report) => {
-                        return interactive_clap::ResultFromCli::Err(
-                            optional_clap_variant,
-                            color_eyre::Report::msg(report),
-                        )
+                        return interactive_clap::ResultFromCli::Err(optional_clap_variant, report)
                     }
                 };
 
+                let json_rpc_client = match context.network_config.json_rpc_client() {
+                    Ok(json_rpc_client) => json_rpc_client,
+                    Err(report) => {
+                        return interactive_clap::ResultFromCli::Err(optional_clap_variant, report)
+                    }
+                };
                 eprintln!("Transaction sent ...");
                 let transaction_info = loop {
-                    let transaction_info_result = context.network_config.json_rpc_client()
+                    let transaction_info_result = json_rpc_client
                         .blocking_call(
                             near_jsonrpc_client::methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest{
                                 signed_transaction: context.signed_transaction.clone()
@@ -146,12 +192,15 @@ impl interactive_clap::FromCli for Submit {
                         Ok(response) => {
                             break response;
                         }
-                        Err(err) => match crate::common::rpc_transaction_error(err) {
+                        Err(err) => match crate::common::rpc_transaction_error(
+                            err,
+                            context.global_context.1.scripting_mode,
+                        ) {
                             Ok(_) => std::thread::sleep(std::time::Duration::from_millis(100)),
                             Err(report) => {
                                 return interactive_clap::ResultFromCli::Err(
                                     optional_clap_variant,
-                                    color_eyre::Report::msg(report),
+                                    report,
                                 )
                             }
                         },
@@ -160,13 +209,11 @@ impl interactive_clap::FromCli for Submit {
                 match crate::common::print_transaction_status(
                     &transaction_info,
                     &context.network_config,
+                    &context.global_context.1.output_format,
                 ) {
                     Ok(_) => (),
                     Err(report) => {
-                        return interactive_clap::ResultFromCli::Err(
-                            optional_clap_variant,
-                            color_eyre::Report::msg(report),
-                        )
+                        return interactive_clap::ResultFromCli::Err(optional_clap_variant, report)
                     }
                 };
                 match (context.on_after_sending_transaction_callback)(
@@ -175,10 +222,7 @@ impl interactive_clap::FromCli for Submit {
                 ) {
                     Ok(_) => (),
                     Err(report) => {
-                        return interactive_clap::ResultFromCli::Err(
-                            optional_clap_variant,
-                            color_eyre::Report::msg(report),
-                        )
+                        return interactive_clap::ResultFromCli::Err(optional_clap_variant, report)
                     }
                 };
                 eprintln!("{storage_message}");
@@ -192,10 +236,7 @@ impl interactive_clap::FromCli for Submit {
                 ) {
                     Ok(_) => (),
                     Err(report) => {
-                        return interactive_clap::ResultFromCli::Err(
-                            optional_clap_variant,
-                            color_eyre::Report::msg(report),
-                        )
+                        return interactive_clap::ResultFromCli::Err(optional_clap_variant, report)
                     }
                 };
                 let base64_transaction = near_primitives::serialize::to_base64(
@@ -237,6 +278,7 @@ pub type OnAfterSendingTransactionCallback = std::sync::Arc<
 
 #[derive(Clone)]
 pub struct SubmitContext {
+    pub global_context: crate::GlobalContext,
     pub network_config: crate::config::NetworkConfig,
     pub signed_transaction: near_primitives::transaction::SignedTransaction,
     pub on_before_sending_transaction_callback: OnBeforeSendingTransactionCallback,
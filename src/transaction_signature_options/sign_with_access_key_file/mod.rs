@@ -15,6 +15,7 @@ pub struct SignAccessKeyFile {
 
 #[derive(Clone)]
 pub struct SignAccessKeyFileContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -36,7 +37,7 @@ impl SignAccessKeyFileContext {
             .wrap_err_with(|| format!("Error reading data from file: {:?}", &scope.file_path))?;
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &account_json.public_key,
@@ -75,6 +76,7 @@ impl SignAccessKeyFileContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -88,6 +90,7 @@ impl SignAccessKeyFileContext {
 impl From<SignAccessKeyFileContext> for super::SubmitContext {
     fn from(item: SignAccessKeyFileContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
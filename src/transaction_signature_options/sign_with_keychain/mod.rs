@@ -22,6 +22,7 @@ pub struct SignKeychain {
 
 #[derive(Clone)]
 pub struct SignKeychainContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -41,7 +42,8 @@ impl SignKeychainContext {
             "{}.json",
             &previous_context.prepopulated_transaction.signer_id
         );
-        let mut path = std::path::PathBuf::from(&previous_context.config.credentials_home_dir);
+        let mut path =
+            std::path::PathBuf::from(&previous_context.global_context.0.credentials_home_dir);
 
         let data_path: std::path::PathBuf = {
             let dir_name = network_config.network_name.clone();
@@ -52,7 +54,7 @@ impl SignKeychainContext {
                 path
             } else {
                 let access_key_list = network_config
-                    .json_rpc_client()
+                    .json_rpc_client()?
                     .blocking_call_view_access_key_list(
                         &previous_context.prepopulated_transaction.signer_id,
                         near_primitives::types::Finality::Final.into(),
@@ -64,8 +66,9 @@ impl SignKeychainContext {
                         )
                     })?
                     .access_key_list_view()?;
-                let mut path =
-                    std::path::PathBuf::from(&previous_context.config.credentials_home_dir);
+                let mut path = std::path::PathBuf::from(
+                    &previous_context.global_context.0.credentials_home_dir,
+                );
                 path.push(dir_name);
                 path.push(
                     &previous_context
@@ -86,7 +89,10 @@ impl SignKeychainContext {
                     };
                     let dir = path
                         .read_dir()
-                        .wrap_err("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain.")?;
+                        .wrap_err_with(|| crate::common::CliError::new(
+                            crate::common::ErrorCategory::LocalKeyStorage,
+                            "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain.",
+                        ))?;
                     for entry in dir {
                         if let Ok(entry) = entry {
                             if entry
@@ -97,26 +103,47 @@ impl SignKeychainContext {
                                 .unwrap()
                                 .contains(account_public_key.rsplit(':').next().unwrap())
                                 && is_full_access_key
+                                // Ledger keys are saved with the HD path only
+                                && (crate::commands::local_keys::storage::is_encrypted_key_file(&entry.path())
+                                    || std::fs::read_to_string(entry.path())
+                                        .ok()
+                                        .and_then(|data| {
+                                            serde_json::from_str::<super::AccountKeyPair>(&data).ok()
+                                        })
+                                        .is_some())
                             {
                                 data_path.push(entry.path());
                                 break 'outer;
                             }
                         } else {
-                            return Err(color_eyre::Report::msg(
-                                "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
-                            ));
+                            return Err(crate::common::CliError::new(
+                                crate::common::ErrorCategory::LocalKeyStorage,
+                                "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain.",
+                            ).into());
                         };
                     }
                 }
                 data_path
             }
         };
-        let data = std::fs::read_to_string(&data_path).wrap_err("Access key file not found!")?;
-        let account_json: super::AccountKeyPair = serde_json::from_str(&data)
-            .wrap_err_with(|| format!("Error reading data from file: {:?}", &data_path))?;
+        if data_path.as_os_str().is_empty() {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::LocalKeyStorage,
+                "Access key file not found!",
+            )
+            .into());
+        }
+        let data = crate::commands::local_keys::storage::read_key_file(&data_path)?;
+        let account_json: super::AccountKeyPair =
+            serde_json::from_str(&data).wrap_err_with(|| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::LocalKeyStorage,
+                    format!("Error reading data from file: {:?}", &data_path),
+                )
+            })?;
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &account_json.public_key,
@@ -154,6 +181,7 @@ impl SignKeychainContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -167,6 +195,7 @@ impl SignKeychainContext {
 impl From<SignKeychainContext> for super::SubmitContext {
     fn from(item: SignKeychainContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
@@ -34,6 +34,7 @@ pub struct SignLedger {
 
 #[derive(Clone)]
 pub struct SignLedgerContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -52,7 +53,7 @@ impl SignLedgerContext {
         let public_key: near_crypto::PublicKey = scope.signer_public_key.clone().into();
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &public_key,
@@ -109,6 +110,7 @@ impl SignLedgerContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -122,6 +124,7 @@ impl SignLedgerContext {
 impl From<SignLedgerContext> for super::SubmitContext {
     fn from(item: SignLedgerContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
@@ -147,11 +150,14 @@ impl interactive_clap::FromCli for SignLedger {
         let mut clap_variant = optional_clap_variant.unwrap_or_default();
 
         if clap_variant.seed_phrase_hd_path.is_none() {
-            clap_variant.seed_phrase_hd_path = match Self::input_seed_phrase_hd_path() {
-                Ok(Some(seed_phrase_hd_path)) => Some(seed_phrase_hd_path),
-                Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
-                Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
-            };
+            clap_variant.seed_phrase_hd_path =
+                match Self::input_seed_phrase_hd_path(&context.global_context.1) {
+                    Ok(Some(seed_phrase_hd_path)) => Some(seed_phrase_hd_path),
+                    Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
+                    Err(err) => {
+                        return interactive_clap::ResultFromCli::Err(Some(clap_variant), err)
+                    }
+                };
         }
         let seed_phrase_hd_path = clap_variant
             .seed_phrase_hd_path
@@ -225,16 +231,14 @@ impl interactive_clap::FromCli for SignLedger {
 
 impl SignLedger {
     pub fn input_seed_phrase_hd_path(
+        global_options: &crate::common::GlobalOptions,
     ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
-        Ok(Some(
-            crate::types::slip10::BIP32Path::from_str(
-                &Text::new("Enter seed phrase HD Path (if you not sure leave blank for default)")
-                    .with_initial_value("44'/397'/0'/0'/1'")
-                    .prompt()
-                    .unwrap(),
-            )
-            .unwrap(),
-        ))
+        global_options.ensure_interactive_mode("--seed-phrase-hd-path")?;
+        Ok(Some(crate::types::slip10::BIP32Path::from_str(
+            &Text::new("Enter seed phrase HD Path (if you not sure leave blank for default)")
+                .with_initial_value("44'/397'/0'/0'/1'")
+                .prompt()?,
+        )?))
     }
 
     pub fn input_nonce(
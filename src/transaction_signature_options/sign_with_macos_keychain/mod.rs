@@ -20,6 +20,7 @@ pub struct SignMacosKeychain {
 
 #[derive(Clone)]
 pub struct SignMacosKeychainContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -36,10 +37,15 @@ impl SignMacosKeychainContext {
         let network_config = previous_context.network_config.clone();
 
         let keychain = security_framework::os::macos::keychain::SecKeychain::default()
-            .wrap_err("Failed to open keychain")?;
+            .wrap_err_with(|| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::LocalKeyStorage,
+                    "Failed to open keychain",
+                )
+            })?;
 
         let access_key_list = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key_list(
                 &previous_context.prepopulated_transaction.signer_id,
                 near_primitives::types::Finality::Final.into(),
@@ -80,17 +86,25 @@ impl SignMacosKeychainContext {
                 Some(password)
             })
             .ok_or_else(|| {
-                color_eyre::eyre::eyre!(format!(
-                    "There are no access keys for {} account in the macOS keychain.",
-                    previous_context.prepopulated_transaction.signer_id
-                ))
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::LocalKeyStorage,
+                    format!(
+                        "There are no access keys for {} account in the macOS keychain.",
+                        previous_context.prepopulated_transaction.signer_id
+                    ),
+                )
             })?;
 
-        let account_json: super::AccountKeyPair =
-            serde_json::from_slice(password.as_ref()).wrap_err("Error reading data")?;
+        let account_json: super::AccountKeyPair = serde_json::from_slice(password.as_ref())
+            .wrap_err_with(|| {
+                crate::common::CliError::new(
+                    crate::common::ErrorCategory::LocalKeyStorage,
+                    "Error reading data",
+                )
+            })?;
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &account_json.public_key,
@@ -128,6 +142,7 @@ impl SignMacosKeychainContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -141,6 +156,7 @@ impl SignMacosKeychainContext {
 impl From<SignMacosKeychainContext> for super::SubmitContext {
     fn from(item: SignMacosKeychainContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
@@ -26,6 +26,7 @@ pub struct SignPrivateKey {
 
 #[derive(Clone)]
 pub struct SignPrivateKeyContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -44,7 +45,7 @@ impl SignPrivateKeyContext {
         let public_key: near_crypto::PublicKey = scope.signer_public_key.clone().into();
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &public_key,
@@ -80,6 +81,7 @@ impl SignPrivateKeyContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -93,6 +95,7 @@ impl SignPrivateKeyContext {
 impl From<SignPrivateKeyContext> for super::SubmitContext {
     fn from(item: SignPrivateKeyContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
@@ -118,7 +121,12 @@ impl interactive_clap::FromCli for SignPrivateKey {
         let mut clap_variant = optional_clap_variant.unwrap_or_default();
 
         if clap_variant.signer_public_key.is_none() {
-            clap_variant.signer_public_key = match Self::input_signer_public_key(&context) {
+            clap_variant.signer_public_key = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--signer-public-key")
+                .and_then(|_| Self::input_signer_public_key(&context))
+            {
                 Ok(Some(signer_public_key)) => Some(signer_public_key),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
@@ -129,7 +137,12 @@ impl interactive_clap::FromCli for SignPrivateKey {
             .clone()
             .expect("Unexpected error");
         if clap_variant.signer_private_key.is_none() {
-            clap_variant.signer_private_key = match Self::input_signer_private_key(&context) {
+            clap_variant.signer_private_key = match context
+                .global_context
+                .1
+                .ensure_interactive_mode("--signer-private-key")
+                .and_then(|_| Self::input_signer_private_key(&context))
+            {
                 Ok(Some(signer_private_key)) => Some(signer_private_key),
                 Ok(None) => return interactive_clap::ResultFromCli::Cancel(Some(clap_variant)),
                 Err(err) => return interactive_clap::ResultFromCli::Err(Some(clap_variant), err),
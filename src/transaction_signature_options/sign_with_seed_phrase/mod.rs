@@ -1,3 +1,4 @@
+use crate::types::seed_phrase::SeedPhraseOptions;
 use std::str::FromStr;
 
 use color_eyre::eyre::WrapErr;
@@ -13,6 +14,14 @@ pub struct SignSeedPhrase {
     master_seed_phrase: String,
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    seed_phrase_passphrase: Option<String>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Wordlist language of the seed phrase (detected from the words when omitted)
+    seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
     seed_phrase_hd_path: crate::types::slip10::BIP32Path,
     #[interactive_clap(subcommand)]
     submit: super::Submit,
@@ -20,6 +29,7 @@ pub struct SignSeedPhrase {
 
 #[derive(Clone)]
 pub struct SignSeedPhraseContext {
+    global_context: crate::GlobalContext,
     network_config: crate::config::NetworkConfig,
     signed_transaction: near_primitives::transaction::SignedTransaction,
     on_before_sending_transaction_callback:
@@ -38,6 +48,8 @@ impl SignSeedPhraseContext {
         let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
             scope.seed_phrase_hd_path.clone(),
             scope.master_seed_phrase.clone(),
+            scope.seed_phrase_language,
+            scope.seed_phrase_passphrase.as_deref(),
         )?;
 
         let signer_secret_key: near_crypto::SecretKey =
@@ -46,7 +58,7 @@ impl SignSeedPhraseContext {
             near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
 
         let rpc_query_response = network_config
-            .json_rpc_client()
+            .json_rpc_client()?
             .blocking_call_view_access_key(
                 &previous_context.prepopulated_transaction.signer_id,
                 &signer_public_key,
@@ -82,6 +94,7 @@ impl SignSeedPhraseContext {
         eprintln!("Signature: {}", signature);
 
         Ok(Self {
+            global_context: previous_context.global_context,
             network_config: previous_context.network_config,
             signed_transaction,
             on_before_sending_transaction_callback: previous_context
@@ -95,6 +108,7 @@ impl SignSeedPhraseContext {
 impl From<SignSeedPhraseContext> for super::SubmitContext {
     fn from(item: SignSeedPhraseContext) -> Self {
         Self {
+            global_context: item.global_context,
             network_config: item.network_config,
             signed_transaction: item.signed_transaction,
             on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
@@ -105,8 +119,12 @@ impl From<SignSeedPhraseContext> for super::SubmitContext {
 
 impl SignSeedPhrase {
     fn input_seed_phrase_hd_path(
-        _context: &crate::commands::TransactionContext,
+        context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::slip10::BIP32Path>> {
+        context
+            .global_context
+            .1
+            .ensure_interactive_mode("--seed-phrase-hd-path")?;
         Ok(Some(
             inquire::CustomType::new("Enter seed phrase HD Path [if not sure, keep the default]")
                 .with_default(crate::types::slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap())
@@ -114,3 +132,11 @@ impl SignSeedPhrase {
         ))
     }
 }
+
+impl SeedPhraseOptions for SignSeedPhrase {
+    type InputContext = crate::commands::TransactionContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext {
+        &context.global_context
+    }
+}
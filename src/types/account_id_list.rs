@@ -0,0 +1,74 @@
+/// Comma-separated account IDs; an `@<path>` entry is replaced with the account IDs listed in the
+/// file (one per line, empty lines and `#` comments are skipped).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountIdList(pub Vec<near_primitives::types::AccountId>);
+
+impl std::fmt::Display for AccountIdList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let account_ids = self
+            .0
+            .iter()
+            .map(|account_id| account_id.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", account_ids.join(","))
+    }
+}
+
+impl std::str::FromStr for AccountIdList {
+    type Err = color_eyre::eyre::ErrReport;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut account_ids = vec![];
+        for entry in s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            if let Some(path) = entry.strip_prefix('@') {
+                let path = shellexpand::tilde(path).to_string();
+                let data = std::fs::read_to_string(&path).map_err(|err| {
+                    color_eyre::eyre::eyre!("Failed to read the account IDs from {}: {}", path, err)
+                })?;
+                for line in data.lines().map(str::trim) {
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+                    let account_id =
+                        line.parse::<near_primitives::types::AccountId>()
+                            .map_err(|err| {
+                                color_eyre::eyre::eyre!(
+                                    "Account ID <{}> in {}: {}",
+                                    line,
+                                    path,
+                                    err
+                                )
+                            })?;
+                    if !account_ids.contains(&account_id) {
+                        account_ids.push(account_id);
+                    }
+                }
+            } else {
+                let account_id = entry
+                    .parse::<near_primitives::types::AccountId>()
+                    .map_err(|err| color_eyre::eyre::eyre!("Account ID <{}>: {}", entry, err))?;
+                if !account_ids.contains(&account_id) {
+                    account_ids.push(account_id);
+                }
+            }
+        }
+        if account_ids.is_empty() {
+            color_eyre::eyre::bail!("At least one account ID has to be specified");
+        }
+        Ok(Self(account_ids))
+    }
+}
+
+impl From<AccountIdList> for Vec<near_primitives::types::AccountId> {
+    fn from(item: AccountIdList) -> Self {
+        item.0
+    }
+}
+
+impl interactive_clap::ToCli for AccountIdList {
+    type CliVariant = AccountIdList;
+}
@@ -0,0 +1,73 @@
+/// The `--allowance` of a function-call access key: an amount of NEAR (e.g. `0.25 NEAR`) the key
+/// can spend on gas and transaction fees, or `unlimited`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Allowance {
+    Unlimited,
+    Limited(crate::common::NearBalance),
+}
+
+impl Allowance {
+    /// The allowance of the access key permission (`None` stands for an unlimited allowance)
+    pub fn optional_yoctonear(&self) -> Option<u128> {
+        match self {
+            Self::Unlimited => None,
+            Self::Limited(near_balance) => Some(near_balance.to_yoctonear()),
+        }
+    }
+}
+
+impl std::fmt::Display for Allowance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unlimited => write!(f, "unlimited"),
+            Self::Limited(near_balance) => write!(f, "{}", near_balance),
+        }
+    }
+}
+
+impl std::str::FromStr for Allowance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("unlimited") {
+            return Ok(Self::Unlimited);
+        }
+        crate::common::NearBalance::from_str(s)
+            .map(Self::Limited)
+            .map_err(|err| format!("{} (use an amount such as 0.25 NEAR, or unlimited)", err))
+    }
+}
+
+impl interactive_clap::ToCli for Allowance {
+    type CliVariant = Allowance;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_unlimited_and_amounts() {
+        assert_eq!(Allowance::from_str("unlimited"), Ok(Allowance::Unlimited));
+        assert_eq!(Allowance::from_str(" Unlimited "), Ok(Allowance::Unlimited));
+        assert_eq!(
+            Allowance::from_str("0.25 NEAR")
+                .unwrap()
+                .optional_yoctonear(),
+            Some(25 * 10u128.pow(22))
+        );
+        assert_eq!(Allowance::Unlimited.optional_yoctonear(), None);
+        assert!(Allowance::from_str("a lot").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for allowance in ["unlimited", "0.25 NEAR", "10 NEAR"] {
+            assert_eq!(
+                Allowance::from_str(allowance).unwrap().to_string(),
+                allowance
+            );
+        }
+    }
+}
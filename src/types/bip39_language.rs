@@ -0,0 +1,80 @@
+/// Wordlist language of a BIP39 seed phrase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bip39Language(pub bip39::Language);
+
+impl From<Bip39Language> for bip39::Language {
+    fn from(language: Bip39Language) -> Self {
+        language.0
+    }
+}
+
+impl std::fmt::Display for Bip39Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self.0 {
+            bip39::Language::English => "english",
+            bip39::Language::SimplifiedChinese => "chinese-simplified",
+            bip39::Language::TraditionalChinese => "chinese-traditional",
+            bip39::Language::Czech => "czech",
+            bip39::Language::French => "french",
+            bip39::Language::Italian => "italian",
+            bip39::Language::Japanese => "japanese",
+            bip39::Language::Korean => "korean",
+            bip39::Language::Spanish => "spanish",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl std::str::FromStr for Bip39Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = match s.to_lowercase().as_str() {
+            "english" => bip39::Language::English,
+            "chinese-simplified" => bip39::Language::SimplifiedChinese,
+            "chinese-traditional" => bip39::Language::TraditionalChinese,
+            "czech" => bip39::Language::Czech,
+            "french" => bip39::Language::French,
+            "italian" => bip39::Language::Italian,
+            "japanese" => bip39::Language::Japanese,
+            "korean" => bip39::Language::Korean,
+            "spanish" => bip39::Language::Spanish,
+            _ => return Err(format!(
+                "Seed phrase language: unsupported value <{}> (expected one of: english, chinese-simplified, chinese-traditional, czech, french, italian, japanese, korean, spanish)",
+                s
+            )),
+        };
+        Ok(Self(language))
+    }
+}
+
+impl interactive_clap::ToCli for Bip39Language {
+    type CliVariant = Bip39Language;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn bip39_language_round_trips() {
+        for language in bip39::Language::all() {
+            let language = Bip39Language(*language);
+            assert_eq!(
+                Bip39Language::from_str(&language.to_string()).unwrap(),
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn bip39_language_parsing() {
+        assert_eq!(
+            Bip39Language::from_str("Chinese-Simplified").unwrap(),
+            Bip39Language(bip39::Language::SimplifiedChinese)
+        );
+        assert!(Bip39Language::from_str("chinese").is_err());
+        assert!(Bip39Language::from_str("").is_err());
+    }
+}
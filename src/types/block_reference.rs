@@ -0,0 +1,78 @@
+/// A block on the command line: `now` (the final block), a block height or a block hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockReference(pub near_primitives::types::BlockReference);
+
+impl From<BlockReference> for near_primitives::types::BlockReference {
+    fn from(item: BlockReference) -> Self {
+        item.0
+    }
+}
+
+impl std::fmt::Display for BlockReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.0 {
+            near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Height(block_height),
+            ) => write!(f, "{}", block_height),
+            near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(block_hash),
+            ) => write!(f, "{}", block_hash),
+            _ => write!(f, "now"),
+        }
+    }
+}
+
+impl std::str::FromStr for BlockReference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "now" {
+            return Ok(Self(near_primitives::types::Finality::Final.into()));
+        }
+        let block_id = if let Ok(block_height) = s.parse() {
+            near_primitives::types::BlockId::Height(block_height)
+        } else {
+            near_primitives::hash::CryptoHash::from_str(s)
+                .map(near_primitives::types::BlockId::Hash)
+                .map_err(|_| {
+                    format!(
+                        "Block: <{}> is neither \"now\", nor a block height, nor a block hash",
+                        s
+                    )
+                })?
+        };
+        Ok(Self(near_primitives::types::BlockReference::BlockId(
+            block_id,
+        )))
+    }
+}
+
+impl interactive_clap::ToCli for BlockReference {
+    type CliVariant = BlockReference;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_block_references() {
+        for block in [
+            "now",
+            "106000000",
+            "6Gz8X3zQYvRhaVjo6QFzXZJkqRxJYhFRiMnFSGxdMKmV",
+        ] {
+            assert_eq!(BlockReference::from_str(block).unwrap().to_string(), block);
+        }
+        assert!(matches!(
+            BlockReference::from_str("now").unwrap().0,
+            near_primitives::types::BlockReference::Finality(
+                near_primitives::types::Finality::Final
+            )
+        ));
+        assert!(BlockReference::from_str("-1").is_err());
+        assert!(BlockReference::from_str("final").is_err());
+        assert!(BlockReference::from_str("6Gz8X3zQ").is_err());
+    }
+}
@@ -0,0 +1,103 @@
+/// A time interval such as `500ms`, `30s`, `5m` or `1h` (a plain number means seconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duration(pub std::time::Duration);
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let millis = self.0.as_millis();
+        if millis % 3_600_000 == 0 && millis > 0 {
+            write!(f, "{}h", millis / 3_600_000)
+        } else if millis % 60_000 == 0 && millis > 0 {
+            write!(f, "{}m", millis / 60_000)
+        } else if millis % 1000 == 0 {
+            write!(f, "{}s", millis / 1000)
+        } else {
+            write!(f, "{}ms", millis)
+        }
+    }
+}
+
+impl std::str::FromStr for Duration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let unit_start = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(unit_start);
+        let number = number.parse::<u64>().map_err(|_| {
+            format!(
+                "Duration <{}>: expected a number followed by ms, s, m or h",
+                s
+            )
+        })?;
+        let duration = match unit.trim() {
+            "ms" => std::time::Duration::from_millis(number),
+            "" | "s" => std::time::Duration::from_secs(number),
+            "m" => std::time::Duration::from_secs(
+                number
+                    .checked_mul(60)
+                    .ok_or_else(|| format!("Duration <{}> is too long", s))?,
+            ),
+            "h" => std::time::Duration::from_secs(
+                number
+                    .checked_mul(3600)
+                    .ok_or_else(|| format!("Duration <{}> is too long", s))?,
+            ),
+            unit => {
+                return Err(format!(
+                    "Duration <{}>: unknown unit <{}>, use ms, s, m or h",
+                    s, unit
+                ))
+            }
+        };
+        if duration.is_zero() {
+            return Err(format!("Duration <{}> has to be longer than zero", s));
+        }
+        Ok(Self(duration))
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(item: Duration) -> Self {
+        item.0
+    }
+}
+
+impl interactive_clap::ToCli for Duration {
+    type CliVariant = Duration;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_durations() {
+        for (duration, millis) in [
+            ("500ms", 500),
+            ("30", 30_000),
+            ("30s", 30_000),
+            ("5m", 300_000),
+            ("1h", 3_600_000),
+        ] {
+            assert_eq!(
+                Duration::from_str(duration).unwrap().0,
+                std::time::Duration::from_millis(millis)
+            );
+        }
+        assert_eq!(Duration::from_str("90s").unwrap().to_string(), "90s");
+        assert_eq!(Duration::from_str("120s").unwrap().to_string(), "2m");
+        assert_eq!(Duration::from_str("1500ms").unwrap().to_string(), "1500ms");
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for duration in ["", "0", "0ms", "5d", "m", "-1s", "1.5s"] {
+            assert!(Duration::from_str(duration).is_err(), "{}", duration);
+        }
+        assert!(Duration::from_str(&format!("{}m", u64::MAX / 60 + 1)).is_err());
+        assert!(Duration::from_str(&format!("{}h", u64::MAX / 3600 + 1)).is_err());
+        assert!(Duration::from_str(&format!("{}h", u64::MAX / 3600)).is_ok());
+    }
+}
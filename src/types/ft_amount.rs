@@ -0,0 +1,151 @@
+/// An amount of fungible tokens: `12.5 USDC` or `12.5` in tokens (converted with the decimals of
+/// the FT contract), or `12500000 raw` in the smallest units of the token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FtAmount {
+    Tokens {
+        amount: String,
+        symbol: Option<String>,
+    },
+    Raw(u128),
+}
+
+impl FtAmount {
+    /// The amount in the smallest units of the token; the symbol, if it was given, has to be the
+    /// symbol of the token.
+    pub fn to_raw(&self, symbol: &str, decimals: u32) -> color_eyre::eyre::Result<u128> {
+        match self {
+            Self::Raw(amount) => Ok(*amount),
+            Self::Tokens {
+                amount,
+                symbol: amount_symbol,
+            } => {
+                if let Some(amount_symbol) = amount_symbol {
+                    if !amount_symbol.eq_ignore_ascii_case(symbol) {
+                        return Err(crate::common::CliError::new(
+                            crate::common::ErrorCategory::UserInput,
+                            format!(
+                                "The amount is in <{}>, but the token of the FT contract is <{}>",
+                                amount_symbol, symbol
+                            ),
+                        )
+                        .into());
+                    }
+                }
+                crate::common::parse_decimal_amount(amount, decimals).map_err(|err| {
+                    crate::common::CliError::new(
+                        crate::common::ErrorCategory::UserInput,
+                        format!("FT amount: {} (the token has {} decimals)", err, decimals),
+                    )
+                    .into()
+                })
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for FtAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Tokens {
+                amount,
+                symbol: Some(symbol),
+            } => write!(f, "{} {}", amount, symbol),
+            Self::Tokens {
+                amount,
+                symbol: None,
+            } => write!(f, "{}", amount),
+            Self::Raw(amount) => write!(f, "{} raw", amount),
+        }
+    }
+}
+
+impl std::str::FromStr for FtAmount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let suffix_start = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len());
+        let (amount, suffix) = s.split_at(suffix_start);
+        let suffix = suffix.trim();
+        if suffix.eq_ignore_ascii_case("raw") {
+            return amount
+                .parse::<u128>()
+                .map(Self::Raw)
+                .map_err(|err| format!("FT amount <{}>: {}", s, err));
+        }
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        if (integer.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+            return Err(format!(
+                "FT amount <{}>: expected an amount such as 12.5, 12.5 <symbol> or 12500000 raw",
+                s
+            ));
+        }
+        Ok(Self::Tokens {
+            amount: amount.to_string(),
+            symbol: (!suffix.is_empty()).then(|| suffix.to_string()),
+        })
+    }
+}
+
+impl interactive_clap::ToCli for FtAmount {
+    type CliVariant = FtAmount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_ft_amounts() {
+        assert_eq!(
+            FtAmount::from_str("12.5 USDC").unwrap(),
+            FtAmount::Tokens {
+                amount: "12.5".to_string(),
+                symbol: Some("USDC".to_string()),
+            }
+        );
+        assert_eq!(
+            FtAmount::from_str(".5").unwrap(),
+            FtAmount::Tokens {
+                amount: ".5".to_string(),
+                symbol: None,
+            }
+        );
+        assert_eq!(
+            FtAmount::from_str("12500000 raw").unwrap(),
+            FtAmount::Raw(12_500_000)
+        );
+        assert_eq!(
+            FtAmount::from_str("12.5 USDC").unwrap().to_string(),
+            "12.5 USDC"
+        );
+        assert_eq!(
+            FtAmount::from_str("12500000 RAW").unwrap().to_string(),
+            "12500000 raw"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ft_amounts() {
+        for amount in ["1.2.3", "raw", "1.5 raw", "", ".", "USDC"] {
+            assert!(FtAmount::from_str(amount).is_err(), "{}", amount);
+        }
+    }
+
+    #[test]
+    fn converts_ft_amounts_with_the_token_decimals() {
+        let to_raw = |amount: &str| FtAmount::from_str(amount).unwrap().to_raw("USDC", 6);
+        assert_eq!(to_raw("12.5 USDC").unwrap(), 12_500_000);
+        assert_eq!(to_raw("12.5 usdc").unwrap(), 12_500_000);
+        assert_eq!(to_raw("12.5").unwrap(), 12_500_000);
+        assert_eq!(to_raw(".5").unwrap(), 500_000);
+        assert_eq!(to_raw("0.000001").unwrap(), 1);
+        assert_eq!(to_raw("12500000 raw").unwrap(), 12_500_000);
+        // A symbol mismatch and more decimal places than the token has are rejected
+        assert!(to_raw("12.5 USDT").is_err());
+        assert!(to_raw("0.0000001 USDC").is_err());
+    }
+}
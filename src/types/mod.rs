@@ -1,12 +1,21 @@
 pub mod account_id;
+pub mod account_id_list;
+pub mod allowance;
 pub mod api_key;
+pub mod bip39_language;
+pub mod block_reference;
 pub mod crypto_hash;
+pub mod duration;
+pub mod ft_amount;
+pub mod nep413;
 pub mod path_buf;
 pub mod public_key;
 pub mod secret_key;
+pub mod seed_phrase;
 pub mod signature;
 pub mod signed_transaction;
 pub mod slip10;
+pub mod threshold;
 pub mod transaction;
 pub mod url;
 pub mod vec_string;
@@ -0,0 +1,203 @@
+//! Off-chain message signing as defined in NEP-413:
+//! https://github.com/near/NEPs/blob/master/neps/nep-0413.md
+use near_primitives::borsh::BorshSerialize;
+
+/// `2^31 + 413`, borsh-serialized in front of the payload, so a signed message can never be
+/// a valid transaction
+const NEP413_TAG: u32 = (1 << 31) + 413;
+
+/// 32 bytes that make every signed message unique, base64 encoded on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nonce(pub [u8; 32]);
+
+impl Nonce {
+    pub fn random() -> Self {
+        let mut nonce = [0; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut nonce);
+        Self(nonce)
+    }
+}
+
+impl std::fmt::Display for Nonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", base64::encode(self.0))
+    }
+}
+
+impl std::str::FromStr for Nonce {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base64::decode(s).map_err(|err| format!("Nonce: {}", err))?;
+        let nonce = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            format!(
+                "Nonce: expected 32 base64 encoded bytes, but got {}",
+                bytes.len()
+            )
+        })?;
+        Ok(Self(nonce))
+    }
+}
+
+impl interactive_clap::ToCli for Nonce {
+    type CliVariant = Nonce;
+}
+
+/// ED25519 signature, base64 encoded as NEP-413 wallets do (`ed25519:<base58>` is accepted too)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature(pub near_crypto::Signature);
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.0 {
+            near_crypto::Signature::ED25519(signature) => {
+                write!(f, "{}", base64::encode(signature.to_bytes()))
+            }
+            signature => write!(f, "{}", signature),
+        }
+    }
+}
+
+impl std::str::FromStr for Signature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            return near_crypto::Signature::from_str(s)
+                .map(Self)
+                .map_err(|err| format!("Signature: {}", err));
+        }
+        let bytes = base64::decode(s).map_err(|err| format!("Signature: {}", err))?;
+        near_crypto::Signature::from_parts(near_crypto::KeyType::ED25519, &bytes)
+            .map(Self)
+            .map_err(|err| format!("Signature: {}", err))
+    }
+}
+
+impl interactive_clap::ToCli for Signature {
+    type CliVariant = Signature;
+}
+
+#[derive(Debug, Clone)]
+pub struct Payload {
+    pub message: String,
+    pub nonce: Nonce,
+    pub recipient: String,
+    pub callback_url: Option<String>,
+}
+
+impl Payload {
+    /// The borsh-serialized payload without the tag (the NEAR Ledger app adds the tag itself).
+    pub fn borsh_bytes(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        // A tuple is borsh-serialized the same way as a struct with these fields
+        Ok((
+            &self.message,
+            self.nonce.0,
+            &self.recipient,
+            &self.callback_url,
+        )
+            .try_to_vec()?)
+    }
+
+    /// SHA-256 of the tag followed by the borsh-serialized payload; this is what gets signed.
+    pub fn hash(&self) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        let mut bytes = NEP413_TAG.try_to_vec()?;
+        bytes.extend(self.borsh_bytes()?);
+        Ok(near_primitives::hash::hash(&bytes))
+    }
+
+    pub fn sign(&self, secret_key: &near_crypto::SecretKey) -> color_eyre::eyre::Result<Signature> {
+        if !matches!(secret_key.key_type(), near_crypto::KeyType::ED25519) {
+            return Err(crate::common::CliError::new(
+                crate::common::ErrorCategory::UserInput,
+                "NEP-413 messages can only be signed with ED25519 keys",
+            )
+            .into());
+        }
+        Ok(Signature(secret_key.sign(self.hash()?.as_ref())))
+    }
+
+    pub fn verify(
+        &self,
+        signature: &Signature,
+        public_key: &near_crypto::PublicKey,
+    ) -> color_eyre::eyre::Result<bool> {
+        Ok(signature.0.verify(self.hash()?.as_ref(), public_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // The hashes and signatures were computed independently with Python (hashlib and the
+    // Ed25519 implementation of the cryptography package) from the byte layout of NEP-413
+    const SECRET_KEY: &str = "ed25519:99eUso3aSbE9tqGSTXzo3TLfKb9RkMTURrHKQ1K7Zh3StnzFNUx8FKCPPPPpR479qsw5zv2WNBKmgiz7WqgAJfM";
+    const NONCE: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    fn payload(callback_url: Option<&str>) -> Payload {
+        Payload {
+            message: "Sign in to example.com".to_string(),
+            nonce: Nonce::from_str(NONCE).unwrap(),
+            recipient: "example.com".to_string(),
+            callback_url: callback_url.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn hashes_and_signs_payloads() {
+        let secret_key = near_crypto::SecretKey::from_str(SECRET_KEY).unwrap();
+        assert_eq!(
+            secret_key.public_key().to_string(),
+            "ed25519:GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB"
+        );
+        for (callback_url, hash, signature) in [
+            (
+                None,
+                "53d883cc238d6aa0947e4686426cc4b7d91fef474a61ca8258a5039add818e8b",
+                "j34amE0Gbeqt+3SBdWcgDgRJrJmsnQCOs8Z2X1x7JXjjutmMuiiGDhs1VgshgJOqwtHzJo+iZON+gzc+i1onCA==",
+            ),
+            (
+                Some("https://example.com/callback"),
+                "148965296ef53289cfb1443cffd249c1e7952827e3334a0bc03ecc7e289f8884",
+                "bKXxlz84J4IJbezmBdKzMx6nnGNiPIFNQDgS7k2RtaNFTzgbux5fd1ZG5dUP91bnbW7AWAUJysDDARXaAt+AAA==",
+            ),
+        ] {
+            let payload = payload(callback_url);
+            assert_eq!(hex::encode(payload.hash().unwrap()), hash);
+            let signed = payload.sign(&secret_key).unwrap();
+            assert_eq!(signed.to_string(), signature);
+            assert!(payload
+                .verify(
+                    &Signature::from_str(signature).unwrap(),
+                    &secret_key.public_key()
+                )
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn rejects_tampered_payloads() {
+        let secret_key = near_crypto::SecretKey::from_str(SECRET_KEY).unwrap();
+        let signature = payload(None).sign(&secret_key).unwrap();
+        let mut tampered = payload(None);
+        tampered.recipient = "example.org".to_string();
+        assert!(!tampered
+            .verify(&signature, &secret_key.public_key())
+            .unwrap());
+    }
+
+    #[test]
+    fn parses_nonces_and_signatures() {
+        assert_eq!(Nonce::from_str(NONCE).unwrap().0[31], 31);
+        assert_eq!(Nonce::from_str(NONCE).unwrap().to_string(), NONCE);
+        assert!(Nonce::from_str("AAEC").is_err());
+        assert_ne!(Nonce::random(), Nonce::random());
+        let signature = "j34amE0Gbeqt+3SBdWcgDgRJrJmsnQCOs8Z2X1x7JXjjutmMuiiGDhs1VgshgJOqwtHzJo+iZON+gzc+i1onCA==";
+        let parsed = Signature::from_str(signature).unwrap();
+        assert_eq!(parsed.to_string(), signature);
+        assert_eq!(Signature::from_str(&parsed.0.to_string()).unwrap(), parsed);
+        assert!(Signature::from_str("AAEC").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+/// Lowercases the seed phrase and separates its words with single spaces, the way near-wallet did
+/// before deriving keys, so a phrase copied with capitals or line breaks derives the same keys.
+pub fn normalize_seed_phrase(master_seed_phrase: &str) -> String {
+    master_seed_phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// The input functions of the `--seed-phrase-passphrase` and `--seed-phrase-language` options,
+/// which every command that takes a seed phrase declares. interactive_clap calls
+/// `Self::input_<field>(&context)`, so implementing this trait for the command provides both of
+/// them; the fields themselves have to stay in each command, since interactive_clap resolves
+/// flattened arguments only after the context of the command is built.
+pub trait SeedPhraseOptions {
+    type InputContext;
+
+    fn global_context(context: &Self::InputContext) -> &crate::GlobalContext;
+
+    /// The passphrase is optional, so it is asked for in the interactive mode only, and a blank
+    /// answer means no passphrase.
+    fn input_seed_phrase_passphrase(
+        context: &Self::InputContext,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        if Self::global_context(context).1.scripting_mode {
+            return Ok(None);
+        }
+        let seed_phrase_passphrase = inquire::Password::new(
+            "Enter the BIP39 passphrase of the seed phrase (leave blank if there is none)",
+        )
+        .without_confirmation()
+        .prompt()?;
+        Ok((!seed_phrase_passphrase.is_empty()).then_some(seed_phrase_passphrase))
+    }
+
+    /// The language is detected from the words of the seed phrase unless it is given.
+    fn input_seed_phrase_language(
+        _context: &Self::InputContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::bip39_language::Bip39Language>> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_seed_phrases() {
+        assert_eq!(
+            normalize_seed_phrase("  Abandon\tabandon\n\nABOUT "),
+            "abandon abandon about"
+        );
+        assert_eq!(normalize_seed_phrase("abandon about"), "abandon about");
+        assert_eq!(normalize_seed_phrase(" \n"), "");
+    }
+}
@@ -0,0 +1,86 @@
+/// The `--exit-if` condition of watch mode: `below:<amount>`, `above:<amount>` or
+/// `equal:<amount>`, where the amount is in NEAR, in tokens or, for function results, as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Threshold {
+    pub comparison: Comparison,
+    pub amount: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Below,
+    Above,
+    Equal,
+}
+
+impl Threshold {
+    /// Whether the value (in the smallest units of a token with `decimals` decimal places)
+    /// meets the condition.
+    pub fn is_met(&self, value: u128, decimals: u32) -> color_eyre::eyre::Result<bool> {
+        let amount = crate::common::parse_decimal_amount(&self.amount, decimals)
+            .map_err(|err| color_eyre::eyre::eyre!("--exit-if: {}", err))?;
+        Ok(match self.comparison {
+            Comparison::Below => value < amount,
+            Comparison::Above => value > amount,
+            Comparison::Equal => value == amount,
+        })
+    }
+}
+
+impl std::fmt::Display for Threshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let comparison = match self.comparison {
+            Comparison::Below => "below",
+            Comparison::Above => "above",
+            Comparison::Equal => "equal",
+        };
+        write!(f, "{}:{}", comparison, self.amount)
+    }
+}
+
+impl std::str::FromStr for Threshold {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (comparison, amount) = s.split_once(':').ok_or_else(|| {
+            format!(
+                "Threshold <{}>: expected below:<amount>, above:<amount> or equal:<amount>",
+                s
+            )
+        })?;
+        let comparison = match comparison.trim() {
+            "below" => Comparison::Below,
+            "above" => Comparison::Above,
+            "equal" => Comparison::Equal,
+            comparison => {
+                return Err(format!(
+                    "Threshold <{}>: unknown comparison <{}>, use below, above or equal",
+                    s, comparison
+                ))
+            }
+        };
+        let amount = amount.trim();
+        // The number of decimal places is only known once the token metadata is fetched, so only
+        // the format of the amount is checked here
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(format!(
+                "Threshold <{}>: <{}> is not a decimal number",
+                s, amount
+            ));
+        }
+        Ok(Self {
+            comparison,
+            amount: amount.to_string(),
+        })
+    }
+}
+
+impl interactive_clap::ToCli for Threshold {
+    type CliVariant = Threshold;
+}
@@ -10,6 +10,12 @@ pub struct CliGenerateKeypair {
     pub new_master_seed_phrase_words_count: usize,
     #[clap(long, default_value = "m/44'/397'/0'")]
     pub seed_phrase_hd_path: crate::types::slip10::BIP32Path,
+    /// BIP39 passphrase (the "25th word") of the seed phrase
+    #[clap(long)]
+    pub seed_phrase_passphrase: Option<String>,
+    /// Wordlist language of the seed phrase (detected from the words, or English for new phrases)
+    #[clap(long)]
+    pub seed_phrase_language: Option<crate::types::bip39_language::Bip39Language>,
     #[clap(long, default_value = "plaintext")]
     pub format: crate::common::OutputFormat,
 }
@@ -21,6 +27,8 @@ impl Default for CliGenerateKeypair {
             new_master_seed_phrase_words_count: 12,
             seed_phrase_hd_path: crate::types::slip10::BIP32Path::from_str("m/44'/397'/0'")
                 .unwrap(),
+            seed_phrase_passphrase: None,
+            seed_phrase_language: None,
             format: crate::common::OutputFormat::Json,
         }
     }